@@ -0,0 +1,100 @@
+// backend_simple_web/src/api/backups.rs
+use rocket::fs::NamedFile;
+use rocket::serde::{json::Json, Serialize};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use super::repo_lock;
+use crate::backup::{self, BackupInfo, RestorePlan};
+use super::error::AppError;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BackupListResponse {
+    /// Directory the backups are written to
+    pub directory: String,
+    pub backups: Vec<BackupInfo>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BackupResponse {
+    pub success: bool,
+    pub message: String,
+    pub backup: Option<BackupInfo>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RestoreResponse {
+    pub success: bool,
+    pub message: String,
+    pub dry_run: bool,
+    pub plan: RestorePlan,
+}
+
+/// List backups, newest first
+/// ### Examples:
+/// - GET /api/backups
+#[get("/backups")]
+pub async fn list_backups(_admin: Admin) -> Result<Json<BackupListResponse>, AppError> {
+    let backups = backup::list_backups().await?;
+    Ok(Json(BackupListResponse {
+        directory: backup::backup_dir().to_string_lossy().into_owned(),
+        backups,
+    }))
+}
+
+/// Take a backup right now
+/// ### Examples:
+/// - POST /api/backups
+#[post("/backups")]
+pub async fn create_backup(_admin: Admin) -> Result<Json<BackupResponse>, AppError> {
+    let path = backup::create_snapshot().await?;
+    let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+    let info = backup::list_backups().await?.into_iter().find(|b| b.name == name);
+
+    Ok(Json(BackupResponse {
+        success: true,
+        message: format!("Backup {} created", name),
+        backup: info,
+    }))
+}
+
+/// Download a backup archive
+/// ### Examples:
+/// - GET /api/backups/backup-1767254400.tar.gz
+#[get("/backups/<name>")]
+pub async fn download_backup(_admin: Admin, name: &str) -> Result<NamedFile, AppError> {
+    let path = backup::backup_path(name)
+        .ok_or_else(|| AppError::NotFound(format!("Backup '{}' not found", name)))?;
+    Ok(NamedFile::open(path).await?)
+}
+
+/// Restore the site from a backup, optionally including the data directory
+/// (themes, scheduler and other settings; takes effect after a restart).
+/// With `dry_run=true` only the list of changes is returned.
+/// ### Examples:
+/// - POST /api/backups/backup-1767254400.tar.gz/restore?dry_run=true
+/// - POST /api/backups/backup-1767254400.tar.gz/restore?data=true
+#[post("/backups/<name>/restore?<dry_run>&<data>")]
+pub async fn restore_backup(_admin: Admin, name: &str, dry_run: Option<bool>, data: Option<bool>) -> Result<Json<RestoreResponse>, AppError> {
+    let dry_run = dry_run.unwrap_or(false);
+    let include_data = data.unwrap_or(false);
+    // A dry run only compares, so it can share the repository with other reads
+    let operation = format!("POST /api/backups/{}/restore", name);
+    let _lock = if dry_run { repo_lock::read(operation).await? } else { repo_lock::write(operation).await?.into() };
+    let plan = backup::restore(name, include_data, dry_run).await?;
+
+    let changes = plan.added.len() + plan.modified.len() + plan.deleted.len();
+    let message = if plan.is_empty() {
+        "Nothing to restore: the current files match the backup".to_string()
+    } else if dry_run {
+        format!("Restoring {} would change {} files", name, changes)
+    } else if plan.touches_data() {
+        format!("Restored {} ({} files changed). Scheduled jobs and publications are stopped; restart the server to load restored settings.", name, changes)
+    } else {
+        format!("Restored {} ({} files changed)", name, changes)
+    };
+
+    Ok(Json(RestoreResponse { success: true, message, dry_run, plan }))
+}
@@ -10,6 +10,8 @@ pub enum AppError {
     BadRequest(String),
     NotFound(String),
     Internal(String),
+    /// The repository lock could not be acquired in time
+    Busy(String),
 }
 
 #[derive(Serialize)]
@@ -28,6 +30,7 @@ impl<'r> Responder<'r, 'static> for AppError {
                 error!("Internal error: {}", m);
                 (Status::InternalServerError, m.clone())
             }
+            AppError::Busy(m) => (Status::ServiceUnavailable, format!("Repository busy: {}", m)),
         };
         let body = Json(ErrorBody { success: false, message: msg });
         Response::build_from(body.respond_to(req)?)
@@ -64,6 +67,7 @@ impl std::fmt::Display for AppError {
             AppError::BadRequest(m) => write!(f, "Bad request: {}", m),
             AppError::NotFound(m) => write!(f, "Not found: {}", m),
             AppError::Internal(m) => write!(f, "Internal error: {}", m),
+            AppError::Busy(m) => write!(f, "Repository busy: {}", m),
         }
     }
 }
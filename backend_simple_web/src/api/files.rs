@@ -6,7 +6,9 @@ use rocket::tokio::fs;
 use std::path::{Path, PathBuf};
 
 use prisma_auth::backend::AuthGuard as Admin;
+use super::repo_lock::{self, RepoWrite};
 use super::error::AppError;
+use super::git::submodules::submodule_paths_internal;
 use super::path::ValidatedPath;
 use super::{ROOT, clean};
 
@@ -15,6 +17,8 @@ use super::{ROOT, clean};
 pub struct FileEntry {
     path: String,
     is_dir: bool,
+    /// The directory is a git submodule
+    is_submodule: bool,
 }
 
 #[derive(Deserialize, Debug)]
@@ -37,18 +41,21 @@ pub async fn list_files(path: Option<String>, _admin: Admin) -> Json<Vec<FileEnt
         _ => PathBuf::from(ROOT),
     };
 
+    let submodules = submodule_paths_internal();
     let mut list = Vec::new();
     if let Ok(mut rd) = fs::read_dir(&dir_path).await {
         while let Ok(Some(entry)) = rd.next_entry().await {
             if let Ok(md) = entry.metadata().await {
+                let path: String = entry
+                    .path()
+                    .strip_prefix(ROOT)
+                    .unwrap()
+                    .to_string_lossy()
+                    .trim_start_matches('/')
+                    .into();
                 list.push(FileEntry {
-                    path: entry
-                        .path()
-                        .strip_prefix(ROOT)
-                        .unwrap()
-                        .to_string_lossy()
-                        .trim_start_matches('/')
-                        .into(),
+                    is_submodule: md.is_dir() && submodules.contains(&path),
+                    path,
                     is_dir: md.is_dir(),
                 });
             }
@@ -80,6 +87,8 @@ pub async fn get_file(path: Option<String>, _admin: Admin) -> Option<NamedFile>
 #[post("/file?<path>", data = "<body>")]
 pub async fn save_file(_admin: Admin, path: &str, body: Json<FileBody>) -> Result<Status, AppError> {
     let vp = ValidatedPath::new_destination(path)?;
+    // Locked only once the body is in, so a slow client doesn't hold up git
+    let _lock = repo_lock::write("POST /api/file").await?;
     let full = vp.as_path();
 
     if fs::metadata(full)
@@ -104,7 +113,7 @@ pub async fn save_file(_admin: Admin, path: &str, body: Json<FileBody>) -> Resul
 /// ### Examples:
 /// - DELETE /api/file?path=img/logo.png
 #[delete("/file?<path>")]
-pub async fn delete_file(path: Option<String>, _admin: Admin) -> Result<Status, AppError> {
+pub async fn delete_file(path: Option<String>, _admin: Admin, _lock: RepoWrite) -> Result<Status, AppError> {
     let rel = path.map(|p| clean(&p)).filter(|p| !p.is_empty())
         .ok_or_else(|| AppError::BadRequest("Path is required".into()))?;
     let vp = ValidatedPath::new(&rel)?;
@@ -2,62 +2,82 @@
 use rocket::serde::{json::Json, Deserialize, Serialize};
 use rocket::tokio::fs;
 use std::path::Path;
-use git2::{Repository, Cred, FetchOptions, PushOptions, RemoteCallbacks};
+use git2::{Repository, Cred, CredentialType, FetchOptions, PushOptions, RemoteCallbacks};
 
 use prisma_auth::backend::AuthGuard as Admin;
+use super::repo_lock::{self, LockHolder, RepoWrite, RepoWriteGuard};
 use crate::scheduler::{get_scheduler, AutoPullConfig};
 use super::error::AppError;
 use super::ROOT;
-
-const GIT_CREDENTIALS_PATH: &str = "/app/data/git_credentials.json";
-
-// --- Credentials ---
-
-#[derive(Deserialize, Serialize, Clone)]
-#[serde(crate = "rocket::serde")]
-struct GitCredentials {
-    username: String,
-    token: String,
-}
-
-async fn save_git_credentials(username: &str, token: &str) {
-    let creds = GitCredentials {
-        username: username.to_string(),
-        token: token.to_string(),
-    };
-    if let Ok(json) = serde_json::to_string(&creds) {
-        if let Err(e) = fs::write(GIT_CREDENTIALS_PATH, json).await {
-            error!("Failed to save git credentials: {}", e);
-        }
-    }
-}
-
-async fn load_git_credentials() -> Option<GitCredentials> {
-    fs::read_to_string(GIT_CREDENTIALS_PATH)
-        .await
-        .ok()
-        .and_then(|json| serde_json::from_str(&json).ok())
-}
-
-fn make_fetch_options(creds: &GitCredentials) -> FetchOptions<'_> {
+use credentials::{load_git_credentials, save_git_credentials, GitCredentials};
+use identity::{configured_committer, Identity, IdentityConfig};
+use remotes::PushResponse;
+use submodules::SubmoduleInfo;
+
+pub mod blame;
+pub mod branches;
+pub mod credentials;
+pub mod diff;
+pub mod history;
+pub mod identity;
+pub mod merge;
+pub mod remotes;
+pub mod revert;
+pub mod sparse;
+pub mod ssh;
+pub mod staging;
+pub mod stash;
+pub mod submodules;
+pub mod tags;
+pub mod tasks;
+pub mod webhooks;
+
+/// Remote that setup clones from and that pulls and pushes use by default
+const DEFAULT_REMOTE: &str = "origin";
+
+fn make_fetch_options<'a>(creds: Option<&'a GitCredentials>, url: &str) -> FetchOptions<'a> {
     let mut opts = FetchOptions::new();
-    opts.remote_callbacks(make_callbacks(creds));
+    opts.remote_callbacks(make_callbacks(creds, url));
     opts
 }
 
-fn make_push_options(creds: &GitCredentials) -> PushOptions<'_> {
+fn make_push_options<'a>(creds: Option<&'a GitCredentials>, url: &str) -> PushOptions<'a> {
     let mut opts = PushOptions::new();
-    opts.remote_callbacks(make_callbacks(creds));
+    opts.remote_callbacks(make_callbacks(creds, url));
     opts
 }
 
-fn make_callbacks(creds: &GitCredentials) -> RemoteCallbacks<'_> {
-    let username = creds.username.clone();
-    let token = creds.token.clone();
+/// Authenticate with the deploy key for SSH remotes and the saved token for
+/// HTTPS ones, and verify SSH host keys against known_hosts for the port of
+/// `url`.
+fn make_callbacks<'a>(creds: Option<&'a GitCredentials>, url: &str) -> RemoteCallbacks<'a> {
+    let deploy_key = ssh::deploy_key();
+    let port = ssh::ssh_port(url);
+    // libgit2 asks again after a rejected credential; offer each one only once
+    let (mut tried_key, mut tried_token) = (false, false);
     let mut callbacks = RemoteCallbacks::new();
-    callbacks.credentials(move |_url, _username_from_url, _allowed_types| {
-        Cred::userpass_plaintext(&username, &token)
+    callbacks.credentials(move |url, username_from_url, allowed_types| {
+        if allowed_types.contains(CredentialType::SSH_KEY) {
+            return match &deploy_key {
+                Some((private, public)) if !tried_key => {
+                    tried_key = true;
+                    Cred::ssh_key(username_from_url.unwrap_or("git"), Some(public), private, None)
+                }
+                Some(_) => Err(git2::Error::from_str("The deploy key was rejected. Add its public key to the repository.")),
+                None => Err(git2::Error::from_str("No deploy key configured. Generate or import one for SSH remotes.")),
+            };
+        }
+        match creds.and_then(|creds| creds.for_url(url)) {
+            Some(credential) if !tried_token && allowed_types.contains(CredentialType::USER_PASS_PLAINTEXT) => {
+                tried_token = true;
+                Cred::userpass_plaintext(&credential.username, &credential.token)
+            }
+            Some(_) => Err(git2::Error::from_str("Authentication failed. Check the username and token.")),
+            None => Err(git2::Error::from_str("No credentials for this host. Add a credential profile for it.")),
+        }
     });
+    callbacks.certificate_check(move |cert, host| ssh::check_host_key(cert, host, port));
+    tasks::attach_progress(&mut callbacks);
     callbacks
 }
 
@@ -68,12 +88,23 @@ fn open_repo() -> Result<Repository, AppError> {
         .map_err(|e| AppError::Internal(format!("No Git repository found: {}", e)))
 }
 
+fn find_remote<'r>(repo: &'r Repository, name: &str) -> Result<git2::Remote<'r>, AppError> {
+    repo.find_remote(name)
+        .map_err(|e| AppError::BadRequest(format!("No remote '{}' found: {}", name, e.message())))
+}
+
+fn fetch_remote(repo: &Repository, name: &str, creds: &Option<GitCredentials>) -> Result<(), AppError> {
+    let mut remote = find_remote(repo, name)?;
+    let mut opts = make_fetch_options(creds.as_ref(), remote.url().unwrap_or_default());
+    if let Some(depth) = sparse::fetch_depth(repo) {
+        opts.depth(depth);
+    }
+    remote.fetch(&[] as &[&str], Some(&mut opts), None)
+        .map_err(|e| AppError::Internal(format!("Failed to fetch changes from {}: {}", name, e)))
+}
+
 fn fetch_origin(repo: &Repository, creds: &Option<GitCredentials>) -> Result<(), AppError> {
-    let mut remote = repo.find_remote("origin")
-        .map_err(|e| AppError::Internal(format!("No remote 'origin' found: {}", e)))?;
-    let mut fetch_opts = creds.as_ref().map(make_fetch_options);
-    remote.fetch(&[] as &[&str], fetch_opts.as_mut(), None)
-        .map_err(|e| AppError::Internal(format!("Failed to fetch changes: {}", e)))
+    fetch_remote(repo, DEFAULT_REMOTE, creds)
 }
 
 fn current_branch_name(repo: &Repository) -> Result<String, AppError> {
@@ -82,8 +113,8 @@ fn current_branch_name(repo: &Repository) -> Result<String, AppError> {
     Ok(head.shorthand().unwrap_or("main").to_string())
 }
 
-fn remote_commit<'a>(repo: &'a Repository, branch: &str) -> Result<git2::Commit<'a>, AppError> {
-    let remote_name = format!("origin/{}", branch);
+fn remote_commit<'a>(repo: &'a Repository, remote: &str, branch: &str) -> Result<git2::Commit<'a>, AppError> {
+    let remote_name = format!("{}/{}", remote, branch);
     let remote_branch = repo.find_branch(&remote_name, git2::BranchType::Remote)
         .map_err(|e| AppError::Internal(format!("Remote branch '{}' not found: {}", remote_name, e)))?;
     remote_branch.get().peel_to_commit()
@@ -91,11 +122,11 @@ fn remote_commit<'a>(repo: &'a Repository, branch: &str) -> Result<git2::Commit<
 }
 
 fn ensure_clean_workdir(repo: &Repository, action: &str) -> Result<(), AppError> {
-    let statuses = repo.statuses(None)
+    let statuses = repo.statuses(Some(&mut sparse::status_options(repo)))
         .map_err(|e| AppError::Internal(format!("Failed to check repository status: {}", e)))?;
     if !statuses.is_empty() {
         return Err(AppError::BadRequest(format!(
-            "Cannot {} with uncommitted changes. Please commit changes first.", action
+            "Cannot {} with uncommitted changes. Commit or stash them first.", action
         )));
     }
     Ok(())
@@ -105,12 +136,83 @@ fn head_commit_hash(repo: &Repository) -> Option<String> {
     repo.head().ok().and_then(|h| h.target()).map(|oid| oid.to_string())
 }
 
+/// After an update moved HEAD away from `before`, check out the submodule
+/// commits it records. `None` updates them whatever HEAD is.
+fn update_submodules_after(
+    repo: &Repository,
+    before: Option<String>,
+    result: Result<GitStatus, AppError>,
+    creds: Option<&GitCredentials>,
+) -> Result<GitStatus, AppError> {
+    let mut status = result?;
+    if !status.success || (before.is_some() && head_commit_hash(repo) == before) {
+        return Ok(status);
+    }
+    match submodules::update_submodules(repo, creds) {
+        Ok(0) => Ok(status),
+        Ok(updated) => {
+            status.message = format!("{} ({} submodules updated)", status.message, updated);
+            Ok(status)
+        }
+        Err(e) => Err(AppError::Internal(format!("{}, but the submodules are stale. {}", status.message, e))),
+    }
+}
+
+/// Move a local branch to `target`, make it HEAD and check it out.
+fn fast_forward(repo: &Repository, branch_name: &str, target: git2::Oid, reflog_msg: &str) -> Result<(), AppError> {
+    let local_branch = repo.find_branch(branch_name, git2::BranchType::Local)
+        .map_err(|e| AppError::Internal(format!("Failed to find local branch: {}", e)))?;
+    local_branch.into_reference().set_target(target, reflog_msg)
+        .map_err(|e| AppError::Internal(format!("Failed to update branch: {}", e)))?;
+    repo.set_head(&format!("refs/heads/{}", branch_name))
+        .map_err(|e| AppError::Internal(format!("Failed to update HEAD: {}", e)))?;
+    repo.checkout_head(Some(sparse::checkout(repo).force()))
+        .map_err(|e| AppError::Internal(format!("Failed to update working directory: {}", e)))?;
+    let tree = repo.find_commit(target).and_then(|commit| commit.tree())
+        .map_err(|e| AppError::Internal(format!("Failed to read commit tree: {}", e)))?;
+    sparse::sync_index(repo, &tree)
+}
+
+/// Identity from the repository's `user.name`/`user.email`, else a generic one
+fn default_signature(repo: &Repository) -> git2::Signature<'static> {
+    repo.signature().map(|sig| sig.to_owned())
+        .or_else(|_| git2::Signature::now("Simple Web", "noreply@simple-web.local"))
+        .unwrap_or_else(|_| git2::Signature::now("Unknown", "unknown@local").unwrap())
+}
+
+/// Committer for commits made by the server: the configured instance-wide
+/// committer, falling back to `default_signature`
+fn signature(repo: &Repository) -> git2::Signature<'static> {
+    configured_committer()
+        .and_then(|identity| identity.signature().ok())
+        .unwrap_or_else(|| default_signature(repo))
+}
+
+/// Number of changed or untracked (but not ignored) files in the working tree.
+fn uncommitted_changes(repo: &Repository) -> Result<usize, AppError> {
+    let mut opts = sparse::status_options(repo);
+    opts.include_untracked(true).include_ignored(false);
+    repo.statuses(Some(&mut opts))
+        .map(|statuses| statuses.len())
+        .map_err(|e| AppError::Internal(format!("Failed to check repository status: {}", e)))
+}
+
+/// Paths with conflicts in a merged index
+fn conflict_paths(index: &git2::Index) -> Vec<String> {
+    let Ok(conflicts) = index.conflicts() else { return Vec::new() };
+    conflicts
+        .filter_map(|conflict| conflict.ok())
+        .filter_map(|conflict| conflict.our.or(conflict.their).or(conflict.ancestor))
+        .map(|entry| String::from_utf8_lossy(&entry.path).into_owned())
+        .collect()
+}
+
 /// Fetch from origin and hard-reset to the remote branch tip.
 fn fetch_and_reset(repo: &Repository, creds: &Option<GitCredentials>) -> Result<GitStatus, AppError> {
     fetch_origin(repo, creds)?;
     let branch = current_branch_name(repo)?;
-    let commit = remote_commit(repo, &branch)?;
-    repo.reset(&commit.as_object(), git2::ResetType::Hard, None)
+    let commit = remote_commit(repo, DEFAULT_REMOTE, &branch)?;
+    repo.reset(&commit.as_object(), git2::ResetType::Hard, Some(&mut sparse::checkout(repo)))
         .map_err(|e| AppError::Internal(format!("Failed to reset to remote: {}", e)))?;
     Ok(GitStatus::ok(
         "Successfully pulled latest changes",
@@ -118,6 +220,218 @@ fn fetch_and_reset(repo: &Repository, creds: &Option<GitCredentials>) -> Result<
     ))
 }
 
+// --- Scheduled pull policies ---
+
+/// What a scheduled pull does with uncommitted edits in the working tree.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum DirtyTreePolicy {
+    /// Leave the tree alone and report the pull as skipped
+    #[default]
+    Skip,
+    /// Stash the edits, pull, then re-apply them
+    Stash,
+    /// Commit the edits, then pull as for a diverged branch
+    Commit,
+    /// Throw the edits away
+    Discard,
+}
+
+/// What a scheduled pull does when the local branch has commits origin lacks.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum DivergedPolicy {
+    /// Leave the branch alone and report the pull as skipped
+    #[default]
+    Skip,
+    /// Create a merge commit
+    Merge,
+    /// Replay the local commits on top of origin
+    Rebase,
+    /// Hard-reset to origin, dropping the local commits
+    Reset,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[serde(crate = "rocket::serde")]
+pub struct PullPolicy {
+    #[serde(default)]
+    pub dirty: DirtyTreePolicy,
+    #[serde(default)]
+    pub diverged: DivergedPolicy,
+}
+
+/// Pull for a scheduled job, handling a dirty tree and a diverged branch as
+/// the policy says. Nothing is discarded unless the policy asks for it.
+fn pull_with_policy(policy: PullPolicy, creds: &Option<GitCredentials>) -> Result<GitStatus, AppError> {
+    let mut repo = open_repo()?;
+
+    let changes = uncommitted_changes(&repo)?;
+    let mut stashed = false;
+    if changes > 0 {
+        match policy.dirty {
+            DirtyTreePolicy::Skip => {
+                return Ok(GitStatus::ok(
+                    format!("Skipped pull: {} uncommitted changes in the working tree", changes),
+                    head_commit_hash(&repo),
+                ));
+            }
+            DirtyTreePolicy::Stash => {
+                let stasher = signature(&repo);
+                repo.stash_save(&stasher, "Auto-stash before scheduled pull", Some(git2::StashFlags::INCLUDE_UNTRACKED))
+                    .map_err(|e| AppError::Internal(format!("Failed to stash changes: {}", e)))?;
+                stashed = true;
+            }
+            DirtyTreePolicy::Commit => {
+                let pathspecs = sparse::all_pathspecs(&repo);
+                let pathspecs: Vec<&str> = pathspecs.iter().map(String::as_str).collect();
+                commit_pathspecs("Auto-commit before scheduled pull", &pathspecs)?;
+            }
+            DirtyTreePolicy::Discard => {
+                let head = repo.head().and_then(|head| head.peel_to_commit())
+                    .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+                repo.reset(head.as_object(), git2::ResetType::Hard, Some(&mut sparse::checkout(&repo)))
+                    .map_err(|e| AppError::Internal(format!("Failed to discard changes: {}", e)))?;
+                repo.checkout_head(Some(sparse::checkout(&repo).force().remove_untracked(true)))
+                    .map_err(|e| AppError::Internal(format!("Failed to remove untracked files: {}", e)))?;
+            }
+        }
+    }
+
+    let before = head_commit_hash(&repo);
+    let result = update_from_origin(&repo, policy.diverged, creds);
+    let result = update_submodules_after(&repo, before, result, creds.as_ref());
+
+    if stashed {
+        // Re-apply even when the update failed, so the edits are back where they were
+        if let Err(e) = repo.stash_pop(0, None) {
+            let outcome = match &result {
+                Ok(status) => status.message.clone(),
+                Err(e) => e.to_string(),
+            };
+            return Err(AppError::Internal(format!(
+                "{}, but re-applying the stashed changes failed: {}. They are kept in the stash.", outcome, e
+            )));
+        }
+    }
+    result
+}
+
+/// Fetch origin and bring the current branch up to date with it. Expects a clean tree.
+fn update_from_origin(repo: &Repository, diverged: DivergedPolicy, creds: &Option<GitCredentials>) -> Result<GitStatus, AppError> {
+    fetch_origin(repo, creds)?;
+
+    let branch_name = current_branch_name(repo)?;
+    let local = repo.head().and_then(|head| head.peel_to_commit())
+        .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+    let remote = remote_commit(repo, DEFAULT_REMOTE, &branch_name)?;
+    if local.id() == remote.id() {
+        return Ok(GitStatus::ok("Already up to date", Some(local.id().to_string())));
+    }
+
+    let (ahead, behind) = repo.graph_ahead_behind(local.id(), remote.id())
+        .map_err(|e| AppError::Internal(format!("Failed to calculate repository status: {}", e)))?;
+    if behind == 0 {
+        return Ok(GitStatus::ok(
+            format!("Nothing to pull; {} local commits are not pushed yet", ahead),
+            Some(local.id().to_string()),
+        ));
+    }
+    if ahead == 0 {
+        fast_forward(repo, &branch_name, remote.id(), "Fast-forward pull")?;
+        return Ok(GitStatus::ok(
+            format!("Successfully pulled {} new commits", behind),
+            Some(remote.id().to_string()),
+        ));
+    }
+
+    match diverged {
+        DivergedPolicy::Skip => Ok(GitStatus::ok(
+            format!("Skipped pull: {} has diverged from origin ({} local and {} remote commits)", branch_name, ahead, behind),
+            Some(local.id().to_string()),
+        )),
+        DivergedPolicy::Merge => {
+            let mut index = repo.merge_commits(&local, &remote, None)
+                .map_err(|e| AppError::Internal(format!("Failed to merge: {}", e)))?;
+            if index.has_conflicts() {
+                return Err(AppError::BadRequest(format!(
+                    "Cannot merge origin/{}: conflicts in {}", branch_name, conflict_paths(&index).join(", ")
+                )));
+            }
+            let tree_id = index.write_tree_to(repo)
+                .map_err(|e| AppError::Internal(format!("Failed to write merge tree: {}", e)))?;
+            let tree = repo.find_tree(tree_id)
+                .map_err(|e| AppError::Internal(format!("Failed to find merge tree: {}", e)))?;
+            let signature = signature(repo);
+            let message = format!("Merge remote-tracking branch 'origin/{}'", branch_name);
+            let oid = repo.commit(None, &signature, &signature, &message, &tree, &[&local, &remote])
+                .map_err(|e| AppError::Internal(format!("Failed to create merge commit: {}", e)))?;
+            fast_forward(repo, &branch_name, oid, "Merge pull")?;
+            Ok(GitStatus::ok(format!("Merged {} new commits from origin", behind), Some(oid.to_string())))
+        }
+        DivergedPolicy::Rebase => {
+            let oid = rebase_onto(repo, &remote)?;
+            Ok(GitStatus::ok(
+                format!("Rebased {} local commits onto {} new commits from origin", ahead, behind),
+                Some(oid.to_string()),
+            ))
+        }
+        DivergedPolicy::Reset => {
+            repo.reset(remote.as_object(), git2::ResetType::Hard, Some(&mut sparse::checkout(repo)))
+                .map_err(|e| AppError::Internal(format!("Failed to reset to remote: {}", e)))?;
+            Ok(GitStatus::ok(
+                format!("Reset {} to origin, dropping {} local commits", branch_name, ahead),
+                Some(remote.id().to_string()),
+            ))
+        }
+    }
+}
+
+/// Rebase the current branch onto `upstream`. Aborts and leaves the branch
+/// untouched on conflicts. Returns the new branch tip.
+fn rebase_onto(repo: &Repository, upstream: &git2::Commit) -> Result<git2::Oid, AppError> {
+    let head = repo.head()
+        .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+    let branch = repo.reference_to_annotated_commit(&head)
+        .map_err(|e| AppError::Internal(format!("Failed to read branch: {}", e)))?;
+    let upstream = repo.find_annotated_commit(upstream.id())
+        .map_err(|e| AppError::Internal(format!("Failed to read remote commit: {}", e)))?;
+    let mut rebase = repo.rebase(Some(&branch), Some(&upstream), None, None)
+        .map_err(|e| AppError::Internal(format!("Failed to start rebase: {}", e)))?;
+
+    if let Err(e) = apply_rebase(repo, &mut rebase) {
+        if let Err(abort_err) = rebase.abort() {
+            error!("Failed to abort rebase: {}", abort_err);
+        }
+        return Err(e);
+    }
+    rebase.finish(Some(&signature(repo)))
+        .map_err(|e| AppError::Internal(format!("Failed to finish rebase: {}", e)))?;
+    repo.head().ok().and_then(|head| head.target())
+        .ok_or_else(|| AppError::Internal("HEAD has no target".into()))
+}
+
+fn apply_rebase(repo: &Repository, rebase: &mut git2::Rebase) -> Result<(), AppError> {
+    let signature = signature(repo);
+    while let Some(operation) = rebase.next() {
+        operation.map_err(|e| AppError::Internal(format!("Failed to apply commit: {}", e)))?;
+        let index = repo.index()
+            .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
+        if index.has_conflicts() {
+            return Err(AppError::BadRequest(format!(
+                "Cannot rebase onto origin: conflicts in {}", conflict_paths(&index).join(", ")
+            )));
+        }
+        match rebase.commit(None, &signature, None) {
+            // The change is already upstream
+            Err(e) if e.code() == git2::ErrorCode::Applied => {}
+            Err(e) => return Err(AppError::Internal(format!("Failed to commit rebased change: {}", e))),
+            Ok(_) => {}
+        }
+    }
+    Ok(())
+}
+
 // --- API types ---
 
 #[derive(Deserialize)]
@@ -127,13 +441,22 @@ pub struct GitRepoConfig {
     branch: Option<String>,
     username: Option<String>,
     token: Option<String>,
+    /// Clone only this many commits of history; fetches keep the depth
+    depth: Option<u32>,
+    /// Directories to check out, e.g. the publish directory; everything when empty
+    #[serde(default)]
+    sparse_paths: Vec<String>,
 }
 
 impl GitRepoConfig {
-    async fn save_creds_if_present(&self) {
+    /// Save credentials sent with the form as the profile for the URL's host,
+    /// and return what to authenticate with.
+    async fn credentials(&self) -> Option<GitCredentials> {
         if let (Some(username), Some(token)) = (&self.username, &self.token) {
-            save_git_credentials(username, token).await;
+            save_git_credentials(&self.url, username, token).await;
+            return Some(GitCredentials::single(&self.url, username, token));
         }
+        load_git_credentials().await
     }
 }
 
@@ -183,6 +506,16 @@ pub struct GitRepoStatus {
     pub has_unstaged_changes: bool,
     pub changed_files: Vec<GitFileStatus>,
     pub untracked_files: Vec<String>,
+    /// A merge is waiting for conflicts to be resolved
+    pub merge_in_progress: bool,
+    pub submodules: Vec<SubmoduleInfo>,
+    /// The clone has only part of the history
+    pub shallow: bool,
+    /// Directories of a sparse checkout; empty when everything is checked out
+    pub sparse_paths: Vec<String>,
+    /// Operations holding the repository lock; an exclusive holder makes
+    /// other git operations and file writes wait
+    pub lock_holders: Vec<LockHolder>,
 }
 
 impl GitRepoStatus {
@@ -193,6 +526,11 @@ impl GitRepoStatus {
             behind_count: 0, ahead_count: 0,
             has_changes: false, has_staged_changes: false, has_unstaged_changes: false,
             changed_files: vec![], untracked_files: vec![],
+            merge_in_progress: false,
+            submodules: vec![],
+            shallow: false,
+            sparse_paths: vec![],
+            lock_holders: repo_lock::holders(),
         }
     }
 }
@@ -201,22 +539,32 @@ impl GitRepoStatus {
 #[serde(crate = "rocket::serde")]
 pub struct CommitRequest {
     message: String,
+    /// Stage and commit every change instead of only what is staged
+    #[serde(default)]
+    all: bool,
+    /// Author profile id to commit as; the repository's identity when unset
+    author: Option<String>,
+    /// `Name <email>` of people credited with `Co-authored-by` trailers
+    #[serde(default)]
+    co_authors: Vec<String>,
 }
 
 // --- Route handlers ---
 
 /// POST /api/git/setup
 #[post("/git/setup", data = "<config>")]
-pub async fn setup_git_repo(config: Json<GitRepoConfig>, _admin: Admin) -> Json<GitStatus> {
+pub async fn setup_git_repo(config: Json<GitRepoConfig>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
     info!("Setting up Git repository: {}", config.url);
-    git_result(setup_git_repo_inner(&config).await)
+    let config = config.into_inner();
+    git_result(tasks::blocking(move || async move { setup_git_repo_inner(&config).await }).await)
 }
 
 async fn setup_git_repo_inner(config: &GitRepoConfig) -> Result<GitStatus, AppError> {
     let repo_path = Path::new(ROOT);
+    sparse::normalize_paths(&config.sparse_paths)?;
 
     // Save credentials before any git2 operations (git2 types aren't Send)
-    config.save_creds_if_present().await;
+    let creds = config.credentials().await;
 
     if repo_path.exists() {
         if let Ok(mut entries) = fs::read_dir(repo_path).await {
@@ -237,7 +585,7 @@ async fn setup_git_repo_inner(config: &GitRepoConfig) -> Result<GitStatus, AppEr
         }
     }
 
-    clone_repo(config, repo_path)
+    clone_repo(config, repo_path, creds.as_ref())
 }
 
 fn configure_existing_remote(repo: &Repository, url: &str) -> Result<(), AppError> {
@@ -258,19 +606,20 @@ fn configure_existing_remote(repo: &Repository, url: &str) -> Result<(), AppErro
     Ok(())
 }
 
-fn clone_repo(config: &GitRepoConfig, repo_path: &Path) -> Result<GitStatus, AppError> {
+fn clone_repo(config: &GitRepoConfig, repo_path: &Path, creds: Option<&GitCredentials>) -> Result<GitStatus, AppError> {
     info!("Cloning repository from {}", config.url);
 
-    // Credentials should already be saved by caller before entering sync git2 code
-    let creds = if let (Some(username), Some(token)) = (&config.username, &config.token) {
-        Some(GitCredentials { username: username.clone(), token: token.clone() })
-    } else {
-        None
-    };
+    let sparse_paths = sparse::normalize_paths(&config.sparse_paths)?;
+    let depth = config.depth.filter(|depth| *depth > 0);
 
     let mut builder = git2::build::RepoBuilder::new();
-    if let Some(ref creds) = creds {
-        builder.fetch_options(make_fetch_options(creds));
+    let mut fetch_options = make_fetch_options(creds, &config.url);
+    if let Some(depth) = depth {
+        fetch_options.depth(depth.min(i32::MAX as u32) as i32);
+    }
+    builder.fetch_options(fetch_options);
+    if !sparse_paths.is_empty() {
+        builder.with_checkout(sparse::checkout_paths(Some(&sparse_paths)));
     }
     if let Some(branch) = &config.branch {
         builder.branch(branch);
@@ -278,7 +627,21 @@ fn clone_repo(config: &GitRepoConfig, repo_path: &Path) -> Result<GitStatus, App
 
     let repo = builder.clone(&config.url, repo_path)
         .map_err(|e| AppError::Internal(format!("Failed to clone repository: {}", e)))?;
-    Ok(GitStatus::ok("Repository cloned successfully", head_commit_hash(&repo)))
+    if let Some(depth) = depth {
+        sparse::save_fetch_depth(&repo, depth)?;
+    }
+    if !sparse_paths.is_empty() {
+        sparse::save_sparse_paths(&repo, &sparse_paths)?;
+        let tree = repo.head().and_then(|head| head.peel_to_tree())
+            .map_err(|e| AppError::Internal(format!("Failed to read the cloned tree: {}", e)))?;
+        sparse::sync_index(&repo, &tree)?;
+    }
+    update_submodules_after(
+        &repo,
+        None,
+        Ok(GitStatus::ok("Repository cloned successfully", head_commit_hash(&repo))),
+        creds,
+    )
 }
 
 /// POST /api/git/test
@@ -290,9 +653,7 @@ pub async fn test_git_repo(config: Json<GitRepoConfig>, _admin: Admin) -> Json<G
 
 async fn test_git_repo_inner(config: &GitRepoConfig) -> Result<GitStatus, AppError> {
     // Save credentials before any git2 operations (git2 types aren't Send)
-    if let (Some(username), Some(token)) = (&config.username, &config.token) {
-        save_git_credentials(username, token).await;
-    }
+    let creds = config.credentials().await;
 
     // All git2 operations below are sync — no .await after this point
     let temp_dir = tempfile::tempdir()
@@ -302,43 +663,82 @@ async fn test_git_repo_inner(config: &GitRepoConfig) -> Result<GitStatus, AppErr
     let mut remote = repo.remote("origin", &config.url)
         .map_err(|e| AppError::BadRequest(format!("Invalid repository URL: {}", e)))?;
 
-    let mut callbacks = RemoteCallbacks::new();
-    if let (Some(username), Some(token)) = (&config.username, &config.token) {
-        let username = username.clone();
-        let token = token.clone();
-        callbacks.credentials(move |_url, _username_from_url, _allowed_types| {
-            Cred::userpass_plaintext(&username, &token)
-        });
-    }
-
-    remote.connect_auth(git2::Direction::Fetch, Some(callbacks), None)
+    remote.connect_auth(git2::Direction::Fetch, Some(make_callbacks(creds.as_ref(), &config.url)), None)
         .map_err(|e| AppError::Internal(format!("Connection test failed: {}", e)))?;
 
     Ok(GitStatus::ok("Connection test passed - repository is accessible", None))
 }
 
-/// POST /api/git/pull
-#[post("/git/pull")]
-pub async fn pull_repo(_admin: Admin) -> Json<GitStatus> {
-    info!("Pulling latest changes from repository");
-    git_result(pull_repo_inner().await)
+/// How a manual pull handles local commits that origin lacks.
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum PullMode {
+    /// Only fast-forward; refuse when the branch has diverged
+    #[default]
+    FastForward,
+    /// Merge origin in, stopping for conflict resolution if needed
+    Merge,
+    /// Replay local commits on origin; aborted on conflicts
+    Rebase,
 }
 
-async fn pull_repo_inner() -> Result<GitStatus, AppError> {
+#[derive(Deserialize, Default)]
+#[serde(crate = "rocket::serde")]
+pub struct PullRequest {
+    #[serde(default)]
+    mode: PullMode,
+    /// Stash uncommitted changes before pulling and restore them afterwards
+    #[serde(default)]
+    stash: bool,
+    /// Remote to pull from; origin when unset
+    remote: Option<String>,
+}
+
+/// Pull from origin, or another remote. Without a body only fast-forwards;
+/// `merge` and `rebase` also bring a diverged branch up to date.
+/// ### Examples:
+/// - POST /api/git/pull
+/// - POST /api/git/pull  JSON ```{"mode":"merge"}```
+/// - POST /api/git/pull  JSON ```{"mode":"rebase","stash":true}```
+/// - POST /api/git/pull  JSON ```{"remote":"backup"}```
+#[post("/git/pull", data = "<request>")]
+pub async fn pull_repo(request: Option<Json<PullRequest>>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    let request = request.map(Json::into_inner).unwrap_or_default();
+    let remote = request.remote.unwrap_or_else(|| DEFAULT_REMOTE.to_string());
+    info!("Pulling latest changes from {} ({:?})", remote, request.mode);
+    git_result(tasks::blocking(move || async move { pull_repo_inner(request.mode, request.stash, &remote).await }).await)
+}
+
+async fn pull_repo_inner(mode: PullMode, autostash: bool, remote: &str) -> Result<GitStatus, AppError> {
     // Load credentials before any git2 operations (git2 types aren't Send)
     let creds = load_git_credentials().await;
 
-    let repo = open_repo()?;
-    ensure_clean_workdir(&repo, "pull")?;
-    fetch_origin(&repo, &creds)?;
+    let mut repo = open_repo()?;
+    if repo.state() == git2::RepositoryState::Merge {
+        return Err(AppError::BadRequest("A merge is in progress. Complete or abort it first.".into()));
+    }
+    let stashed = autostash
+        && stash::stash_changes(&mut repo, Some("Auto-stash before pull"), true)?.is_some();
+    let before = head_commit_hash(&repo);
+    let result = pull_with_mode(&repo, mode, remote, &creds);
+    let result = update_submodules_after(&repo, before, result, creds.as_ref());
+    if stashed {
+        return stash::restore_autostash(&mut repo, result);
+    }
+    result
+}
 
-    let branch_name = current_branch_name(&repo)?;
+fn pull_with_mode(repo: &Repository, mode: PullMode, remote_name: &str, creds: &Option<GitCredentials>) -> Result<GitStatus, AppError> {
+    ensure_clean_workdir(repo, "pull")?;
+    fetch_remote(repo, remote_name, creds)?;
+
+    let branch_name = current_branch_name(repo)?;
     let head = repo.head().map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
     let local_oid = head.target()
         .ok_or_else(|| AppError::Internal("HEAD has no target".into()))?;
     let local_commit = repo.find_commit(local_oid)
         .map_err(|e| AppError::Internal(format!("Failed to find local commit: {}", e)))?;
-    let remote = remote_commit(&repo, &branch_name)?;
+    let remote = remote_commit(repo, remote_name, &branch_name)?;
 
     if local_commit.id() == remote.id() {
         return Ok(GitStatus::ok("Already up to date", Some(local_commit.id().to_string())));
@@ -348,21 +748,23 @@ async fn pull_repo_inner() -> Result<GitStatus, AppError> {
         .map_err(|e| AppError::Internal(format!("Failed to calculate repository status: {}", e)))?;
 
     if ahead > 0 {
-        return Err(AppError::BadRequest(format!(
-            "Cannot pull: you have {} unpushed commits. Please push your changes first, or use Force Pull to discard local changes.",
-            ahead
-        )));
+        return match mode {
+            PullMode::FastForward => Err(AppError::BadRequest(format!(
+                "Cannot pull: you have {} unpushed commits. Push them first, pull with merge or rebase, or use Force Pull to discard local changes.",
+                ahead
+            ))),
+            PullMode::Merge => merge::merge_into_head(repo, &format!("{}/{}", remote_name, branch_name), &remote),
+            PullMode::Rebase => {
+                let oid = rebase_onto(repo, &remote)?;
+                Ok(GitStatus::ok(
+                    format!("Rebased {} local commits onto {} new commits from {}", ahead, behind, remote_name),
+                    Some(oid.to_string()),
+                ))
+            }
+        };
     }
 
-    // Fast-forward
-    let local_branch = repo.find_branch(&branch_name, git2::BranchType::Local)
-        .map_err(|e| AppError::Internal(format!("Failed to find local branch: {}", e)))?;
-    local_branch.into_reference().set_target(remote.id(), "Fast-forward pull")
-        .map_err(|e| AppError::Internal(format!("Failed to update branch: {}", e)))?;
-    repo.set_head(&format!("refs/heads/{}", branch_name))
-        .map_err(|e| AppError::Internal(format!("Failed to update HEAD: {}", e)))?;
-    repo.checkout_head(Some(git2::build::CheckoutBuilder::new().force()))
-        .map_err(|e| AppError::Internal(format!("Failed to update working directory: {}", e)))?;
+    fast_forward(repo, &branch_name, remote.id(), "Fast-forward pull")?;
 
     Ok(GitStatus::ok(
         format!("Successfully pulled {} new commits", behind),
@@ -371,12 +773,94 @@ async fn pull_repo_inner() -> Result<GitStatus, AppError> {
 }
 
 /// Internal pull for scheduled operations (no auth guard)
-pub async fn pull_repo_internal() -> Result<GitStatus, String> {
-    info!("Internal pull operation started");
-    // Load credentials before any git2 operations (git2 types aren't Send)
-    let creds = load_git_credentials().await;
+pub async fn pull_repo_internal(policy: PullPolicy) -> Result<GitStatus, String> {
+    info!("Internal pull operation started ({:?})", policy);
+    let _lock = repo_lock::write("automatic pull").await.map_err(|e| e.to_string())?;
+    tasks::blocking(move || async move {
+        let creds = load_git_credentials().await;
+        pull_with_policy(policy, &creds)
+    }).await.map_err(|e| e.to_string())
+}
+
+/// Internal commit of every change for scheduled operations (no auth guard).
+/// Does nothing if the working tree is clean.
+pub async fn commit_all_internal(message: &str) -> Result<GitStatus, String> {
+    let _lock = repo_lock::write("automatic commit").await.map_err(|e| e.to_string())?;
     let repo = open_repo().map_err(|e| e.to_string())?;
-    fetch_and_reset(&repo, &creds).map_err(|e| e.to_string())
+    let statuses = repo.statuses(Some(&mut sparse::status_options(&repo)))
+        .map_err(|e| format!("Failed to check repository status: {}", e))?;
+    if statuses.is_empty() {
+        return Ok(GitStatus::ok("Nothing to commit", head_commit_hash(&repo)));
+    }
+    commit_changes_inner(message, true, None).map_err(|e| e.to_string())
+}
+
+/// Internal push to origin and its mirrors for scheduled operations (no auth
+/// guard). Fails if any remote rejected the push.
+pub async fn push_repo_internal() -> Result<GitStatus, String> {
+    let _lock = repo_lock::write("automatic push").await.map_err(|e| e.to_string())?;
+    let response = tasks::blocking(|| push_repo_inner(None)).await.map_err(|e| e.to_string())?;
+    if !response.success {
+        return Err(response.message);
+    }
+    Ok(GitStatus::ok(response.message, response.commit_hash))
+}
+
+/// Internal commit of only the given paths, relative to ROOT (no auth guard).
+/// Takes no lock of its own: the caller holds the repository lock from writing
+/// the files until the commit is made, and proves it by passing the guard.
+pub fn commit_paths_inner(_lock: &RepoWriteGuard, paths: &[String], message: &str) -> Result<GitStatus, String> {
+    let pathspecs: Vec<&str> = paths.iter().map(String::as_str).collect();
+    commit_pathspecs(message, &pathspecs).map_err(|e| e.to_string())
+}
+
+/// Internal fast-forward of the current branch to `reference` (a branch, tag or
+/// commit; no auth guard). Fetches from origin first when there is one.
+pub async fn fast_forward_internal(reference: &str) -> Result<GitStatus, String> {
+    let _lock = repo_lock::write("automatic fast-forward").await.map_err(|e| e.to_string())?;
+    let reference = reference.to_string();
+    tasks::blocking(move || async move {
+        let creds = load_git_credentials().await;
+        fast_forward_to_ref(&reference, &creds)
+    }).await.map_err(|e| e.to_string())
+}
+
+fn fast_forward_to_ref(reference: &str, creds: &Option<GitCredentials>) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    ensure_clean_workdir(&repo, "fast-forward")?;
+    if repo.find_remote(DEFAULT_REMOTE).is_ok() {
+        fetch_origin(&repo, creds)?;
+    }
+
+    let target = repo.revparse_single(reference)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| AppError::BadRequest(format!("Unknown ref '{}': {}", reference, e)))?;
+    let branch_name = current_branch_name(&repo)?;
+    let head_oid = repo.head().ok().and_then(|head| head.target())
+        .ok_or_else(|| AppError::Internal("HEAD has no target".into()))?;
+
+    if head_oid == target.id() {
+        return Ok(GitStatus::ok(format!("{} is already at {}", branch_name, reference), Some(head_oid.to_string())));
+    }
+    let is_ahead = repo.graph_descendant_of(target.id(), head_oid)
+        .map_err(|e| AppError::Internal(format!("Failed to compare commits: {}", e)))?;
+    if !is_ahead {
+        return Err(AppError::BadRequest(format!(
+            "Cannot fast-forward {} to '{}': the branch has commits that '{}' does not contain",
+            branch_name, reference, reference
+        )));
+    }
+
+    fast_forward(&repo, &branch_name, target.id(), &format!("Fast-forward to {}", reference))?;
+    Ok(GitStatus::ok(
+        format!("Fast-forwarded {} to {}", branch_name, reference),
+        Some(target.id().to_string()),
+    ))
+}
+
+/// Current HEAD commit hash, if ROOT is a repository with at least one commit
+pub fn head_commit_internal() -> Option<String> {
+    open_repo().ok().and_then(|repo| head_commit_hash(&repo))
 }
 
 /// GET /api/git/auto-pull
@@ -398,6 +882,9 @@ pub async fn set_auto_pull_config(config: Json<AutoPullConfig>, _admin: Admin) -
 }
 
 /// GET /api/git/status
+///
+/// Takes no repository lock, so it can report who holds it while a long
+/// operation runs; it only reads and never refreshes the index.
 #[get("/git/status")]
 pub async fn get_git_status(_admin: Admin) -> Json<GitRepoStatus> {
     let repo = match open_repo() {
@@ -430,7 +917,9 @@ pub async fn get_git_status(_admin: Admin) -> Json<GitRepoStatus> {
         (0, 0)
     };
 
-    let statuses = match repo.statuses(None) {
+    let mut status_options = sparse::status_options(&repo);
+    status_options.update_index(false);
+    let statuses = match repo.statuses(Some(&mut status_options)) {
         Ok(s) => s,
         Err(e) => {
             error!("Failed to get repository status: {}", e);
@@ -441,6 +930,11 @@ pub async fn get_git_status(_admin: Admin) -> Json<GitRepoStatus> {
                 behind_count: 0, ahead_count: 0,
                 has_changes: false, has_staged_changes: false, has_unstaged_changes: false,
                 changed_files: vec![], untracked_files: vec![],
+                merge_in_progress: repo.state() == git2::RepositoryState::Merge,
+                submodules: submodules::submodule_states(&repo),
+                shallow: repo.is_shallow(),
+                sparse_paths: sparse::sparse_paths(&repo).unwrap_or_default(),
+                lock_holders: repo_lock::holders(),
             });
         }
     };
@@ -490,30 +984,130 @@ pub async fn get_git_status(_admin: Admin) -> Json<GitRepoStatus> {
         behind_count, ahead_count,
         has_changes, has_staged_changes, has_unstaged_changes,
         changed_files, untracked_files,
+        merge_in_progress: repo.state() == git2::RepositoryState::Merge,
+        submodules: submodules::submodule_states(&repo),
+        shallow: repo.is_shallow(),
+        sparse_paths: sparse::sparse_paths(&repo).unwrap_or_default(),
+        lock_holders: repo_lock::holders(),
     })
 }
 
-/// POST /api/git/commit
+/// Commit staged changes, or every change with `all`, as an author profile
+/// and with optional co-authors
+/// ### Examples:
+/// - POST /api/git/commit  JSON ```{"message":"Fix typo"}```
+/// - POST /api/git/commit  JSON ```{"message":"Update site","all":true,"author":"<profile id>"}```
+/// - POST /api/git/commit  JSON ```{"message":"Pair on footer","co_authors":["Bob <bob@example.com>"]}```
 #[post("/git/commit", data = "<request>")]
-pub async fn commit_changes(request: Json<CommitRequest>, _admin: Admin) -> Json<GitStatus> {
+pub async fn commit_changes(request: Json<CommitRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
     info!("Committing changes with message: {}", request.message);
-    git_result(commit_changes_inner(&request.message))
+    git_result(commit_request(&request).await)
 }
 
-fn commit_changes_inner(message: &str) -> Result<GitStatus, AppError> {
-    let repo = open_repo()?;
+async fn commit_request(request: &CommitRequest) -> Result<GitStatus, AppError> {
+    let author = match &request.author {
+        Some(id) => Some(IdentityConfig::load().await?.author(id)?),
+        None => None,
+    };
+    let message = with_co_authors(&request.message, &request.co_authors)?;
+    commit_changes_inner(&message, request.all, author.as_ref())
+}
+
+/// Append a `Co-authored-by` trailer for each co-author.
+fn with_co_authors(message: &str, co_authors: &[String]) -> Result<String, AppError> {
+    let trailers = co_authors.iter()
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| {
+            Identity::parse(text)
+                .map(|identity| format!("Co-authored-by: {} <{}>", identity.name, identity.email))
+                .ok_or_else(|| AppError::BadRequest(format!("Co-author '{}' is not in the form 'Name <email>'", text)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if trailers.is_empty() {
+        return Ok(message.to_string());
+    }
+    Ok(format!("{}\n\n{}", message.trim_end(), trailers.join("\n")))
+}
 
+fn commit_changes_inner(message: &str, all: bool, author: Option<&Identity>) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
     let mut index = repo.index()
         .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
-    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+    if all {
+        let pathspecs = sparse::all_pathspecs(&repo);
+        stage_pathspecs(&mut index, &pathspecs.iter().map(String::as_str).collect::<Vec<_>>())?;
+    } else if !has_staged_changes(&repo, &mut index)? {
+        return Err(AppError::BadRequest("Nothing staged to commit. Stage changes first or commit all.".into()));
+    }
+    commit_index(&repo, &mut index, message, author)
+}
+
+/// Whether the index differs from HEAD (everything in it counts before the first commit).
+fn has_staged_changes(repo: &Repository, index: &mut git2::Index) -> Result<bool, AppError> {
+    let tree_id = index.write_tree()
+        .map_err(|e| AppError::Internal(format!("Failed to create commit tree: {}", e)))?;
+    let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+    Ok(match head_tree {
+        Some(tree) => tree.id() != tree_id,
+        None => !index.is_empty(),
+    })
+}
+
+/// Stage everything matching `pathspecs`: additions, changes and deletions.
+fn stage_pathspecs(index: &mut git2::Index, pathspecs: &[&str]) -> Result<(), AppError> {
+    index.add_all(pathspecs.iter(), git2::IndexAddOption::DEFAULT, None)
         .map_err(|e| AppError::Internal(format!("Failed to stage changes: {}", e)))?;
-    index.update_all(["*"].iter(), None)
+    index.update_all(pathspecs.iter(), None)
         .map_err(|e| AppError::Internal(format!("Failed to update index: {}", e)))?;
     index.write()
-        .map_err(|e| AppError::Internal(format!("Failed to write index: {}", e)))?;
+        .map_err(|e| AppError::Internal(format!("Failed to write index: {}", e)))
+}
+
+/// Stage everything matching `pathspecs` and commit only those paths. Other
+/// changes already staged stay staged and out of the commit.
+fn commit_pathspecs(message: &str, pathspecs: &[&str]) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let mut index = repo.index()
+        .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
+    stage_pathspecs(&mut index, pathspecs)?;
+    let tree_id = pathspec_tree(&repo, &index, pathspecs)?;
+    commit_tree(&repo, tree_id, message, None)
+}
 
+/// Tree of HEAD with the paths matching `pathspecs` taken from `index`
+fn pathspec_tree(repo: &Repository, index: &git2::Index, pathspecs: &[&str]) -> Result<git2::Oid, AppError> {
+    let index_error = |e: git2::Error| AppError::Internal(format!("Failed to create commit tree: {}", e));
+    let pathspec = git2::Pathspec::new(pathspecs.iter()).map_err(index_error)?;
+    let matches = |path: &[u8]| pathspec.matches_path(Path::new(&*String::from_utf8_lossy(path)), git2::PathspecFlags::DEFAULT);
+
+    // In-memory index starting from HEAD, so the repository's index is untouched
+    let mut tree_index = git2::Index::new().map_err(index_error)?;
+    if let Some(tree) = repo.head().ok().and_then(|head| head.peel_to_tree().ok()) {
+        tree_index.read_tree(&tree).map_err(index_error)?;
+    }
+    let replaced: Vec<Vec<u8>> = tree_index.iter().map(|entry| entry.path).filter(|path| matches(path)).collect();
+    for path in replaced {
+        tree_index.remove(Path::new(&*String::from_utf8_lossy(&path)), 0).map_err(index_error)?;
+    }
+    for entry in index.iter().filter(|entry| matches(&entry.path)) {
+        tree_index.add(&entry).map_err(index_error)?;
+    }
+    tree_index.write_tree_to(repo).map_err(index_error)
+}
+
+/// Commit the index as it is on top of HEAD. The author defaults to the
+/// repository's identity; the configured committer, if any, commits.
+fn commit_index(repo: &Repository, index: &mut git2::Index, message: &str, author: Option<&Identity>) -> Result<GitStatus, AppError> {
     let tree_id = index.write_tree()
         .map_err(|e| AppError::Internal(format!("Failed to create commit tree: {}", e)))?;
+    commit_tree(repo, tree_id, message, author)
+}
+
+/// Commit `tree_id` on top of HEAD
+fn commit_tree(repo: &Repository, tree_id: git2::Oid, message: &str, author: Option<&Identity>) -> Result<GitStatus, AppError> {
+    if repo.state() == git2::RepositoryState::Merge {
+        return Err(AppError::BadRequest("A merge is in progress. Complete or abort it instead of committing.".into()));
+    }
     let tree = repo.find_tree(tree_id)
         .map_err(|e| AppError::Internal(format!("Failed to find commit tree: {}", e)))?;
 
@@ -522,24 +1116,48 @@ fn commit_changes_inner(message: &str) -> Result<GitStatus, AppError> {
         .and_then(|oid| repo.find_commit(oid).ok());
     let parents: Vec<&git2::Commit> = parent_commit.iter().collect();
 
-    let signature = git2::Signature::now("Simple Web", "noreply@simple-web.local")
-        .unwrap_or_else(|_| git2::Signature::now("Unknown", "unknown@local").unwrap());
+    let author = match author {
+        Some(identity) => identity.signature()
+            .map_err(|e| AppError::BadRequest(format!("Invalid author identity: {}", e)))?,
+        None => default_signature(repo),
+    };
+    let committer = configured_committer()
+        .and_then(|identity| identity.signature().ok())
+        .unwrap_or_else(|| author.clone());
 
-    let oid = repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+    let oid = repo.commit(Some("HEAD"), &author, &committer, message, &tree, &parents)
         .map_err(|e| AppError::Internal(format!("Failed to create commit: {}", e)))?;
 
     info!("Commit created successfully: {}", oid);
     Ok(GitStatus::ok("Changes committed successfully", Some(oid.to_string())))
 }
 
-/// POST /api/git/push
-#[post("/git/push")]
-pub async fn push_repo(_admin: Admin) -> Json<GitStatus> {
-    info!("Pushing local commits to remote repository");
-    git_result(push_repo_inner().await)
+#[derive(Deserialize, Default)]
+#[serde(crate = "rocket::serde")]
+pub struct PushRequest {
+    /// Push only to this remote, instead of origin and its mirrors
+    remote: Option<String>,
 }
 
-async fn push_repo_inner() -> Result<GitStatus, AppError> {
+/// Push the current branch to origin and every mirror remote, or to one
+/// remote. Reports the outcome per remote.
+/// ### Examples:
+/// - POST /api/git/push
+/// - POST /api/git/push  JSON ```{"remote":"backup"}```
+#[post("/git/push", data = "<request>")]
+pub async fn push_repo(request: Option<Json<PushRequest>>, _admin: Admin, _lock: RepoWrite) -> Json<PushResponse> {
+    let remote = request.and_then(|r| r.into_inner().remote);
+    info!("Pushing local commits to {}", remote.as_deref().unwrap_or("origin and mirrors"));
+    match tasks::blocking(move || async move { push_repo_inner(remote.as_deref()).await }).await {
+        Ok(response) => Json(response),
+        Err(e) => {
+            error!("{}", e);
+            Json(PushResponse::err(e.to_string()))
+        }
+    }
+}
+
+async fn push_repo_inner(remote: Option<&str>) -> Result<PushResponse, AppError> {
     // Load credentials before any git2 operations (git2 types aren't Send)
     let creds = load_git_credentials().await;
 
@@ -547,24 +1165,19 @@ async fn push_repo_inner() -> Result<GitStatus, AppError> {
     ensure_clean_workdir(&repo, "push")?;
 
     let branch_name = current_branch_name(&repo)?;
-    let mut remote = repo.find_remote("origin")
-        .map_err(|e| AppError::Internal(format!("No remote 'origin' found: {}", e)))?;
-
+    let targets = match remote {
+        Some(name) => vec![name.to_string()],
+        None => remotes::push_targets(&repo)?,
+    };
     let refspec = format!("refs/heads/{}:refs/heads/{}", branch_name, branch_name);
-    let mut push_opts = creds.as_ref().map(make_push_options);
-    remote.push(&[&refspec], push_opts.as_mut())
-        .map_err(|e| AppError::Internal(format!(
-            "Failed to push to remote: {}. Check if you have push permissions.", e
-        )))?;
-
-    Ok(GitStatus::ok("Successfully pushed commits to remote repository", head_commit_hash(&repo)))
+    Ok(remotes::push_to_remotes(&repo, &targets, &[refspec], &creds, head_commit_hash(&repo)))
 }
 
 /// POST /api/git/force-pull
 #[post("/git/force-pull")]
-pub async fn force_pull_repo(_admin: Admin) -> Json<GitStatus> {
+pub async fn force_pull_repo(_admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
     info!("Force pulling - this will overwrite local changes");
-    git_result(force_pull_inner().await)
+    git_result(tasks::blocking(force_pull_inner).await)
 }
 
 async fn force_pull_inner() -> Result<GitStatus, AppError> {
@@ -574,5 +1187,41 @@ async fn force_pull_inner() -> Result<GitStatus, AppError> {
     let repo = open_repo()?;
     let mut result = fetch_and_reset(&repo, &creds)?;
     result.message = "Successfully force pulled - local changes discarded".to_string();
-    Ok(result)
+    update_submodules_after(&repo, None, Ok(result), creds.as_ref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, name: &str, content: &str) {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+
+    #[test]
+    fn pathspec_tree_leaves_out_other_staged_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init(dir.path()).unwrap();
+        let signature = git2::Signature::now("Test", "test@example.com").unwrap();
+        write(dir.path(), "page.html", "old");
+        write(dir.path(), "notes.txt", "old");
+        let mut index = repo.index().unwrap();
+        stage_pathspecs(&mut index, &["*"]).unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        repo.commit(Some("HEAD"), &signature, &signature, "Initial", &tree, &[]).unwrap();
+
+        // An unrelated change the user staged, and the published file
+        write(dir.path(), "notes.txt", "staged by the user");
+        stage_pathspecs(&mut index, &["notes.txt"]).unwrap();
+        write(dir.path(), "page.html", "published");
+        stage_pathspecs(&mut index, &["page.html"]).unwrap();
+
+        let tree = repo.find_tree(pathspec_tree(&repo, &index, &["page.html"]).unwrap()).unwrap();
+        let content = |name: &str| {
+            let blob = tree.get_name(name).unwrap().to_object(&repo).unwrap().peel_to_blob().unwrap();
+            String::from_utf8(blob.content().to_vec()).unwrap()
+        };
+        assert_eq!(content("page.html"), "published");
+        assert_eq!(content("notes.txt"), "old");
+    }
 }
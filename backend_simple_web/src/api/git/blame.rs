@@ -0,0 +1,193 @@
+// backend_simple_web/src/api/git/blame.rs
+use rocket::serde::{json::Json, Serialize};
+use rocket::tokio::fs;
+use std::collections::HashMap;
+use std::path::Path;
+use git2::{BlameOptions, Oid, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::RepoRead;
+use super::open_repo;
+use crate::api::error::AppError;
+use crate::api::path::ValidatedPath;
+
+/// Files larger than this are not blamed
+const MAX_BLAME_BYTES: usize = 2 * 1024 * 1024;
+
+/// Consecutive lines last changed by the same commit
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BlameHunk {
+    /// First line of the hunk, counting from 1
+    pub start_line: usize,
+    pub lines: usize,
+    /// `None` for lines not committed yet
+    pub commit: Option<String>,
+    pub short_hash: Option<String>,
+    pub author: Option<String>,
+    pub email: Option<String>,
+    /// Unix timestamp (seconds) of the author date
+    pub date: Option<i64>,
+    /// First line of the commit message
+    pub summary: Option<String>,
+    /// Path in that commit, when the file was renamed since
+    pub orig_path: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BlameResponse {
+    pub success: bool,
+    pub message: String,
+    pub hunks: Vec<BlameHunk>,
+}
+
+impl BlameResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), hunks: vec![] }
+    }
+}
+
+/// Author, date and summary of the commits a blame points to, looked up once each.
+struct CommitCache<'r> {
+    repo: &'r Repository,
+    commits: HashMap<Oid, (String, String, i64, String)>,
+}
+
+impl CommitCache<'_> {
+    fn hunk(&mut self, id: Oid, start_line: usize, lines: usize, orig_path: Option<String>) -> BlameHunk {
+        let repo = self.repo;
+        let info = self.commits.entry(id).or_insert_with(|| match repo.find_commit(id) {
+            Ok(commit) => {
+                let author = commit.author();
+                (
+                    author.name().unwrap_or("").to_string(),
+                    author.email().unwrap_or("").to_string(),
+                    author.when().seconds(),
+                    commit.summary().unwrap_or("").to_string(),
+                )
+            }
+            Err(_) => Default::default(),
+        });
+        let hash = id.to_string();
+        BlameHunk {
+            start_line,
+            lines,
+            short_hash: Some(hash.chars().take(7).collect()),
+            commit: Some(hash),
+            author: Some(info.0.clone()),
+            email: Some(info.1.clone()),
+            date: Some(info.2),
+            summary: Some(info.3.clone()),
+            orig_path,
+        }
+    }
+}
+
+fn uncommitted_hunk(start_line: usize, lines: usize) -> BlameHunk {
+    BlameHunk {
+        start_line,
+        lines,
+        commit: None,
+        short_hash: None,
+        author: None,
+        email: None,
+        date: None,
+        summary: None,
+        orig_path: None,
+    }
+}
+
+fn line_count(content: &[u8]) -> usize {
+    let newlines = content.iter().filter(|&&b| b == b'\n').count();
+    if content.last().is_some_and(|&b| b != b'\n') { newlines + 1 } else { newlines }
+}
+
+/// Blame `path` as of `rev`, or as in the working tree (`content`) when no rev is given.
+fn blame_inner(path: &str, rev: Option<&str>, content: Option<&[u8]>) -> Result<Vec<BlameHunk>, AppError> {
+    let repo = open_repo()?;
+    let mut opts = BlameOptions::new();
+    if let Some(rev) = rev {
+        let commit = repo.revparse_single(rev)
+            .and_then(|object| object.peel_to_commit())
+            .map_err(|e| AppError::BadRequest(format!("Unknown commit '{}': {}", rev, e.message())))?;
+        opts.newest_commit(commit.id());
+    }
+
+    let committed = match repo.blame_file(Path::new(path), Some(&mut opts)) {
+        Ok(blame) => blame,
+        // Never committed: every line is new
+        Err(e) if e.code() == git2::ErrorCode::NotFound => {
+            return match content {
+                Some(content) if !content.is_empty() => Ok(vec![uncommitted_hunk(1, line_count(content))]),
+                Some(_) => Ok(vec![]),
+                None => Err(AppError::NotFound(format!("'{}' does not exist in {}", path, rev.unwrap_or("HEAD")))),
+            };
+        }
+        Err(e) => return Err(AppError::Internal(format!("Failed to blame '{}': {}", path, e.message()))),
+    };
+    let blame = match content {
+        Some(content) => committed.blame_buffer(content)
+            .map_err(|e| AppError::Internal(format!("Failed to blame working copy of '{}': {}", path, e.message())))?,
+        None => committed,
+    };
+
+    let mut cache = CommitCache { repo: &repo, commits: HashMap::new() };
+    let hunks = blame.iter().map(|hunk| {
+        let (start, lines) = (hunk.final_start_line(), hunk.lines_in_hunk());
+        let id = hunk.final_commit_id();
+        if id.is_zero() {
+            uncommitted_hunk(start, lines)
+        } else {
+            let orig_path = hunk.path()
+                .map(|p| p.to_string_lossy().into_owned())
+                .filter(|p| p != path);
+            cache.hunk(id, start, lines, orig_path)
+        }
+    }).collect();
+    Ok(hunks)
+}
+
+/// Who last changed each line of a file, as ranges of lines per commit.
+/// Without `rev` the working copy is blamed, and lines not committed yet come
+/// back without a commit.
+/// ### Examples:
+/// - GET /api/git/blame?path=index.html
+/// - GET /api/git/blame?path=blog/post.html&rev=HEAD~5
+#[get("/git/blame?<path>&<rev>")]
+pub async fn get_blame(path: &str, rev: Option<&str>, _admin: Admin, _lock: RepoRead) -> Json<BlameResponse> {
+    let rel = path.trim().trim_matches('/');
+    let rev = rev.map(str::trim).filter(|r| !r.is_empty());
+
+    // Read the working copy before touching the repository (git2 types aren't Send)
+    let content = match rev {
+        Some(_) => {
+            if let Err(e) = ValidatedPath::new(rel) {
+                return Json(BlameResponse::err(e.to_string()));
+            }
+            None
+        }
+        None => {
+            let file = match ValidatedPath::existing(rel).await {
+                Ok(file) => file,
+                Err(e) => return Json(BlameResponse::err(e.to_string())),
+            };
+            match fs::read(file.as_path()).await {
+                Ok(content) => Some(content),
+                Err(e) => return Json(BlameResponse::err(format!("Failed to read '{}': {}", rel, e))),
+            }
+        }
+    };
+    if content.as_ref().is_some_and(|c| c.len() > MAX_BLAME_BYTES || c.contains(&0)) {
+        return Json(BlameResponse::err(format!("'{}' is binary or too large to blame", rel)));
+    }
+
+    match blame_inner(rel, rev, content.as_deref()) {
+        Ok(hunks) => Json(BlameResponse {
+            success: true,
+            message: format!("{} hunks", hunks.len()),
+            hunks,
+        }),
+        Err(e) => Json(BlameResponse::err(e.to_string())),
+    }
+}
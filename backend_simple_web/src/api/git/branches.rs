@@ -0,0 +1,383 @@
+// backend_simple_web/src/api/git/branches.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use std::path::PathBuf;
+use git2::{BranchType, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::{self, RepoWrite};
+use super::{
+    fetch_remote, git_result, head_commit_hash, load_git_credentials, open_repo, sparse, stash,
+    uncommitted_changes, GitStatus, DEFAULT_REMOTE,
+};
+use crate::api::error::AppError;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BranchInfo {
+    /// Short name, e.g. `main` or `origin/main`
+    pub name: String,
+    pub is_head: bool,
+    /// Upstream of a local branch, e.g. `origin/main`
+    pub upstream: Option<String>,
+    /// Commits on the branch that its upstream lacks
+    pub ahead: usize,
+    /// Commits on the upstream that the branch lacks
+    pub behind: usize,
+    pub commit: Option<String>,
+    /// First line of the tip commit's message
+    pub summary: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BranchListResponse {
+    pub success: bool,
+    pub message: String,
+    pub current: Option<String>,
+    pub local: Vec<BranchInfo>,
+    pub remote: Vec<BranchInfo>,
+}
+
+impl BranchListResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), current: None, local: vec![], remote: vec![] }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateBranchRequest {
+    name: String,
+    /// Branch, tag or commit to start from (default: HEAD)
+    from: Option<String>,
+    /// Switch to the new branch after creating it
+    #[serde(default)]
+    checkout: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CheckoutRequest {
+    /// Local branch, or a remote branch (`origin/x`) to create a tracking branch for
+    name: String,
+    /// Keep uncommitted changes when switching instead of refusing
+    #[serde(default)]
+    carry_changes: bool,
+    /// Stash uncommitted changes before switching; they stay in `stash@{0}`
+    #[serde(default)]
+    stash: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RenameBranchRequest {
+    name: String,
+    new_name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct UpstreamRequest {
+    name: String,
+    /// Remote branch such as `origin/main`; `None` removes the upstream
+    upstream: Option<String>,
+}
+
+fn validate_branch_name(name: &str) -> Result<(), AppError> {
+    let valid = git2::Branch::name_is_valid(name).unwrap_or(false);
+    if name.is_empty() || !valid {
+        return Err(AppError::BadRequest(format!("'{}' is not a valid branch name", name)));
+    }
+    Ok(())
+}
+
+fn find_local<'a>(repo: &'a Repository, name: &str) -> Result<git2::Branch<'a>, AppError> {
+    repo.find_branch(name, BranchType::Local)
+        .map_err(|_| AppError::NotFound(format!("Branch '{}' not found", name)))
+}
+
+fn branch_info(repo: &Repository, branch: &git2::Branch) -> BranchInfo {
+    let name = branch.name().ok().flatten().unwrap_or_default().to_string();
+    let tip = branch.get().peel_to_commit().ok();
+    let upstream = branch.upstream().ok();
+    let (ahead, behind) = match (tip.as_ref(), upstream.as_ref().and_then(|u| u.get().target())) {
+        (Some(tip), Some(upstream)) => repo.graph_ahead_behind(tip.id(), upstream).unwrap_or((0, 0)),
+        _ => (0, 0),
+    };
+
+    BranchInfo {
+        name,
+        is_head: branch.is_head(),
+        upstream: upstream.and_then(|u| u.name().ok().flatten().map(str::to_string)),
+        ahead,
+        behind,
+        commit: tip.as_ref().map(|c| c.id().to_string()),
+        summary: tip.as_ref().and_then(|c| c.summary().map(str::to_string)),
+    }
+}
+
+fn list_branches_inner(repo: &Repository) -> Result<BranchListResponse, AppError> {
+    let mut local = Vec::new();
+    let mut remote = Vec::new();
+    let branches = repo.branches(None)
+        .map_err(|e| AppError::Internal(format!("Failed to list branches: {}", e)))?;
+    for entry in branches {
+        let (branch, kind) = entry
+            .map_err(|e| AppError::Internal(format!("Failed to read branch: {}", e)))?;
+        // `origin/HEAD` is a pointer to the remote's default branch, not a branch
+        if kind == BranchType::Remote && branch.get().symbolic_target().is_some() {
+            continue;
+        }
+        match kind {
+            BranchType::Local => local.push(branch_info(repo, &branch)),
+            BranchType::Remote => remote.push(branch_info(repo, &branch)),
+        }
+    }
+    local.sort_by(|a, b| a.name.cmp(&b.name));
+    remote.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let current = repo.head().ok()
+        .filter(|head| head.is_branch())
+        .and_then(|head| head.shorthand().map(str::to_string));
+    Ok(BranchListResponse {
+        success: true,
+        message: "Branches retrieved successfully".to_string(),
+        current,
+        local,
+        remote,
+    })
+}
+
+/// Check out a local branch and point HEAD at it. Uncommitted changes are kept
+/// unless they conflict with the target, in which case nothing is changed.
+fn switch_to(repo: &Repository, branch: &git2::Branch) -> Result<(), AppError> {
+    let refname = branch.get().name()
+        .ok_or_else(|| AppError::Internal("Branch name is not valid UTF-8".into()))?;
+    let tree = branch.get().peel_to_tree()
+        .map_err(|e| AppError::Internal(format!("Failed to read branch tree: {}", e)))?;
+    repo.checkout_tree(tree.as_object(), Some(sparse::checkout(repo).safe()))
+        .map_err(|e| AppError::BadRequest(format!(
+            "Cannot switch branches: {}. Commit or discard the conflicting changes first.", e.message()
+        )))?;
+    sparse::sync_index(repo, &tree)?;
+    repo.set_head(refname)
+        .map_err(|e| AppError::Internal(format!("Failed to update HEAD: {}", e)))
+}
+
+fn checkout_inner(name: &str, carry_changes: bool, stash: bool) -> Result<GitStatus, AppError> {
+    let mut repo = open_repo()?;
+    let mut changes = uncommitted_changes(&repo)?;
+    let mut stashed = 0;
+    if changes > 0 && stash && !carry_changes {
+        let message = format!("Auto-stash before switching to {}", name);
+        if stash::stash_changes(&mut repo, Some(&message), true)?.is_some() {
+            stashed = changes;
+            changes = 0;
+        }
+    }
+    if changes > 0 && !carry_changes {
+        return Err(AppError::BadRequest(format!(
+            "Cannot switch branches with {} uncommitted changes. Commit or stash them first, or switch carrying them along.", changes
+        )));
+    }
+
+    let branch = match repo.find_branch(name, BranchType::Local) {
+        Ok(branch) => branch,
+        Err(_) => {
+            // A remote branch: create a local branch tracking it
+            let remote = repo.find_branch(name, BranchType::Remote)
+                .map_err(|_| AppError::NotFound(format!("Branch '{}' not found", name)))?;
+            let local_name = name.split_once('/').map(|(_, rest)| rest).unwrap_or(name);
+            if repo.find_branch(local_name, BranchType::Local).is_ok() {
+                return Err(AppError::BadRequest(format!(
+                    "A local branch '{}' already exists; switch to it instead", local_name
+                )));
+            }
+            let tip = remote.get().peel_to_commit()
+                .map_err(|e| AppError::Internal(format!("Failed to get remote commit: {}", e)))?;
+            let mut local = repo.branch(local_name, &tip, false)
+                .map_err(|e| AppError::Internal(format!("Failed to create branch: {}", e)))?;
+            local.set_upstream(Some(name))
+                .map_err(|e| AppError::Internal(format!("Failed to set upstream: {}", e)))?;
+            local
+        }
+    };
+
+    if branch.is_head() {
+        return Ok(GitStatus::ok(format!("Already on {}", name), head_commit_hash(&repo)));
+    }
+    switch_to(&repo, &branch)?;
+    let branch_name = branch.name().ok().flatten().unwrap_or(name).to_string();
+    let message = if changes > 0 {
+        format!("Switched to {}, keeping {} uncommitted changes", branch_name, changes)
+    } else if stashed > 0 {
+        format!("Stashed {} uncommitted changes as stash@{{0}} and switched to {}", stashed, branch_name)
+    } else {
+        format!("Switched to {}", branch_name)
+    };
+    Ok(GitStatus::ok(message, head_commit_hash(&repo)))
+}
+
+fn create_inner(request: &CreateBranchRequest) -> Result<GitStatus, AppError> {
+    let name = request.name.trim();
+    validate_branch_name(name)?;
+    let repo = open_repo()?;
+    if repo.find_branch(name, BranchType::Local).is_ok() {
+        return Err(AppError::BadRequest(format!("Branch '{}' already exists", name)));
+    }
+
+    let from = request.from.as_deref().map(str::trim).filter(|f| !f.is_empty()).unwrap_or("HEAD");
+    let start = repo.revparse_single(from)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| AppError::BadRequest(format!("Unknown ref '{}': {}", from, e)))?;
+    let branch = repo.branch(name, &start, false)
+        .map_err(|e| AppError::Internal(format!("Failed to create branch: {}", e)))?;
+
+    if request.checkout {
+        // A new branch at HEAD never conflicts; one elsewhere may, like any switch
+        switch_to(&repo, &branch)?;
+        return Ok(GitStatus::ok(format!("Created and switched to {} at {}", name, from), Some(start.id().to_string())));
+    }
+    Ok(GitStatus::ok(format!("Created {} at {}", name, from), Some(start.id().to_string())))
+}
+
+fn rename_inner(name: &str, new_name: &str) -> Result<GitStatus, AppError> {
+    let new_name = new_name.trim();
+    validate_branch_name(new_name)?;
+    let repo = open_repo()?;
+    let mut branch = find_local(&repo, name)?;
+    if repo.find_branch(new_name, BranchType::Local).is_ok() {
+        return Err(AppError::BadRequest(format!("Branch '{}' already exists", new_name)));
+    }
+    let renamed = branch.rename(new_name, false)
+        .map_err(|e| AppError::Internal(format!("Failed to rename branch: {}", e)))?;
+    Ok(GitStatus::ok(
+        format!("Renamed {} to {}", name, new_name),
+        renamed.get().target().map(|oid| oid.to_string()),
+    ))
+}
+
+fn delete_inner(name: &str, force: bool) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let mut branch = find_local(&repo, name)?;
+    if branch.is_head() {
+        return Err(AppError::BadRequest(format!("Cannot delete {}: it is the current branch", name)));
+    }
+
+    let tip = branch.get().target();
+    if !force {
+        let head = repo.head().ok().and_then(|head| head.target());
+        let merged = match (tip, head) {
+            (Some(tip), Some(head)) => tip == head || repo.graph_descendant_of(head, tip).unwrap_or(false),
+            _ => false,
+        };
+        if !merged {
+            return Err(AppError::BadRequest(format!(
+                "{} has commits that are not in the current branch. Delete it with force to discard them.", name
+            )));
+        }
+    }
+
+    branch.delete()
+        .map_err(|e| AppError::Internal(format!("Failed to delete branch: {}", e)))?;
+    Ok(GitStatus::ok(format!("Deleted {}", name), tip.map(|oid| oid.to_string())))
+}
+
+fn set_upstream_inner(name: &str, upstream: Option<&str>) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let mut branch = find_local(&repo, name)?;
+    if let Some(upstream) = upstream {
+        repo.find_branch(upstream, BranchType::Remote)
+            .map_err(|_| AppError::NotFound(format!("Remote branch '{}' not found", upstream)))?;
+    }
+    branch.set_upstream(upstream)
+        .map_err(|e| AppError::Internal(format!("Failed to set upstream: {}", e)))?;
+    let message = match upstream {
+        Some(upstream) => format!("{} now tracks {}", name, upstream),
+        None => format!("{} no longer tracks a remote branch", name),
+    };
+    Ok(GitStatus::ok(message, branch.get().target().map(|oid| oid.to_string())))
+}
+
+// --- Route handlers ---
+
+/// List local and remote branches with ahead/behind counts against each
+/// local branch's upstream. With `fetch=true` origin, or `remote`, is fetched first.
+/// ### Examples:
+/// - GET /api/git/branches
+/// - GET /api/git/branches?fetch=true
+/// - GET /api/git/branches?fetch=true&remote=backup
+#[get("/git/branches?<fetch>&<remote>")]
+pub async fn list_branches(_admin: Admin, fetch: Option<bool>, remote: Option<&str>) -> Result<Json<BranchListResponse>, AppError> {
+    let fetch = fetch.unwrap_or(false);
+    // Fetching updates the remote-tracking refs, so it holds the lock alone
+    let _lock = if fetch {
+        repo_lock::write("GET /api/git/branches?fetch=true").await?.into()
+    } else {
+        repo_lock::read("GET /api/git/branches").await?
+    };
+    // Load credentials before any git2 operations (git2 types aren't Send)
+    let creds = if fetch { Some(load_git_credentials().await) } else { None };
+
+    let repo = match open_repo() {
+        Ok(repo) => repo,
+        Err(e) => return Ok(Json(BranchListResponse::err(e.to_string()))),
+    };
+    let fetched = match creds {
+        Some(creds) => fetch_remote(&repo, remote.unwrap_or(DEFAULT_REMOTE), &creds),
+        None => Ok(()),
+    };
+    Ok(match fetched.and_then(|_| list_branches_inner(&repo)) {
+        Ok(list) => Json(list),
+        Err(e) => Json(BranchListResponse::err(e.to_string())),
+    })
+}
+
+/// Create a branch from any ref, optionally switching to it
+/// ### Examples:
+/// - POST /api/git/branches  JSON ```{"name":"redesign","from":"origin/main","checkout":true}```
+#[post("/git/branches", data = "<request>")]
+pub async fn create_branch(request: Json<CreateBranchRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    info!("Creating branch {}", request.name);
+    git_result(create_inner(&request))
+}
+
+/// Switch branches. Refuses when there are uncommitted changes unless
+/// `carry_changes` or `stash` is set; a remote branch gets a local tracking branch.
+/// ### Examples:
+/// - POST /api/git/branches/checkout  JSON ```{"name":"redesign"}```
+/// - POST /api/git/branches/checkout  JSON ```{"name":"origin/feature","carry_changes":true}```
+/// - POST /api/git/branches/checkout  JSON ```{"name":"main","stash":true}```
+#[post("/git/branches/checkout", data = "<request>")]
+pub async fn checkout_branch(request: Json<CheckoutRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    info!("Switching to branch {}", request.name);
+    git_result(checkout_inner(request.name.trim(), request.carry_changes, request.stash))
+}
+
+/// Rename a local branch
+/// ### Examples:
+/// - POST /api/git/branches/rename  JSON ```{"name":"redesign","new_name":"redesign-2026"}```
+#[post("/git/branches/rename", data = "<request>")]
+pub async fn rename_branch(request: Json<RenameBranchRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(rename_inner(request.name.trim(), &request.new_name))
+}
+
+/// Set or clear the upstream of a local branch
+/// ### Examples:
+/// - POST /api/git/branches/upstream  JSON ```{"name":"redesign","upstream":"origin/redesign"}```
+#[post("/git/branches/upstream", data = "<request>")]
+pub async fn set_branch_upstream(request: Json<UpstreamRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(set_upstream_inner(request.name.trim(), request.upstream.as_deref().map(str::trim)))
+}
+
+/// Delete a local branch. Branches with commits that are not in the current
+/// branch are only deleted with `force=true`.
+/// ### Examples:
+/// - DELETE /api/git/branches/redesign
+/// - DELETE /api/git/branches/feature/old?force=true
+#[delete("/git/branches/<name..>?<force>")]
+pub async fn delete_branch(name: PathBuf, force: Option<bool>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    let name = name.to_string_lossy().into_owned();
+    info!("Deleting branch {}", name);
+    git_result(delete_inner(&name, force.unwrap_or(false)))
+}
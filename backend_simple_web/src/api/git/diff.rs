@@ -0,0 +1,280 @@
+// backend_simple_web/src/api/git/diff.rs
+use rocket::serde::{json::Json, Serialize};
+use git2::{Diff, DiffFindOptions, DiffOptions, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::RepoRead;
+use super::{history::delta_status, open_repo};
+use crate::api::error::AppError;
+
+/// Lines returned across all files; later hunks are dropped and flagged
+const MAX_LINES: usize = 20_000;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffLine {
+    /// context, addition or deletion
+    pub kind: &'static str,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+    /// Line content without the trailing newline
+    pub content: String,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffHunk {
+    /// `@@ -a,b +c,d @@` line
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffFile {
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    /// added, deleted, modified, renamed, copied, typechange or untracked
+    pub status: &'static str,
+    pub binary: bool,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub hunks: Vec<DiffHunk>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffResponse {
+    pub success: bool,
+    pub message: String,
+    pub files: Vec<DiffFile>,
+    /// Unified diff text, when asked for with `raw=true`
+    pub raw: Option<String>,
+    /// Set when hunks were left out because the diff is too large
+    pub truncated: bool,
+}
+
+impl DiffResponse {
+    pub(super) fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), files: vec![], raw: None, truncated: false }
+    }
+}
+
+/// What to compare.
+pub enum DiffTarget<'a> {
+    /// Working tree against the index (unstaged changes)
+    WorkingTree,
+    /// Index against HEAD (staged changes)
+    Staged,
+    /// A commit against its first parent
+    Commit(&'a str),
+    /// Two refs or commits
+    Range(&'a str, &'a str),
+}
+
+fn resolve_tree<'r>(repo: &'r Repository, rev: &str) -> Result<git2::Tree<'r>, AppError> {
+    repo.revparse_single(rev)
+        .and_then(|object| object.peel_to_tree())
+        .map_err(|e| AppError::BadRequest(format!("Unknown ref '{}': {}", rev, e)))
+}
+
+/// Build a diff for the target, limited to `path` when given, with renames detected.
+pub fn build_diff<'r>(repo: &'r Repository, target: &DiffTarget, path: Option<&str>, context_lines: u32) -> Result<Diff<'r>, AppError> {
+    let mut opts = DiffOptions::new();
+    opts.context_lines(context_lines);
+    if let Some(path) = path {
+        opts.pathspec(path);
+    }
+
+    let diff = match target {
+        DiffTarget::WorkingTree => {
+            opts.include_untracked(true).recurse_untracked_dirs(true).show_untracked_content(true);
+            repo.diff_index_to_workdir(None, Some(&mut opts))
+        }
+        DiffTarget::Staged => {
+            // Before the first commit everything in the index is new
+            let head = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+            repo.diff_tree_to_index(head.as_ref(), None, Some(&mut opts))
+        }
+        DiffTarget::Commit(rev) => {
+            let commit = repo.revparse_single(rev)
+                .and_then(|object| object.peel_to_commit())
+                .map_err(|e| AppError::BadRequest(format!("Unknown commit '{}': {}", rev, e)))?;
+            let parent = commit.parent(0).ok().and_then(|parent| parent.tree().ok());
+            let tree = commit.tree()
+                .map_err(|e| AppError::Internal(format!("Failed to read commit tree: {}", e)))?;
+            repo.diff_tree_to_tree(parent.as_ref(), Some(&tree), Some(&mut opts))
+        }
+        DiffTarget::Range(from, to) => {
+            let old = resolve_tree(repo, from)?;
+            let new = resolve_tree(repo, to)?;
+            repo.diff_tree_to_tree(Some(&old), Some(&new), Some(&mut opts))
+        }
+    };
+    let mut diff = diff.map_err(|e| AppError::Internal(format!("Failed to compute diff: {}", e)))?;
+
+    let mut find = DiffFindOptions::new();
+    find.renames(true).for_untracked(true);
+    diff.find_similar(Some(&mut find))
+        .map_err(|e| AppError::Internal(format!("Failed to detect renames: {}", e)))?;
+    Ok(diff)
+}
+
+fn line_kind(origin: char) -> Option<&'static str> {
+    match origin {
+        ' ' => Some("context"),
+        '+' => Some("addition"),
+        '-' => Some("deletion"),
+        // File headers and "no newline at end of file" markers
+        _ => None,
+    }
+}
+
+/// Structured files and hunks of a diff. Returns the files and whether hunks
+/// were dropped because of `MAX_LINES`.
+pub fn diff_files(diff: &Diff) -> Result<(Vec<DiffFile>, bool), git2::Error> {
+    let mut files = Vec::new();
+    let mut budget = MAX_LINES;
+    let mut truncated = false;
+
+    for idx in 0..diff.deltas().len() {
+        let Some(delta) = diff.get_delta(idx) else { continue };
+        let path_of = |file: git2::DiffFile| file.path().map(|p| p.to_string_lossy().into_owned());
+        let mut entry = DiffFile {
+            old_path: path_of(delta.old_file()),
+            new_path: path_of(delta.new_file()),
+            status: delta_status(delta.status()),
+            binary: false,
+            insertions: 0,
+            deletions: 0,
+            hunks: Vec::new(),
+        };
+
+        if let Some(patch) = git2::Patch::from_diff(diff, idx)? {
+            entry.binary = patch.delta().flags().is_binary();
+            let (_, insertions, deletions) = patch.line_stats()?;
+            entry.insertions = insertions;
+            entry.deletions = deletions;
+
+            for hunk_idx in 0..patch.num_hunks() {
+                let (hunk, line_count) = patch.hunk(hunk_idx)?;
+                if line_count > budget {
+                    truncated = true;
+                    break;
+                }
+                budget -= line_count;
+
+                let mut lines = Vec::with_capacity(line_count);
+                for line_idx in 0..line_count {
+                    let line = patch.line_in_hunk(hunk_idx, line_idx)?;
+                    let Some(kind) = line_kind(line.origin()) else { continue };
+                    lines.push(DiffLine {
+                        kind,
+                        old_lineno: line.old_lineno(),
+                        new_lineno: line.new_lineno(),
+                        content: String::from_utf8_lossy(line.content()).trim_end_matches(['\n', '\r']).to_string(),
+                    });
+                }
+                entry.hunks.push(DiffHunk {
+                    header: String::from_utf8_lossy(hunk.header()).trim_end().to_string(),
+                    old_start: hunk.old_start(),
+                    old_lines: hunk.old_lines(),
+                    new_start: hunk.new_start(),
+                    new_lines: hunk.new_lines(),
+                    lines,
+                });
+            }
+        } else {
+            entry.binary = delta.flags().is_binary();
+        }
+        files.push(entry);
+    }
+    Ok((files, truncated))
+}
+
+/// The diff as unified patch text.
+fn raw_patch(diff: &Diff) -> Result<String, git2::Error> {
+    let mut text = String::new();
+    diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
+        if matches!(line.origin(), ' ' | '+' | '-') {
+            text.push(line.origin());
+        }
+        text.push_str(&String::from_utf8_lossy(line.content()));
+        true
+    })?;
+    Ok(text)
+}
+
+/// Query string of `GET /api/git/diff`
+#[derive(FromForm)]
+pub struct DiffQuery {
+    /// Compare the index with HEAD instead of the working tree with the index
+    staged: Option<bool>,
+    /// Show a commit against its first parent
+    commit: Option<String>,
+    /// Compare two refs or commits
+    from: Option<String>,
+    to: Option<String>,
+    /// Limit to a file or directory relative to ROOT
+    path: Option<String>,
+    /// Also return unified diff text
+    raw: Option<bool>,
+    /// Context lines around changes (default 3)
+    context: Option<u32>,
+}
+
+impl DiffQuery {
+    fn target(&self) -> Result<DiffTarget<'_>, AppError> {
+        match (self.commit.as_deref(), self.from.as_deref(), self.to.as_deref()) {
+            (Some(commit), None, None) => Ok(DiffTarget::Commit(commit)),
+            (None, Some(from), Some(to)) => Ok(DiffTarget::Range(from, to)),
+            (None, None, None) if self.staged.unwrap_or(false) => Ok(DiffTarget::Staged),
+            (None, None, None) => Ok(DiffTarget::WorkingTree),
+            _ => Err(AppError::BadRequest(
+                "Give either `commit`, or both `from` and `to`, or neither for the working tree".into()
+            )),
+        }
+    }
+}
+
+fn diff_inner(query: &DiffQuery) -> Result<DiffResponse, AppError> {
+    let target = query.target()?;
+    let repo = open_repo()?;
+    let path = query.path.as_deref().map(|p| p.trim().trim_matches('/')).filter(|p| !p.is_empty());
+    let diff = build_diff(&repo, &target, path, query.context.unwrap_or(3).min(100))?;
+
+    let (files, truncated) = diff_files(&diff)
+        .map_err(|e| AppError::Internal(format!("Failed to read diff: {}", e)))?;
+    let raw = if query.raw.unwrap_or(false) {
+        Some(raw_patch(&diff).map_err(|e| AppError::Internal(format!("Failed to format diff: {}", e)))?)
+    } else {
+        None
+    };
+    Ok(DiffResponse {
+        success: true,
+        message: format!("{} files changed", files.len()),
+        files,
+        raw,
+        truncated,
+    })
+}
+
+/// Structured diff with hunks, renames and binary files flagged. Without
+/// parameters it shows unstaged changes; `staged=true` shows the index against
+/// HEAD, `commit` a commit against its parent and `from`/`to` two refs.
+/// ### Examples:
+/// - GET /api/git/diff
+/// - GET /api/git/diff?staged=true&path=index.html
+/// - GET /api/git/diff?commit=HEAD~1&raw=true
+/// - GET /api/git/diff?from=main&to=redesign
+#[get("/git/diff?<query..>")]
+pub async fn get_diff(_admin: Admin, _lock: RepoRead, query: DiffQuery) -> Json<DiffResponse> {
+    match diff_inner(&query) {
+        Ok(response) => Json(response),
+        Err(e) => Json(DiffResponse::err(e.to_string())),
+    }
+}
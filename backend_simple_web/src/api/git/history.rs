@@ -0,0 +1,277 @@
+// backend_simple_web/src/api/git/history.rs
+use rocket::serde::{json::Json, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+use git2::{Commit, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::RepoRead;
+use super::{open_repo, tags::tags_by_commit};
+use crate::api::error::AppError;
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 200;
+/// Files listed per commit; the totals in `stats` always cover every file
+const MAX_FILES_PER_COMMIT: usize = 100;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct LogAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct LogFile {
+    pub path: String,
+    /// added, modified, deleted, renamed, ...
+    pub status: String,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct LogStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct LogEntry {
+    pub hash: String,
+    pub short_hash: String,
+    pub author: LogAuthor,
+    /// Unix timestamp (seconds) of the author date
+    pub date: i64,
+    pub message: String,
+    pub parents: Vec<String>,
+    pub stats: LogStats,
+    /// Changed files, compared with the first parent
+    pub files: Vec<LogFile>,
+    /// Tags pointing at this commit
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct LogResponse {
+    pub success: bool,
+    pub message: String,
+    pub entries: Vec<LogEntry>,
+    /// Whether another page follows
+    pub has_more: bool,
+}
+
+impl LogResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), entries: vec![], has_more: false }
+    }
+}
+
+/// Filters for a log query; every given filter has to match.
+struct LogFilter<'a> {
+    rev: Option<&'a str>,
+    /// File or directory relative to ROOT
+    path: Option<&'a str>,
+    /// Case-insensitive substring of the author name or email
+    author: Option<&'a str>,
+    /// Unix timestamps (seconds), inclusive
+    since: Option<i64>,
+    until: Option<i64>,
+    /// Case-insensitive substring of the commit message
+    search: Option<&'a str>,
+}
+
+impl LogFilter<'_> {
+    fn matches(&self, repo: &Repository, commit: &Commit) -> bool {
+        let time = commit.author().when().seconds();
+        if self.since.is_some_and(|since| time < since) || self.until.is_some_and(|until| time > until) {
+            return false;
+        }
+        if let Some(author) = self.author {
+            let author = author.to_lowercase();
+            let signature = commit.author();
+            let name = signature.name().unwrap_or("").to_lowercase();
+            let email = signature.email().unwrap_or("").to_lowercase();
+            if !name.contains(&author) && !email.contains(&author) {
+                return false;
+            }
+        }
+        if let Some(search) = self.search {
+            let message = commit.message().unwrap_or("").to_lowercase();
+            if !message.contains(&search.to_lowercase()) {
+                return false;
+            }
+        }
+        match self.path {
+            Some(path) => touches_path(repo, commit, Path::new(path)),
+            None => true,
+        }
+    }
+}
+
+/// Id of the tree entry at `path` in a commit, `None` when it does not exist.
+fn entry_id(commit: &Commit, path: &Path) -> Option<git2::Oid> {
+    commit.tree().ok()?.get_path(path).ok().map(|entry| entry.id())
+}
+
+/// Whether a commit changed `path` compared with its first parent.
+fn touches_path(repo: &Repository, commit: &Commit, path: &Path) -> bool {
+    let current = entry_id(commit, path);
+    match commit.parent_ids().next() {
+        Some(parent_id) => {
+            let parent = repo.find_commit(parent_id).ok();
+            current != parent.as_ref().and_then(|parent| entry_id(parent, path))
+        }
+        None => current.is_some(),
+    }
+}
+
+pub(super) fn delta_status(status: git2::Delta) -> &'static str {
+    match status {
+        git2::Delta::Added => "added",
+        git2::Delta::Deleted => "deleted",
+        git2::Delta::Modified => "modified",
+        git2::Delta::Renamed => "renamed",
+        git2::Delta::Copied => "copied",
+        git2::Delta::Typechange => "typechange",
+        git2::Delta::Untracked => "untracked",
+        _ => "other",
+    }
+}
+
+/// Stats and changed files of a commit against its first parent.
+fn commit_changes(repo: &Repository, commit: &Commit) -> Result<(LogStats, Vec<LogFile>), git2::Error> {
+    let tree = commit.tree()?;
+    let parent_tree = match commit.parent(0) {
+        Ok(parent) => Some(parent.tree()?),
+        Err(_) => None,
+    };
+    let mut diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+    diff.find_similar(None)?;
+
+    let stats = diff.stats()?;
+    let mut files = Vec::new();
+    for (idx, delta) in diff.deltas().enumerate().take(MAX_FILES_PER_COMMIT) {
+        let path = delta.new_file().path().or_else(|| delta.old_file().path())
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let (insertions, deletions) = git2::Patch::from_diff(&diff, idx)?
+            .map(|patch| patch.line_stats().map(|(_, ins, del)| (ins, del)))
+            .transpose()?
+            .unwrap_or((0, 0));
+        files.push(LogFile { path, status: delta_status(delta.status()).to_string(), insertions, deletions });
+    }
+
+    Ok((
+        LogStats { files_changed: stats.files_changed(), insertions: stats.insertions(), deletions: stats.deletions() },
+        files,
+    ))
+}
+
+fn log_entry(repo: &Repository, commit: &Commit, tags: &HashMap<git2::Oid, Vec<String>>) -> Result<LogEntry, git2::Error> {
+    let (stats, files) = commit_changes(repo, commit)?;
+    let hash = commit.id().to_string();
+    let author = commit.author();
+    Ok(LogEntry {
+        short_hash: hash.chars().take(7).collect(),
+        hash,
+        author: LogAuthor {
+            name: author.name().unwrap_or("").to_string(),
+            email: author.email().unwrap_or("").to_string(),
+        },
+        date: author.when().seconds(),
+        message: commit.message().unwrap_or("").to_string(),
+        parents: commit.parent_ids().map(|id| id.to_string()).collect(),
+        stats,
+        files,
+        tags: tags.get(&commit.id()).cloned().unwrap_or_default(),
+    })
+}
+
+/// Walk history from `filter.rev` (default HEAD), newest first, and return
+/// `limit` matching commits after skipping `skip` matches, plus whether more follow.
+fn read_log(repo: &Repository, filter: &LogFilter, skip: usize, limit: usize) -> Result<(Vec<LogEntry>, bool), AppError> {
+    let rev = filter.rev.unwrap_or("HEAD");
+    let start = repo.revparse_single(rev)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| AppError::BadRequest(format!("Unknown ref '{}': {}", rev, e)))?;
+
+    let mut walk = repo.revwalk()
+        .map_err(|e| AppError::Internal(format!("Failed to walk history: {}", e)))?;
+    walk.set_sorting(git2::Sort::TIME)
+        .and_then(|_| walk.push(start.id()))
+        .map_err(|e| AppError::Internal(format!("Failed to walk history: {}", e)))?;
+
+    let tags = tags_by_commit(repo);
+    let mut matched = 0;
+    let mut entries = Vec::new();
+    for oid in walk {
+        let commit = oid.and_then(|oid| repo.find_commit(oid))
+            .map_err(|e| AppError::Internal(format!("Failed to read commit: {}", e)))?;
+        if !filter.matches(repo, &commit) {
+            continue;
+        }
+        matched += 1;
+        if matched <= skip {
+            continue;
+        }
+        if entries.len() == limit {
+            return Ok((entries, true));
+        }
+        entries.push(log_entry(repo, &commit, &tags)
+            .map_err(|e| AppError::Internal(format!("Failed to diff commit {}: {}", commit.id(), e)))?);
+    }
+    Ok((entries, false))
+}
+
+/// Query string of `GET /api/git/log`
+#[derive(FromForm)]
+pub struct LogQuery {
+    rev: Option<String>,
+    path: Option<String>,
+    author: Option<String>,
+    since: Option<i64>,
+    until: Option<i64>,
+    search: Option<String>,
+    skip: Option<usize>,
+    limit: Option<usize>,
+}
+
+/// Commit history, newest first. Filter by ref, file or directory, author
+/// (name or email), date range (unix seconds) and message text; page with
+/// `skip` and `limit` (default 50, at most 200).
+/// ### Examples:
+/// - GET /api/git/log
+/// - GET /api/git/log?path=index.html&limit=20
+/// - GET /api/git/log?author=alice&since=1767225600&search=typo&skip=50
+#[get("/git/log?<query..>")]
+pub async fn get_log(_admin: Admin, _lock: RepoRead, query: LogQuery) -> Json<LogResponse> {
+    fn non_empty(value: &Option<String>) -> Option<&str> {
+        value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+    }
+    let filter = LogFilter {
+        rev: non_empty(&query.rev),
+        path: non_empty(&query.path).map(|p| p.trim_matches('/')),
+        author: non_empty(&query.author),
+        since: query.since,
+        until: query.until,
+        search: non_empty(&query.search),
+    };
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let result = open_repo().and_then(|repo| read_log(&repo, &filter, query.skip.unwrap_or(0), limit));
+    match result {
+        Ok((entries, has_more)) => Json(LogResponse {
+            success: true,
+            message: format!("{} commits", entries.len()),
+            entries,
+            has_more,
+        }),
+        Err(e) => Json(LogResponse::err(e.to_string())),
+    }
+}
@@ -0,0 +1,221 @@
+// backend_simple_web/src/api/git/identity.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use rocket::tokio::sync::Mutex;
+use std::path::Path;
+
+use prisma_auth::backend::AuthGuard as Admin;
+use super::{git_result, open_repo, GitStatus};
+use crate::api::error::AppError;
+use crate::storage;
+
+const IDENTITY_PATH: &str = "/app/data/git_identity.json";
+
+/// Held across load-modify-save so concurrent edits don't drop each other
+static CONFIG_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+impl Identity {
+    pub fn signature(&self) -> Result<git2::Signature<'static>, git2::Error> {
+        git2::Signature::now(&self.name, &self.email)
+    }
+
+    /// Parse `Name <email>`
+    pub fn parse(text: &str) -> Option<Self> {
+        let (name, rest) = text.trim().split_once('<')?;
+        let email = rest.strip_suffix('>')?;
+        let identity = Self { name: name.trim().to_string(), email: email.trim().to_string() };
+        identity.validate().ok().map(|_| identity)
+    }
+
+    fn validate(&self) -> Result<(), AppError> {
+        let invalid = |s: &str| s.trim().is_empty() || s.contains(['<', '>', '\n', '\r']);
+        if invalid(&self.name) || invalid(&self.email) || !self.email.contains('@') {
+            return Err(AppError::BadRequest(format!("Invalid identity '{} <{}>'", self.name, self.email)));
+        }
+        Ok(())
+    }
+}
+
+/// An author the browser can pick to commit as.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct AuthorProfile {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+}
+
+impl AuthorProfile {
+    pub fn identity(&self) -> Identity {
+        Identity { name: self.name.clone(), email: self.email.clone() }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(crate = "rocket::serde")]
+pub struct IdentityConfig {
+    /// Committer for every commit made by the server; the author commits
+    /// themselves when unset
+    #[serde(default)]
+    pub committer: Option<Identity>,
+    #[serde(default)]
+    pub authors: Vec<AuthorProfile>,
+}
+
+impl IdentityConfig {
+    /// Fails rather than starting empty when the file can't be read, so a
+    /// save never replaces profiles that are still on disk.
+    pub async fn load() -> Result<Self, AppError> {
+        match storage::load_json(Path::new(IDENTITY_PATH)).await {
+            Ok(config) => Ok(config.unwrap_or_default()),
+            Err(e) => {
+                error!("Failed to load git identities: {}", e);
+                Err(AppError::Internal(format!("Failed to load git identities: {}", e)))
+            }
+        }
+    }
+
+    async fn save(&self) -> Result<(), AppError> {
+        storage::save_json(Path::new(IDENTITY_PATH), self).await
+            .map_err(|e| AppError::Internal(format!("Failed to save git identities: {}", e)))
+    }
+
+    pub fn author(&self, id: &str) -> Result<Identity, AppError> {
+        self.authors.iter()
+            .find(|author| author.id == id)
+            .map(AuthorProfile::identity)
+            .ok_or_else(|| AppError::BadRequest(format!("Unknown author profile '{}'", id)))
+    }
+}
+
+/// The instance-wide committer. Read synchronously because signatures are
+/// made deep inside git2 code that cannot await.
+pub fn configured_committer() -> Option<Identity> {
+    let json = std::fs::read_to_string(IDENTITY_PATH).ok()?;
+    serde_json::from_str::<IdentityConfig>(&json).ok()?.committer
+}
+
+/// `user.name` and `user.email` from the repository's git config
+fn repo_identity() -> Option<Identity> {
+    let repo = open_repo().ok()?;
+    let config = repo.config().ok()?;
+    Some(Identity {
+        name: config.get_string("user.name").ok()?,
+        email: config.get_string("user.email").ok()?,
+    })
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct IdentityResponse {
+    pub committer: Option<Identity>,
+    pub authors: Vec<AuthorProfile>,
+    /// Identity from the repository config, used when no profile is picked
+    pub repo_default: Option<Identity>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CommitterRequest {
+    /// `null` to commit as the author
+    committer: Option<Identity>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AuthorRequest {
+    /// Existing profile to update; a new one is created without
+    id: Option<String>,
+    name: String,
+    email: String,
+}
+
+/// Configured committer, author profiles and the repository's default identity
+/// ### Examples:
+/// - GET /api/git/identity
+#[get("/git/identity")]
+pub async fn get_identity(_admin: Admin) -> Result<Json<IdentityResponse>, AppError> {
+    let config = IdentityConfig::load().await?;
+    Ok(Json(IdentityResponse {
+        committer: config.committer,
+        authors: config.authors,
+        repo_default: repo_identity(),
+    }))
+}
+
+async fn set_committer_inner(committer: Option<Identity>) -> Result<GitStatus, AppError> {
+    if let Some(ref identity) = committer {
+        identity.validate()?;
+    }
+    let _guard = CONFIG_LOCK.lock().await;
+    let mut config = IdentityConfig::load().await?;
+    let message = match &committer {
+        Some(identity) => format!("Commits are now committed by {} <{}>", identity.name, identity.email),
+        None => "Commits are now committed by their author".to_string(),
+    };
+    config.committer = committer;
+    config.save().await?;
+    Ok(GitStatus::ok(message, None))
+}
+
+/// Set or clear the instance-wide committer identity
+/// ### Examples:
+/// - POST /api/git/identity/committer  JSON ```{"committer":{"name":"Site Bot","email":"bot@example.com"}}```
+/// - POST /api/git/identity/committer  JSON ```{"committer":null}```
+#[post("/git/identity/committer", data = "<request>")]
+pub async fn set_committer(request: Json<CommitterRequest>, _admin: Admin) -> Json<GitStatus> {
+    git_result(set_committer_inner(request.into_inner().committer).await)
+}
+
+async fn save_author_inner(request: AuthorRequest) -> Result<GitStatus, AppError> {
+    let profile = AuthorProfile {
+        id: request.id.unwrap_or_else(|| uuid::Uuid::new_v4().to_string()),
+        name: request.name.trim().to_string(),
+        email: request.email.trim().to_string(),
+    };
+    profile.identity().validate()?;
+
+    let _guard = CONFIG_LOCK.lock().await;
+    let mut config = IdentityConfig::load().await?;
+    match config.authors.iter_mut().find(|author| author.id == profile.id) {
+        Some(existing) => *existing = profile.clone(),
+        None => config.authors.push(profile.clone()),
+    }
+    config.save().await?;
+    Ok(GitStatus::ok(format!("Saved author {} <{}>", profile.name, profile.email), None))
+}
+
+/// Create or update an author profile
+/// ### Examples:
+/// - POST /api/git/identity/authors  JSON ```{"name":"Alice","email":"alice@example.com"}```
+/// - POST /api/git/identity/authors  JSON ```{"id":"…","name":"Alice B.","email":"alice@example.com"}```
+#[post("/git/identity/authors", data = "<request>")]
+pub async fn save_author(request: Json<AuthorRequest>, _admin: Admin) -> Json<GitStatus> {
+    git_result(save_author_inner(request.into_inner()).await)
+}
+
+async fn delete_author_inner(id: &str) -> Result<GitStatus, AppError> {
+    let _guard = CONFIG_LOCK.lock().await;
+    let mut config = IdentityConfig::load().await?;
+    let before = config.authors.len();
+    config.authors.retain(|author| author.id != id);
+    if config.authors.len() == before {
+        return Err(AppError::NotFound(format!("Unknown author profile '{}'", id)));
+    }
+    config.save().await?;
+    Ok(GitStatus::ok("Author deleted", None))
+}
+
+/// Delete an author profile
+/// ### Examples:
+/// - DELETE /api/git/identity/authors/<id>
+#[delete("/git/identity/authors/<id>")]
+pub async fn delete_author(id: &str, _admin: Admin) -> Json<GitStatus> {
+    git_result(delete_author_inner(id).await)
+}
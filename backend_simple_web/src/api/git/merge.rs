@@ -0,0 +1,315 @@
+// backend_simple_web/src/api/git/merge.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use git2::{Repository, RepositoryState};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::{RepoRead, RepoWrite};
+use super::{conflict_paths, git_result, head_commit_hash, open_repo, signature, sparse, GitStatus};
+use crate::api::error::AppError;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ConflictFile {
+    pub path: String,
+    /// Common ancestor, `None` when the file did not exist there
+    pub base: Option<String>,
+    /// Local version, `None` when deleted locally
+    pub ours: Option<String>,
+    /// Incoming version, `None` when deleted upstream
+    pub theirs: Option<String>,
+    /// Working tree file with conflict markers
+    pub working: Option<String>,
+    /// Contents are left out for binary files
+    pub binary: bool,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct MergeStatusResponse {
+    pub success: bool,
+    pub message: String,
+    pub in_progress: bool,
+    /// Commits being merged into HEAD
+    pub merge_heads: Vec<String>,
+    pub conflicts: Vec<ConflictFile>,
+}
+
+impl MergeStatusResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), in_progress: false, merge_heads: vec![], conflicts: vec![] }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum Resolution {
+    Ours,
+    Theirs,
+    /// Use the given `content`
+    Content,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ResolveRequest {
+    path: String,
+    resolution: Resolution,
+    content: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ContinueRequest {
+    /// Defaults to the message prepared when the merge started
+    message: Option<String>,
+}
+
+fn index_error(e: git2::Error) -> AppError {
+    AppError::Internal(format!("Failed to access repository index: {}", e))
+}
+
+fn ensure_merging(repo: &Repository) -> Result<(), AppError> {
+    if repo.state() != RepositoryState::Merge {
+        return Err(AppError::BadRequest("No merge in progress".into()));
+    }
+    Ok(())
+}
+
+/// Commits being merged, from `MERGE_HEAD`
+fn merge_heads(repo: &Repository) -> Result<Vec<git2::Oid>, AppError> {
+    let content = std::fs::read_to_string(repo.path().join("MERGE_HEAD"))
+        .map_err(|e| AppError::Internal(format!("Failed to read merge heads: {}", e)))?;
+    content.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| git2::Oid::from_str(line.trim()))
+        .collect::<Result<_, _>>()
+        .map_err(|e| AppError::Internal(format!("Invalid merge head: {}", e)))
+}
+
+fn workdir_path(repo: &Repository, path: &str) -> Result<PathBuf, AppError> {
+    repo.workdir()
+        .map(|dir| dir.join(path))
+        .ok_or_else(|| AppError::Internal("Repository has no working directory".into()))
+}
+
+/// Text of a file version, `None` when it is binary.
+fn text(bytes: &[u8]) -> Option<String> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+fn blob_bytes(repo: &Repository, entry: Option<&git2::IndexEntry>) -> Result<Option<Vec<u8>>, AppError> {
+    entry.map(|entry| {
+        repo.find_blob(entry.id)
+            .map(|blob| blob.content().to_vec())
+            .map_err(|e| AppError::Internal(format!("Failed to read conflicting version: {}", e)))
+    }).transpose()
+}
+
+/// Merge `remote` into the current branch in the working tree. Without
+/// conflicts the merge commit is made right away; with conflicts the merge is
+/// left in progress for `/api/git/merge/...`.
+pub(super) fn merge_into_head(repo: &Repository, tracking_branch: &str, remote: &git2::Commit) -> Result<GitStatus, AppError> {
+    let annotated = repo.find_annotated_commit(remote.id())
+        .map_err(|e| AppError::Internal(format!("Failed to read remote commit: {}", e)))?;
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.allow_conflicts(true).conflict_style_merge(true);
+    repo.merge(&[&annotated], None, Some(&mut checkout))
+        .map_err(|e| AppError::Internal(format!("Failed to merge: {}", e)))?;
+
+    // libgit2 prepares "Merge commit '<hash>'"; name the branch like git pull does
+    let message = format!("Merge remote-tracking branch '{}'", tracking_branch);
+    if let Err(e) = std::fs::write(repo.path().join("MERGE_MSG"), format!("{}\n", message)) {
+        warn!("Failed to write merge message: {}", e);
+    }
+
+    let index = repo.index().map_err(index_error)?;
+    if index.has_conflicts() {
+        return Err(AppError::BadRequest(format!(
+            "Merging {} left conflicts in {}. Resolve them, then complete or abort the merge.",
+            tracking_branch, conflict_paths(&index).join(", ")
+        )));
+    }
+    complete_merge(repo, None)
+}
+
+/// Commit the resolved merge and leave the merge state.
+fn complete_merge(repo: &Repository, message: Option<&str>) -> Result<GitStatus, AppError> {
+    ensure_merging(repo)?;
+    let mut index = repo.index().map_err(index_error)?;
+    if index.has_conflicts() {
+        return Err(AppError::BadRequest(format!(
+            "Resolve the conflicts in {} first", conflict_paths(&index).join(", ")
+        )));
+    }
+
+    let head = repo.head().and_then(|head| head.peel_to_commit())
+        .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+    let mut parent_ids = vec![head.id()];
+    parent_ids.extend(merge_heads(repo)?);
+    let parents = parent_ids.iter()
+        .map(|oid| repo.find_commit(*oid))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| AppError::Internal(format!("Failed to read merge parent: {}", e)))?;
+    let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+
+    let prepared = repo.message().ok();
+    let message = message.map(str::trim).filter(|m| !m.is_empty())
+        .or(prepared.as_deref().map(str::trim))
+        .unwrap_or("Merge");
+
+    let tree_id = index.write_tree()
+        .map_err(|e| AppError::Internal(format!("Failed to write merge tree: {}", e)))?;
+    let tree = repo.find_tree(tree_id)
+        .map_err(|e| AppError::Internal(format!("Failed to find merge tree: {}", e)))?;
+    let signature = signature(repo);
+    let oid = repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parent_refs)
+        .map_err(|e| AppError::Internal(format!("Failed to create merge commit: {}", e)))?;
+    repo.cleanup_state()
+        .map_err(|e| AppError::Internal(format!("Failed to finish merge: {}", e)))?;
+
+    info!("Merge commit created: {}", oid);
+    Ok(GitStatus::ok("Merge completed", Some(oid.to_string())))
+}
+
+fn merge_status(repo: &Repository) -> Result<MergeStatusResponse, AppError> {
+    let in_progress = repo.state() == RepositoryState::Merge;
+    let heads = if in_progress { merge_heads(repo)? } else { Vec::new() };
+
+    let index = repo.index().map_err(index_error)?;
+    let mut conflicts = Vec::new();
+    for conflict in index.conflicts().map_err(index_error)? {
+        let conflict = conflict.map_err(index_error)?;
+        let Some(entry) = conflict.our.as_ref().or(conflict.their.as_ref()).or(conflict.ancestor.as_ref()) else { continue };
+        let path = String::from_utf8_lossy(&entry.path).into_owned();
+
+        let base = blob_bytes(repo, conflict.ancestor.as_ref())?;
+        let ours = blob_bytes(repo, conflict.our.as_ref())?;
+        let theirs = blob_bytes(repo, conflict.their.as_ref())?;
+        let working = std::fs::read(workdir_path(repo, &path)?).ok();
+
+        let versions = [&base, &ours, &theirs, &working];
+        let binary = versions.iter().any(|v| v.as_deref().is_some_and(|bytes| text(bytes).is_none()));
+        let as_text = |bytes: Option<Vec<u8>>| if binary { None } else { bytes.as_deref().and_then(text) };
+        conflicts.push(ConflictFile {
+            path,
+            base: as_text(base),
+            ours: as_text(ours),
+            theirs: as_text(theirs),
+            working: as_text(working),
+            binary,
+        });
+    }
+
+    Ok(MergeStatusResponse {
+        success: true,
+        message: if in_progress {
+            format!("Merge in progress with {} conflicted files", conflicts.len())
+        } else {
+            "No merge in progress".to_string()
+        },
+        in_progress,
+        merge_heads: heads.iter().map(|oid| oid.to_string()).collect(),
+        conflicts,
+    })
+}
+
+fn resolve_inner(request: &ResolveRequest) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    ensure_merging(&repo)?;
+    let mut index = repo.index().map_err(index_error)?;
+
+    let path = request.path.trim().trim_start_matches('/');
+    let conflict = index.conflicts().map_err(index_error)?
+        .filter_map(|conflict| conflict.ok())
+        .find(|conflict| {
+            conflict.our.as_ref().or(conflict.their.as_ref()).or(conflict.ancestor.as_ref())
+                .is_some_and(|entry| entry.path == path.as_bytes())
+        })
+        .ok_or_else(|| AppError::BadRequest(format!("'{}' has no conflict", path)))?;
+
+    let content = match request.resolution {
+        Resolution::Ours => blob_bytes(&repo, conflict.our.as_ref())?,
+        Resolution::Theirs => blob_bytes(&repo, conflict.their.as_ref())?,
+        Resolution::Content => Some(request.content.clone()
+            .ok_or_else(|| AppError::BadRequest("`content` is required to resolve with content".into()))?
+            .into_bytes()),
+    };
+
+    let file = workdir_path(&repo, path)?;
+    match content {
+        Some(bytes) => {
+            if let Some(parent) = file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&file, bytes)?;
+            index.add_path(Path::new(path)).map_err(index_error)?;
+        }
+        // The chosen side deleted the file
+        None => {
+            if file.exists() {
+                std::fs::remove_file(&file)?;
+            }
+            index.remove_path(Path::new(path)).map_err(index_error)?;
+        }
+    }
+    index.write().map_err(index_error)?;
+
+    let remaining = conflict_paths(&index).len();
+    Ok(GitStatus::ok(
+        format!("Resolved {}; {} conflicts left", path, remaining),
+        head_commit_hash(&repo),
+    ))
+}
+
+fn abort_inner() -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    ensure_merging(&repo)?;
+    let head = repo.head().and_then(|head| head.peel(git2::ObjectType::Commit))
+        .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+    repo.reset(&head, git2::ResetType::Hard, Some(&mut sparse::checkout(&repo)))
+        .map_err(|e| AppError::Internal(format!("Failed to reset to HEAD: {}", e)))?;
+    repo.cleanup_state()
+        .map_err(|e| AppError::Internal(format!("Failed to leave merge state: {}", e)))?;
+    Ok(GitStatus::ok("Merge aborted", Some(head.id().to_string())))
+}
+
+/// Whether a merge is in progress, with base, ours and theirs of every conflicted file
+/// ### Examples:
+/// - GET /api/git/merge
+#[get("/git/merge")]
+pub async fn get_merge_status(_admin: Admin, _lock: RepoRead) -> Json<MergeStatusResponse> {
+    match open_repo().and_then(|repo| merge_status(&repo)) {
+        Ok(status) => Json(status),
+        Err(e) => Json(MergeStatusResponse::err(e.to_string())),
+    }
+}
+
+/// Resolve a conflicted file with our version, their version or new content
+/// ### Examples:
+/// - POST /api/git/merge/resolve  JSON ```{"path":"index.html","resolution":"theirs"}```
+/// - POST /api/git/merge/resolve  JSON ```{"path":"index.html","resolution":"content","content":"<html>…"}```
+#[post("/git/merge/resolve", data = "<request>")]
+pub async fn resolve_conflict(request: Json<ResolveRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(resolve_inner(&request))
+}
+
+/// Commit a merge once every conflict is resolved
+/// ### Examples:
+/// - POST /api/git/merge/continue  JSON ```{}```
+/// - POST /api/git/merge/continue  JSON ```{"message":"Merge origin/main, keeping our header"}```
+#[post("/git/merge/continue", data = "<request>")]
+pub async fn continue_merge(request: Json<ContinueRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(open_repo().and_then(|repo| complete_merge(&repo, request.message.as_deref())))
+}
+
+/// Abandon a merge and return to HEAD
+/// ### Examples:
+/// - POST /api/git/merge/abort
+#[post("/git/merge/abort")]
+pub async fn abort_merge(_admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(abort_inner())
+}
@@ -0,0 +1,297 @@
+// backend_simple_web/src/api/git/remotes.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use git2::Repository;
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::{RepoRead, RepoWrite};
+use super::credentials::GitCredentials;
+use super::{find_remote, git_result, make_push_options, open_repo, GitStatus, DEFAULT_REMOTE};
+use crate::api::error::AppError;
+
+/// Custom key in the remote's git config section; removed along with the remote
+const MIRROR_KEY: &str = "simplewebMirror";
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RemoteInfo {
+    pub name: String,
+    pub url: String,
+    /// Separate URL used for pushing, if set
+    pub push_url: Option<String>,
+    /// Pushed to along with origin
+    pub mirror: bool,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RemoteListResponse {
+    pub success: bool,
+    pub message: String,
+    pub remotes: Vec<RemoteInfo>,
+}
+
+impl RemoteListResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), remotes: vec![] }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RemotePushResult {
+    pub remote: String,
+    pub success: bool,
+    pub message: String,
+}
+
+/// Result of a push, with one entry per remote pushed to
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PushResponse {
+    /// Every remote accepted the push
+    pub success: bool,
+    pub message: String,
+    pub commit_hash: Option<String>,
+    pub remotes: Vec<RemotePushResult>,
+}
+
+impl PushResponse {
+    pub(super) fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), commit_hash: None, remotes: vec![] }
+    }
+
+    fn from_results(remotes: Vec<RemotePushResult>, commit_hash: Option<String>) -> Self {
+        let success = remotes.iter().all(|r| r.success);
+        let message = match remotes.as_slice() {
+            [single] => single.message.clone(),
+            _ => {
+                let pushed: Vec<&str> = remotes.iter().filter(|r| r.success).map(|r| r.remote.as_str()).collect();
+                let failed: Vec<String> = remotes.iter().filter(|r| !r.success)
+                    .map(|r| format!("{} ({})", r.remote, r.message)).collect();
+                match (pushed.is_empty(), failed.is_empty()) {
+                    (_, true) => format!("Pushed to {}", pushed.join(", ")),
+                    (true, false) => format!("Push failed for {}", failed.join("; ")),
+                    (false, false) => format!("Pushed to {}; failed for {}", pushed.join(", "), failed.join("; ")),
+                }
+            }
+        };
+        Self { success, message, commit_hash, remotes }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct AddRemoteRequest {
+    name: String,
+    url: String,
+    push_url: Option<String>,
+    #[serde(default)]
+    mirror: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct UpdateRemoteRequest {
+    /// New name for the remote
+    rename: Option<String>,
+    url: Option<String>,
+    /// Separate push URL; an empty string removes it
+    push_url: Option<String>,
+    mirror: Option<bool>,
+}
+
+fn config_error(e: git2::Error) -> AppError {
+    AppError::Internal(format!("Failed to update repository config: {}", e.message()))
+}
+
+fn is_mirror(repo: &Repository, name: &str) -> bool {
+    repo.config()
+        .and_then(|config| config.get_bool(&format!("remote.{}.{}", name, MIRROR_KEY)))
+        .unwrap_or(false)
+}
+
+fn set_mirror(repo: &Repository, name: &str, mirror: bool) -> Result<(), AppError> {
+    let mut config = repo.config().map_err(config_error)?;
+    let key = format!("remote.{}.{}", name, MIRROR_KEY);
+    if mirror {
+        config.set_bool(&key, true).map_err(config_error)
+    } else {
+        match config.remove(&key) {
+            Err(e) if e.code() != git2::ErrorCode::NotFound => Err(config_error(e)),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    if git2::Remote::is_valid_name(name) {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!("'{}' is not a valid remote name", name)))
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// Remotes a push without an explicit remote goes to: origin, then every mirror.
+pub(super) fn push_targets(repo: &Repository) -> Result<Vec<String>, AppError> {
+    let names = repo.remotes()
+        .map_err(|e| AppError::Internal(format!("Failed to list remotes: {}", e)))?;
+    let mut targets: Vec<String> = names.iter().flatten()
+        .filter(|name| *name != DEFAULT_REMOTE && is_mirror(repo, name))
+        .map(str::to_string)
+        .collect();
+    if repo.find_remote(DEFAULT_REMOTE).is_ok() || targets.is_empty() {
+        targets.insert(0, DEFAULT_REMOTE.to_string());
+    }
+    Ok(targets)
+}
+
+/// Push `refspecs` to each remote in turn; a failure does not stop the others.
+pub(super) fn push_to_remotes(repo: &Repository, remotes: &[String], refspecs: &[String], creds: &Option<GitCredentials>, commit_hash: Option<String>) -> PushResponse {
+    let results = remotes.iter().map(|name| {
+        let pushed = find_remote(repo, name).and_then(|mut remote| {
+            let url = remote.url().unwrap_or_default().to_string();
+            remote.push(refspecs, Some(&mut make_push_options(creds.as_ref(), &url)))
+                .map_err(|e| AppError::Internal(format!(
+                    "Failed to push to {}: {}. Check if you have push permissions.", name, e
+                )))
+        });
+        match pushed {
+            Ok(()) => RemotePushResult {
+                remote: name.clone(),
+                success: true,
+                message: format!("Successfully pushed to {}", name),
+            },
+            Err(e) => {
+                error!("{}", e);
+                RemotePushResult { remote: name.clone(), success: false, message: e.to_string() }
+            }
+        }
+    }).collect();
+    PushResponse::from_results(results, commit_hash)
+}
+
+fn list_inner() -> Result<RemoteListResponse, AppError> {
+    let repo = open_repo()?;
+    let names = repo.remotes()
+        .map_err(|e| AppError::Internal(format!("Failed to list remotes: {}", e)))?;
+    let remotes = names.iter().flatten().filter_map(|name| {
+        let remote = repo.find_remote(name).ok()?;
+        Some(RemoteInfo {
+            name: name.to_string(),
+            url: remote.url().unwrap_or_default().to_string(),
+            push_url: remote.pushurl().map(str::to_string),
+            mirror: is_mirror(&repo, name),
+        })
+    }).collect::<Vec<_>>();
+    Ok(RemoteListResponse {
+        success: true,
+        message: format!("{} remotes", remotes.len()),
+        remotes,
+    })
+}
+
+fn add_inner(request: &AddRemoteRequest) -> Result<GitStatus, AppError> {
+    let name = request.name.trim();
+    validate_name(name)?;
+    let url = request.url.trim();
+    if url.is_empty() {
+        return Err(AppError::BadRequest("A URL is required".into()));
+    }
+    let repo = open_repo()?;
+    if repo.find_remote(name).is_ok() {
+        return Err(AppError::BadRequest(format!("Remote '{}' already exists", name)));
+    }
+    repo.remote(name, url)
+        .map_err(|e| AppError::BadRequest(format!("Failed to add remote: {}", e.message())))?;
+    if let Some(push_url) = non_empty(&request.push_url) {
+        repo.remote_set_pushurl(name, Some(push_url)).map_err(config_error)?;
+    }
+    if request.mirror {
+        set_mirror(&repo, name, true)?;
+    }
+    info!("Added remote {} ({})", name, url);
+    Ok(GitStatus::ok(format!("Added remote '{}'", name), None))
+}
+
+fn update_inner(name: &str, request: &UpdateRemoteRequest) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    find_remote(&repo, name)?;
+    let mut name = name.to_string();
+
+    if let Some(new_name) = non_empty(&request.rename).filter(|new_name| *new_name != name) {
+        validate_name(new_name)?;
+        if repo.find_remote(new_name).is_ok() {
+            return Err(AppError::BadRequest(format!("Remote '{}' already exists", new_name)));
+        }
+        let problems = repo.remote_rename(&name, new_name)
+            .map_err(|e| AppError::Internal(format!("Failed to rename remote: {}", e.message())))?;
+        for problem in problems.iter().flatten() {
+            warn!("Refspec not updated when renaming remote {}: {}", name, problem);
+        }
+        name = new_name.to_string();
+    }
+    if let Some(url) = non_empty(&request.url) {
+        repo.remote_set_url(&name, url).map_err(config_error)?;
+    }
+    if let Some(push_url) = &request.push_url {
+        let push_url = Some(push_url.trim()).filter(|url| !url.is_empty());
+        repo.remote_set_pushurl(&name, push_url).map_err(config_error)?;
+    }
+    if let Some(mirror) = request.mirror {
+        set_mirror(&repo, &name, mirror)?;
+    }
+    Ok(GitStatus::ok(format!("Updated remote '{}'", name), None))
+}
+
+fn delete_inner(name: &str) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    find_remote(&repo, name)?;
+    repo.remote_delete(name)
+        .map_err(|e| AppError::Internal(format!("Failed to remove remote: {}", e.message())))?;
+    info!("Removed remote {}", name);
+    let mut message = format!("Removed remote '{}'", name);
+    if name == DEFAULT_REMOTE {
+        message.push_str("; pulls and pushes need a remote named origin, or an explicit remote");
+    }
+    Ok(GitStatus::ok(message, None))
+}
+
+/// Configured remotes
+/// ### Examples:
+/// - GET /api/git/remotes
+#[get("/git/remotes")]
+pub async fn list_remotes(_admin: Admin, _lock: RepoRead) -> Json<RemoteListResponse> {
+    match list_inner() {
+        Ok(response) => Json(response),
+        Err(e) => Json(RemoteListResponse::err(e.to_string())),
+    }
+}
+
+/// Add a remote. Mirrors are pushed to whenever origin is.
+/// ### Examples:
+/// - POST /api/git/remotes  JSON ```{"name":"backup","url":"https://backup.example.com/site.git","mirror":true}```
+#[post("/git/remotes", data = "<request>")]
+pub async fn add_remote(request: Json<AddRemoteRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(add_inner(&request))
+}
+
+/// Rename a remote or change its URLs or mirror setting
+/// ### Examples:
+/// - POST /api/git/remotes/backup  JSON ```{"mirror":false}```
+/// - POST /api/git/remotes/backup  JSON ```{"rename":"forgejo","url":"git@git.example.com:site.git","push_url":""}```
+#[post("/git/remotes/<name>", data = "<request>")]
+pub async fn update_remote(name: &str, request: Json<UpdateRemoteRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(update_inner(name, &request))
+}
+
+/// Remove a remote and its remote-tracking branches
+/// ### Examples:
+/// - DELETE /api/git/remotes/backup
+#[delete("/git/remotes/<name>")]
+pub async fn delete_remote(name: &str, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(delete_inner(name))
+}
@@ -0,0 +1,206 @@
+// backend_simple_web/src/api/git/revert.rs
+use rocket::serde::{json::Json, Deserialize};
+use std::path::Path;
+use git2::{Repository, ResetType};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::RepoWrite;
+use super::identity::{Identity, IdentityConfig};
+use super::{
+    commit_index, conflict_paths, ensure_clean_workdir, git_result, head_commit_hash, open_repo,
+    sparse, uncommitted_changes, GitStatus,
+};
+use crate::api::error::AppError;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RevertRequest {
+    /// Commit to undo
+    commit: String,
+    /// Parent to revert against when `commit` is a merge (1 = the branch merged into)
+    mainline: Option<u32>,
+    /// Author profile id; the repository identity when unset
+    author: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum ResetMode {
+    /// Move the branch only
+    Soft,
+    /// Move the branch and the index, keep the working tree
+    Mixed,
+    /// Move the branch and discard every uncommitted change
+    Hard,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ResetRequest {
+    commit: String,
+    mode: ResetMode,
+    /// Must be `true`; resetting can drop commits from the branch
+    #[serde(default)]
+    confirm: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct RestoreRequest {
+    /// Commit to take the file or directory from
+    commit: String,
+    /// File or directory relative to ROOT
+    path: String,
+}
+
+fn find_commit<'r>(repo: &'r Repository, rev: &str) -> Result<git2::Commit<'r>, AppError> {
+    repo.revparse_single(rev.trim())
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| AppError::BadRequest(format!("Unknown commit '{}': {}", rev, e.message())))
+}
+
+fn short(oid: git2::Oid) -> String {
+    oid.to_string()[..7].to_string()
+}
+
+fn revert_inner(request: &RevertRequest, author: Option<&Identity>) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    ensure_clean_workdir(&repo, "revert")?;
+    let commit = find_commit(&repo, &request.commit)?;
+    let mainline = match (commit.parent_count(), request.mainline) {
+        (0, _) => return Err(AppError::BadRequest("Cannot revert the first commit".into())),
+        (1, _) => 0,
+        (_, Some(mainline)) if mainline >= 1 && (mainline as usize) <= commit.parent_count() => mainline,
+        (count, _) => return Err(AppError::BadRequest(format!(
+            "{} is a merge; choose the parent to keep with `mainline` (1 to {})", short(commit.id()), count
+        ))),
+    };
+
+    let mut opts = git2::RevertOptions::new();
+    opts.mainline(mainline);
+    repo.revert(&commit, Some(&mut opts))
+        .map_err(|e| AppError::Internal(format!("Failed to revert {}: {}", short(commit.id()), e.message())))?;
+
+    let mut index = repo.index()
+        .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
+    let result = if index.has_conflicts() {
+        Err(AppError::BadRequest(format!(
+            "Cannot revert {}: later commits changed the same lines in {}", short(commit.id()), conflict_paths(&index).join(", ")
+        )))
+    } else {
+        let summary = commit.summary().unwrap_or_default();
+        let message = format!("Revert \"{}\"\n\nThis reverts commit {}.", summary, commit.id());
+        commit_index(&repo, &mut index, &message, author)
+            .map(|status| GitStatus::ok(format!("Reverted {} \"{}\"", short(commit.id()), summary), status.commit_hash))
+    };
+
+    // Leave the revert state; on conflicts also undo the half-applied changes
+    if result.is_err() {
+        let head = repo.head().and_then(|head| head.peel(git2::ObjectType::Commit))
+            .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+        repo.reset(&head, ResetType::Hard, Some(&mut sparse::checkout(&repo)))
+            .map_err(|e| AppError::Internal(format!("Failed to undo revert: {}", e)))?;
+    }
+    repo.cleanup_state()
+        .map_err(|e| AppError::Internal(format!("Failed to finish revert: {}", e)))?;
+    result
+}
+
+fn reset_inner(request: &ResetRequest) -> Result<GitStatus, AppError> {
+    if !request.confirm {
+        return Err(AppError::BadRequest("Resetting can drop commits; send `confirm: true` to proceed".into()));
+    }
+    let repo = open_repo()?;
+    if repo.state() != git2::RepositoryState::Clean {
+        return Err(AppError::BadRequest("A merge is in progress. Complete or abort it first.".into()));
+    }
+    let target = find_commit(&repo, &request.commit)?;
+    let head = repo.head().and_then(|head| head.peel_to_commit())
+        .map_err(|e| AppError::Internal(format!("Failed to get HEAD: {}", e)))?;
+    let (dropped, _) = repo.graph_ahead_behind(head.id(), target.id())
+        .map_err(|e| AppError::Internal(format!("Failed to compare commits: {}", e)))?;
+    let discarded = match request.mode {
+        ResetMode::Hard => uncommitted_changes(&repo)?,
+        _ => 0,
+    };
+
+    let (kind, mode) = match request.mode {
+        ResetMode::Soft => (ResetType::Soft, "soft"),
+        ResetMode::Mixed => (ResetType::Mixed, "mixed"),
+        ResetMode::Hard => (ResetType::Hard, "hard"),
+    };
+    repo.reset(target.as_object(), kind, Some(&mut sparse::checkout(&repo)))
+        .map_err(|e| AppError::Internal(format!("Failed to reset: {}", e)))?;
+
+    warn!("Branch reset to {} ({}), {} commits dropped", target.id(), mode, dropped);
+    let mut message = format!("Reset to {} ({})", short(target.id()), mode);
+    if dropped > 0 {
+        message.push_str(&format!("; {} commits are no longer on the branch", dropped));
+    }
+    if discarded > 0 {
+        message.push_str(&format!("; discarded {} uncommitted changes", discarded));
+    }
+    Ok(GitStatus::ok(message, Some(target.id().to_string())))
+}
+
+fn restore_inner(request: &RestoreRequest) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let commit = find_commit(&repo, &request.commit)?;
+    let path = request.path.trim().trim_matches('/');
+    if path.is_empty() {
+        return Err(AppError::BadRequest("No path given".into()));
+    }
+    let tree = commit.tree()
+        .map_err(|e| AppError::Internal(format!("Failed to read commit tree: {}", e)))?;
+    tree.get_path(Path::new(path))
+        .map_err(|_| AppError::NotFound(format!("'{}' does not exist in {}", path, short(commit.id()))))?;
+
+    let mut checkout = git2::build::CheckoutBuilder::new();
+    checkout.path(path).force();
+    repo.checkout_tree(tree.as_object(), Some(&mut checkout))
+        .map_err(|e| AppError::Internal(format!("Failed to restore '{}': {}", path, e)))?;
+
+    info!("Restored {} from {}", path, commit.id());
+    Ok(GitStatus::ok(
+        format!("Restored {} from {}; the change is staged", path, short(commit.id())),
+        head_commit_hash(&repo),
+    ))
+}
+
+/// Undo a commit by committing its inverse. Refused with the conflicting files
+/// listed when later commits changed the same lines.
+/// ### Examples:
+/// - POST /api/git/revert  JSON ```{"commit":"a1b2c3d"}```
+/// - POST /api/git/revert  JSON ```{"commit":"a1b2c3d","mainline":1,"author":"…"}```
+#[post("/git/revert", data = "<request>")]
+pub async fn revert_commit(request: Json<RevertRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    let author = match &request.author {
+        Some(id) => match IdentityConfig::load().await.and_then(|config| config.author(id)) {
+            Ok(identity) => Some(identity),
+            Err(e) => return git_result(Err(e)),
+        },
+        None => None,
+    };
+    info!("Reverting commit {}", request.commit);
+    git_result(revert_inner(&request, author.as_ref()))
+}
+
+/// Move the current branch to a commit. `hard` also discards uncommitted
+/// changes; every mode needs `confirm: true`.
+/// ### Examples:
+/// - POST /api/git/reset  JSON ```{"commit":"HEAD~1","mode":"soft","confirm":true}```
+/// - POST /api/git/reset  JSON ```{"commit":"a1b2c3d","mode":"hard","confirm":true}```
+#[post("/git/reset", data = "<request>")]
+pub async fn reset_branch(request: Json<ResetRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(reset_inner(&request))
+}
+
+/// Restore a file or directory as it was in a commit, into the working tree
+/// and index. Restore a deleted file from the parent of the commit deleting it.
+/// ### Examples:
+/// - POST /api/git/restore  JSON ```{"commit":"HEAD~3","path":"index.html"}```
+/// - POST /api/git/restore  JSON ```{"commit":"a1b2c3d^","path":"img/"}```
+#[post("/git/restore", data = "<request>")]
+pub async fn restore_path(request: Json<RestoreRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(restore_inner(&request))
+}
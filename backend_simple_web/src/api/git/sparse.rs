@@ -0,0 +1,133 @@
+// backend_simple_web/src/api/git/sparse.rs
+//
+// libgit2 has no sparse checkout of its own. The directories to materialize
+// are kept in `.git/info/sparse-checkout`, as the git CLI does, and every
+// checkout and status here is limited to them, so the files left out are not
+// reported as deleted.
+use std::ffi::OsStr;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use git2::build::CheckoutBuilder;
+use git2::{Index, Repository, StatusOptions, Tree};
+
+use crate::api::error::AppError;
+
+/// Repository config key holding the depth fetches keep a shallow clone at
+const DEPTH_KEY: &str = "simpleweb.fetchDepth";
+
+/// Directories of the sparse checkout, relative to ROOT; `None` when the
+/// whole tree is checked out.
+pub(super) fn sparse_paths(repo: &Repository) -> Option<Vec<String>> {
+    let enabled = repo.config().ok()
+        .and_then(|config| config.get_bool("core.sparseCheckout").ok())
+        .unwrap_or(false);
+    if !enabled {
+        return None;
+    }
+    let content = fs::read_to_string(repo.path().join("info/sparse-checkout")).ok()?;
+    let paths: Vec<String> = content.lines()
+        .map(|line| line.trim().trim_matches('/').to_string())
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('!'))
+        .collect();
+    Some(paths).filter(|paths| !paths.is_empty())
+}
+
+/// Trim and check sparse checkout directories from a setup request.
+pub(super) fn normalize_paths(paths: &[String]) -> Result<Vec<String>, AppError> {
+    let mut normalized = Vec::new();
+    for path in paths {
+        let path = path.trim().trim_matches('/');
+        if path.is_empty() {
+            continue;
+        }
+        if path.split('/').any(|part| part == ".." || part == ".") || path.contains(['*', '?', '[', '\\', '!']) {
+            return Err(AppError::BadRequest(format!(
+                "Sparse checkout paths are directories like 'public', not '{}'", path
+            )));
+        }
+        normalized.push(path.to_string());
+    }
+    Ok(normalized)
+}
+
+/// Record the sparse checkout directories so the git CLI agrees with us.
+pub(super) fn save_sparse_paths(repo: &Repository, paths: &[String]) -> Result<(), AppError> {
+    let file = repo.path().join("info/sparse-checkout");
+    let content: String = paths.iter().map(|path| format!("/{}/\n", path)).collect();
+    fs::create_dir_all(repo.path().join("info"))
+        .and_then(|_| fs::write(&file, content))
+        .map_err(|e| AppError::Internal(format!("Failed to save sparse checkout paths: {}", e)))?;
+    repo.config()
+        .and_then(|mut config| config.set_bool("core.sparseCheckout", true))
+        .map_err(|e| AppError::Internal(format!("Failed to enable sparse checkout: {}", e.message())))
+}
+
+/// Checkout options limited to `paths`, when there are any
+pub(super) fn checkout_paths(paths: Option<&[String]>) -> CheckoutBuilder<'static> {
+    let mut checkout = CheckoutBuilder::new();
+    for path in paths.unwrap_or_default() {
+        checkout.path(path.as_str());
+    }
+    checkout
+}
+
+/// Checkout options limited to the repository's sparse checkout
+pub(super) fn checkout(repo: &Repository) -> CheckoutBuilder<'static> {
+    checkout_paths(sparse_paths(repo).as_deref())
+}
+
+/// Status options limited to the repository's sparse checkout
+pub(super) fn status_options(repo: &Repository) -> StatusOptions {
+    let mut opts = StatusOptions::new();
+    for path in sparse_paths(repo).unwrap_or_default() {
+        opts.pathspec(path);
+    }
+    opts
+}
+
+/// Pathspecs covering every file of the checkout, for staging all changes
+pub(super) fn all_pathspecs(repo: &Repository) -> Vec<String> {
+    sparse_paths(repo).unwrap_or_else(|| vec!["*".to_string()])
+}
+
+fn is_outside(paths: &[String], path: &[u8]) -> bool {
+    !paths.iter().any(|dir| {
+        path.strip_prefix(dir.as_bytes()).is_some_and(|rest| rest.first() == Some(&b'/'))
+    })
+}
+
+/// Make the index match `tree` outside the sparse checkout, which checkouts
+/// limited to it leave alone. Does nothing without a sparse checkout.
+pub(super) fn sync_index(repo: &Repository, tree: &Tree) -> Result<(), AppError> {
+    let Some(paths) = sparse_paths(repo) else { return Ok(()) };
+    let index_error = |e: git2::Error| AppError::Internal(format!("Failed to update index: {}", e.message()));
+    let mut index = repo.index().map_err(index_error)?;
+    let mut target = Index::new().map_err(index_error)?;
+    target.read_tree(tree).map_err(index_error)?;
+
+    let stale: Vec<Vec<u8>> = index.iter()
+        .map(|entry| entry.path)
+        .filter(|path| is_outside(&paths, path))
+        .collect();
+    for path in stale {
+        index.remove(Path::new(OsStr::from_bytes(&path)), 0).map_err(index_error)?;
+    }
+    for entry in target.iter().filter(|entry| is_outside(&paths, &entry.path)) {
+        index.add(&entry).map_err(index_error)?;
+    }
+    index.write().map_err(index_error)
+}
+
+/// Depth a shallow clone was made with, for fetches to keep
+pub(super) fn fetch_depth(repo: &Repository) -> Option<i32> {
+    repo.config().ok()
+        .and_then(|config| config.get_i32(DEPTH_KEY).ok())
+        .filter(|depth| *depth > 0)
+}
+
+pub(super) fn save_fetch_depth(repo: &Repository, depth: u32) -> Result<(), AppError> {
+    repo.config()
+        .and_then(|mut config| config.set_i32(DEPTH_KEY, depth.min(i32::MAX as u32) as i32))
+        .map_err(|e| AppError::Internal(format!("Failed to save the clone depth: {}", e.message())))
+}
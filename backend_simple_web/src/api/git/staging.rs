@@ -0,0 +1,173 @@
+// backend_simple_web/src/api/git/staging.rs
+use rocket::serde::{json::Json, Deserialize};
+use git2::{ApplyLocation, ApplyOptions, Diff, DiffOptions, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::RepoWrite;
+use super::{git_result, head_commit_hash, open_repo, stage_pathspecs, GitStatus};
+use crate::api::error::AppError;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PathsRequest {
+    /// Files, directories or globs relative to ROOT
+    paths: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct HunkRequest {
+    /// File relative to ROOT
+    path: String,
+    /// Position of the hunk in the file's diff, as listed by `GET /api/git/diff`
+    hunk: usize,
+    /// Expected `@@ ... @@` header; the request is refused if the hunk changed
+    header: Option<String>,
+}
+
+fn clean_paths(paths: &[String]) -> Result<Vec<&str>, AppError> {
+    let paths: Vec<&str> = paths.iter()
+        .map(|p| p.trim().trim_start_matches('/'))
+        .filter(|p| !p.is_empty())
+        .collect();
+    if paths.is_empty() {
+        return Err(AppError::BadRequest("No paths given".into()));
+    }
+    Ok(paths)
+}
+
+fn stage_paths(paths: &[String]) -> Result<GitStatus, AppError> {
+    let paths = clean_paths(paths)?;
+    let repo = open_repo()?;
+    let mut index = repo.index()
+        .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
+    stage_pathspecs(&mut index, &paths)?;
+    Ok(GitStatus::ok(format!("Staged {}", paths.join(", ")), head_commit_hash(&repo)))
+}
+
+fn unstage_paths(paths: &[String]) -> Result<GitStatus, AppError> {
+    let paths = clean_paths(paths)?;
+    let repo = open_repo()?;
+    match repo.head().ok().and_then(|head| head.peel(git2::ObjectType::Commit).ok()) {
+        Some(head) => repo.reset_default(Some(&head), paths.iter())
+            .map_err(|e| AppError::Internal(format!("Failed to unstage changes: {}", e)))?,
+        // Before the first commit unstaging means leaving the index
+        None => {
+            let mut index = repo.index()
+                .map_err(|e| AppError::Internal(format!("Failed to access repository index: {}", e)))?;
+            index.remove_all(paths.iter(), None)
+                .and_then(|_| index.write())
+                .map_err(|e| AppError::Internal(format!("Failed to unstage changes: {}", e)))?;
+        }
+    }
+    Ok(GitStatus::ok(format!("Unstaged {}", paths.join(", ")), head_commit_hash(&repo)))
+}
+
+/// Diff of a single file: index against HEAD when `staged`, else working tree
+/// against index. Uses the same context as `GET /api/git/diff` so hunks line up.
+fn file_diff<'r>(repo: &'r Repository, path: &str, staged: bool, reverse: bool) -> Result<Diff<'r>, AppError> {
+    let mut opts = DiffOptions::new();
+    opts.pathspec(path).disable_pathspec_match(true).context_lines(3).reverse(reverse);
+    let diff = if staged {
+        let head = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+        repo.diff_tree_to_index(head.as_ref(), None, Some(&mut opts))
+    } else {
+        repo.diff_index_to_workdir(None, Some(&mut opts))
+    };
+    diff.map_err(|e| AppError::Internal(format!("Failed to compute diff: {}", e)))
+}
+
+/// Check that hunk `hunk` exists in the file's diff and has the expected header.
+fn check_hunk(diff: &Diff, request: &HunkRequest) -> Result<(), AppError> {
+    let patch = git2::Patch::from_diff(diff, 0)
+        .map_err(|e| AppError::Internal(format!("Failed to read diff: {}", e)))?
+        .ok_or_else(|| AppError::BadRequest(format!("No changes to '{}'", request.path)))?;
+    if request.hunk >= patch.num_hunks() {
+        return Err(AppError::BadRequest(format!(
+            "'{}' has {} hunks, no hunk {}", request.path, patch.num_hunks(), request.hunk
+        )));
+    }
+    let (hunk, _) = patch.hunk(request.hunk)
+        .map_err(|e| AppError::Internal(format!("Failed to read diff: {}", e)))?;
+    let header = String::from_utf8_lossy(hunk.header());
+    match request.header.as_deref() {
+        Some(expected) if expected.trim_end() != header.trim_end() => Err(AppError::BadRequest(
+            "The file has changed since the diff was loaded. Reload it and try again.".into()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Apply only hunk `hunk` of `diff` to the index.
+fn apply_hunk(repo: &Repository, diff: &Diff, hunk: usize) -> Result<(), AppError> {
+    let mut seen = 0;
+    let mut opts = ApplyOptions::new();
+    opts.hunk_callback(|_| {
+        seen += 1;
+        seen - 1 == hunk
+    });
+    repo.apply(diff, ApplyLocation::Index, Some(&mut opts))
+        .map_err(|e| AppError::Internal(format!("Failed to apply hunk: {}", e)))
+}
+
+fn stage_hunk_inner(request: &HunkRequest) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let path = request.path.trim().trim_start_matches('/');
+
+    // New files have no index entry to patch; they are staged whole
+    let status = repo.status_file(std::path::Path::new(path))
+        .map_err(|e| AppError::BadRequest(format!("Unknown path '{}': {}", path, e)))?;
+    if status.contains(git2::Status::WT_NEW) {
+        return stage_paths(&[path.to_string()]);
+    }
+
+    let diff = file_diff(&repo, path, false, false)?;
+    check_hunk(&diff, request)?;
+    apply_hunk(&repo, &diff, request.hunk)?;
+    Ok(GitStatus::ok(format!("Staged hunk {} of {}", request.hunk + 1, path), head_commit_hash(&repo)))
+}
+
+fn unstage_hunk_inner(request: &HunkRequest) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    let path = request.path.trim().trim_start_matches('/');
+
+    check_hunk(&file_diff(&repo, path, true, false)?, request)?;
+    // The reversed staged diff takes the hunk back out of the index
+    let reversed = file_diff(&repo, path, true, true)?;
+    apply_hunk(&repo, &reversed, request.hunk)?;
+    Ok(GitStatus::ok(format!("Unstaged hunk {} of {}", request.hunk + 1, path), head_commit_hash(&repo)))
+}
+
+/// Stage files or directories, including deletions
+/// ### Examples:
+/// - POST /api/git/stage  JSON ```{"paths":["index.html","css/"]}```
+#[post("/git/stage", data = "<request>")]
+pub async fn stage(request: Json<PathsRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(stage_paths(&request.paths))
+}
+
+/// Take files or directories out of the index, keeping the working tree
+/// ### Examples:
+/// - POST /api/git/unstage  JSON ```{"paths":["index.html"]}```
+#[post("/git/unstage", data = "<request>")]
+pub async fn unstage(request: Json<PathsRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(unstage_paths(&request.paths))
+}
+
+/// Stage one hunk of a file's unstaged changes. Hunks are numbered from 0 in
+/// the order of `GET /api/git/diff?path=...`.
+/// ### Examples:
+/// - POST /api/git/stage/hunk  JSON ```{"path":"index.html","hunk":0,"header":"@@ -1,4 +1,5 @@"}```
+#[post("/git/stage/hunk", data = "<request>")]
+pub async fn stage_hunk(request: Json<HunkRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(stage_hunk_inner(&request))
+}
+
+/// Unstage one hunk of a file's staged changes, numbered as in
+/// `GET /api/git/diff?staged=true&path=...`.
+/// ### Examples:
+/// - POST /api/git/unstage/hunk  JSON ```{"path":"index.html","hunk":1}```
+#[post("/git/unstage/hunk", data = "<request>")]
+pub async fn unstage_hunk(request: Json<HunkRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(unstage_hunk_inner(&request))
+}
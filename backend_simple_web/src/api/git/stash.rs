@@ -0,0 +1,264 @@
+// backend_simple_web/src/api/git/stash.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use git2::{DiffOptions, Oid, Repository, StashApplyOptions, StashFlags};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::{RepoRead, RepoWrite};
+use super::diff::{diff_files, DiffResponse};
+use super::{git_result, head_commit_hash, open_repo, signature, uncommitted_changes, GitStatus};
+use crate::api::error::AppError;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct StashEntry {
+    /// Position in the stash list, 0 being the newest (`stash@{0}`)
+    pub index: usize,
+    pub message: String,
+    pub commit: String,
+    /// Unix timestamp (seconds) of when the changes were stashed
+    pub date: i64,
+    /// Untracked files were stashed too
+    pub includes_untracked: bool,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct StashListResponse {
+    pub success: bool,
+    pub message: String,
+    pub stashes: Vec<StashEntry>,
+}
+
+impl StashListResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), stashes: vec![] }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct StashRequest {
+    message: Option<String>,
+    /// Also stash untracked files
+    #[serde(default)]
+    include_untracked: bool,
+}
+
+fn stash_error(action: &str, e: git2::Error) -> AppError {
+    match e.code() {
+        git2::ErrorCode::NotFound => AppError::NotFound(format!("Failed to {}: {}", action, e.message())),
+        git2::ErrorCode::Conflict | git2::ErrorCode::MergeConflict => AppError::BadRequest(format!(
+            "Failed to {}: it conflicts with uncommitted changes. Commit or stash them first.", action
+        )),
+        _ => AppError::Internal(format!("Failed to {}: {}", action, e.message())),
+    }
+}
+
+/// Stash commit at `index`
+fn stash_oid(repo: &mut Repository, index: usize) -> Result<Oid, AppError> {
+    let mut found = None;
+    repo.stash_foreach(|idx, _, oid| {
+        if idx == index {
+            found = Some(*oid);
+        }
+        idx < index
+    }).map_err(|e| stash_error("read stash list", e))?;
+    found.ok_or_else(|| AppError::NotFound(format!("No stash at index {}", index)))
+}
+
+/// Stash every uncommitted change. Returns `None` when there was nothing to stash.
+pub(super) fn stash_changes(repo: &mut Repository, message: Option<&str>, include_untracked: bool) -> Result<Option<Oid>, AppError> {
+    if uncommitted_changes(repo)? == 0 {
+        return Ok(None);
+    }
+    let stasher = signature(repo);
+    let flags = if include_untracked { StashFlags::INCLUDE_UNTRACKED } else { StashFlags::DEFAULT };
+    match repo.stash_save2(&stasher, message, Some(flags)) {
+        Ok(oid) => Ok(Some(oid)),
+        // Only untracked files changed and they were not included
+        Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+        Err(e) => Err(stash_error("stash changes", e)),
+    }
+}
+
+/// After a pull that stashed the working tree first, put the changes back.
+/// A failed restore, or a merge stopped on conflicts, keeps them in `stash@{0}`.
+pub(super) fn restore_autostash(repo: &mut Repository, result: Result<GitStatus, AppError>) -> Result<GitStatus, AppError> {
+    if repo.state() == git2::RepositoryState::Merge {
+        return match result {
+            Ok(mut status) => {
+                status.message.push_str(". Your stashed changes are kept in stash@{0}; pop them after the merge.");
+                Ok(status)
+            }
+            Err(e) => Err(AppError::Internal(format!(
+                "{}. Your stashed changes are kept in stash@{{0}}; pop them after the merge.", e
+            ))),
+        };
+    }
+    let mut opts = StashApplyOptions::new();
+    opts.reinstantiate_index();
+    match (result, repo.stash_pop(0, Some(&mut opts))) {
+        (Ok(mut status), Ok(())) => {
+            status.message.push_str(", and restored your uncommitted changes");
+            Ok(status)
+        }
+        (Ok(mut status), Err(e)) => {
+            warn!("Failed to restore stashed changes: {}", e);
+            status.message.push_str(&format!(
+                ". Your changes could not be restored ({}) and are kept in stash@{{0}}.", e.message()
+            ));
+            Ok(status)
+        }
+        (Err(e), Ok(())) => Err(e),
+        (Err(e), Err(pop_error)) => Err(AppError::Internal(format!(
+            "{}. Your stashed changes could not be restored ({}) and are kept in stash@{{0}}.", e, pop_error.message()
+        ))),
+    }
+}
+
+fn list_inner() -> Result<StashListResponse, AppError> {
+    let mut repo = open_repo()?;
+    let mut found = Vec::new();
+    repo.stash_foreach(|index, message, oid| {
+        found.push((index, message.to_string(), *oid));
+        true
+    }).map_err(|e| stash_error("read stash list", e))?;
+
+    let stashes = found.into_iter().map(|(index, message, oid)| {
+        let commit = repo.find_commit(oid).ok();
+        StashEntry {
+            index,
+            message,
+            commit: oid.to_string(),
+            date: commit.as_ref().map(|c| c.time().seconds()).unwrap_or_default(),
+            // The untracked files are kept in a third parent
+            includes_untracked: commit.is_some_and(|c| c.parent_count() > 2),
+        }
+    }).collect::<Vec<_>>();
+
+    Ok(StashListResponse {
+        success: true,
+        message: format!("{} stashes", stashes.len()),
+        stashes,
+    })
+}
+
+fn save_inner(request: &StashRequest) -> Result<GitStatus, AppError> {
+    let mut repo = open_repo()?;
+    let message = request.message.as_deref().map(str::trim).filter(|m| !m.is_empty());
+    match stash_changes(&mut repo, message, request.include_untracked)? {
+        Some(oid) => Ok(GitStatus::ok("Changes stashed as stash@{0}", Some(oid.to_string()))),
+        None => Err(AppError::BadRequest("No local changes to stash".into())),
+    }
+}
+
+fn tree_of<'r>(commit: &git2::Commit<'r>) -> Result<git2::Tree<'r>, AppError> {
+    commit.tree().map_err(|e| AppError::Internal(format!("Failed to read stash tree: {}", e)))
+}
+
+/// Changes in a stash: the stashed working tree against the commit it was
+/// made on, plus any stashed untracked files.
+fn diff_inner(index: usize) -> Result<DiffResponse, AppError> {
+    let mut repo = open_repo()?;
+    let oid = stash_oid(&mut repo, index)?;
+    let stash = repo.find_commit(oid)
+        .map_err(|e| AppError::Internal(format!("Failed to read stash: {}", e)))?;
+    let base = stash.parent(0)
+        .map_err(|e| AppError::Internal(format!("Failed to read stash base: {}", e)))?;
+    let mut opts = DiffOptions::new();
+    opts.context_lines(3);
+    let mut diff = repo.diff_tree_to_tree(Some(&tree_of(&base)?), Some(&tree_of(&stash)?), Some(&mut opts))
+        .map_err(|e| AppError::Internal(format!("Failed to compute diff: {}", e)))?;
+    if let Ok(untracked) = stash.parent(2) {
+        let untracked = repo.diff_tree_to_tree(None, Some(&tree_of(&untracked)?), Some(&mut opts))
+            .map_err(|e| AppError::Internal(format!("Failed to compute diff: {}", e)))?;
+        diff.merge(&untracked)
+            .map_err(|e| AppError::Internal(format!("Failed to compute diff: {}", e)))?;
+    }
+
+    let (files, truncated) = diff_files(&diff)
+        .map_err(|e| AppError::Internal(format!("Failed to read diff: {}", e)))?;
+    Ok(DiffResponse {
+        success: true,
+        message: format!("{} files changed", files.len()),
+        files,
+        raw: None,
+        truncated,
+    })
+}
+
+fn apply_inner(index: usize, pop: bool) -> Result<GitStatus, AppError> {
+    let mut repo = open_repo()?;
+    stash_oid(&mut repo, index)?;
+    let mut opts = StashApplyOptions::new();
+    opts.reinstantiate_index();
+    if pop {
+        repo.stash_pop(index, Some(&mut opts)).map_err(|e| stash_error("pop stash", e))?;
+        Ok(GitStatus::ok(format!("Restored and dropped stash@{{{}}}", index), head_commit_hash(&repo)))
+    } else {
+        repo.stash_apply(index, Some(&mut opts)).map_err(|e| stash_error("apply stash", e))?;
+        Ok(GitStatus::ok(format!("Applied stash@{{{}}}", index), head_commit_hash(&repo)))
+    }
+}
+
+fn drop_inner(index: usize) -> Result<GitStatus, AppError> {
+    let mut repo = open_repo()?;
+    stash_oid(&mut repo, index)?;
+    repo.stash_drop(index).map_err(|e| stash_error("drop stash", e))?;
+    Ok(GitStatus::ok(format!("Dropped stash@{{{}}}", index), head_commit_hash(&repo)))
+}
+
+/// Stashed changes, newest first
+/// ### Examples:
+/// - GET /api/git/stash
+#[get("/git/stash")]
+pub async fn list_stashes(_admin: Admin, _lock: RepoRead) -> Json<StashListResponse> {
+    match list_inner() {
+        Ok(response) => Json(response),
+        Err(e) => Json(StashListResponse::err(e.to_string())),
+    }
+}
+
+/// Stash uncommitted changes and clean the working tree
+/// ### Examples:
+/// - POST /api/git/stash  JSON ```{"message":"Half-done header"}```
+/// - POST /api/git/stash  JSON ```{"include_untracked":true}```
+#[post("/git/stash", data = "<request>")]
+pub async fn save_stash(request: Json<StashRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(save_inner(&request))
+}
+
+/// Diff of a stash, in the format of `GET /api/git/diff`
+/// ### Examples:
+/// - GET /api/git/stash/0/diff
+#[get("/git/stash/<index>/diff")]
+pub async fn get_stash_diff(index: usize, _admin: Admin, _lock: RepoRead) -> Json<DiffResponse> {
+    match diff_inner(index) {
+        Ok(response) => Json(response),
+        Err(e) => Json(DiffResponse::err(e.to_string())),
+    }
+}
+
+/// Re-apply a stash to the working tree, keeping it in the list
+/// ### Examples:
+/// - POST /api/git/stash/0/apply
+#[post("/git/stash/<index>/apply")]
+pub async fn apply_stash(index: usize, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(apply_inner(index, false))
+}
+
+/// Re-apply a stash and drop it from the list
+/// ### Examples:
+/// - POST /api/git/stash/0/pop
+#[post("/git/stash/<index>/pop")]
+pub async fn pop_stash(index: usize, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(apply_inner(index, true))
+}
+
+/// Delete a stash without applying it
+/// ### Examples:
+/// - DELETE /api/git/stash/0
+#[delete("/git/stash/<index>")]
+pub async fn drop_stash(index: usize, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(drop_inner(index))
+}
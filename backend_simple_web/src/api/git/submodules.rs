@@ -0,0 +1,95 @@
+// backend_simple_web/src/api/git/submodules.rs
+use rocket::serde::Serialize;
+use git2::{Repository, SubmoduleIgnore, SubmoduleStatus, SubmoduleUpdateOptions};
+
+use super::credentials::GitCredentials;
+use super::{make_fetch_options, open_repo};
+use crate::api::error::AppError;
+
+/// Submodules of submodules are followed this many levels deep
+const MAX_DEPTH: usize = 8;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SubmoduleInfo {
+    pub name: String,
+    /// Directory of the submodule, relative to ROOT
+    pub path: String,
+    pub url: Option<String>,
+    /// Commit the site repository records for the submodule
+    pub recorded_commit: Option<String>,
+    /// Commit checked out in the submodule directory
+    pub checked_out_commit: Option<String>,
+    /// `uninitialized`, `new_commits` (another commit than the recorded one
+    /// is checked out), `modified` (uncommitted edits inside) or `clean`
+    pub state: String,
+}
+
+fn state(status: SubmoduleStatus) -> &'static str {
+    if status.contains(SubmoduleStatus::WD_UNINITIALIZED) {
+        "uninitialized"
+    } else if status.contains(SubmoduleStatus::WD_MODIFIED) {
+        "new_commits"
+    } else if status.intersects(
+        SubmoduleStatus::WD_INDEX_MODIFIED | SubmoduleStatus::WD_WD_MODIFIED | SubmoduleStatus::WD_UNTRACKED,
+    ) {
+        "modified"
+    } else {
+        "clean"
+    }
+}
+
+/// Submodules of the site repository and how their checkouts compare to
+/// the recorded commits. Nested submodules are not listed.
+pub(super) fn submodule_states(repo: &Repository) -> Vec<SubmoduleInfo> {
+    let Ok(submodules) = repo.submodules() else { return Vec::new() };
+    submodules.iter().map(|submodule| {
+        let name = submodule.name().unwrap_or("").to_string();
+        let status = repo.submodule_status(&name, SubmoduleIgnore::None)
+            .unwrap_or(SubmoduleStatus::empty());
+        SubmoduleInfo {
+            path: submodule.path().to_string_lossy().into_owned(),
+            url: submodule.url().map(str::to_string),
+            recorded_commit: submodule.head_id().map(|id| id.to_string()),
+            checked_out_commit: submodule.workdir_id().map(|id| id.to_string()),
+            state: state(status).to_string(),
+            name,
+        }
+    }).collect()
+}
+
+/// Initialize every submodule and check out the commit the repository
+/// records for it, recursively, fetching with the same credentials as the
+/// site repository. Returns how many submodules were updated.
+pub(super) fn update_submodules(repo: &Repository, creds: Option<&GitCredentials>) -> Result<usize, AppError> {
+    update_recursive(repo, creds, 0)
+}
+
+fn update_recursive(repo: &Repository, creds: Option<&GitCredentials>, depth: usize) -> Result<usize, AppError> {
+    if depth >= MAX_DEPTH {
+        return Ok(0);
+    }
+    let submodules = repo.submodules()
+        .map_err(|e| AppError::Internal(format!("Failed to read submodules: {}", e.message())))?;
+    let mut updated = 0;
+    for mut submodule in submodules {
+        let path = submodule.path().to_string_lossy().into_owned();
+        let mut opts = SubmoduleUpdateOptions::new();
+        opts.fetch(make_fetch_options(creds, submodule.url().unwrap_or_default()));
+        submodule.update(true, Some(&mut opts))
+            .map_err(|e| AppError::Internal(format!("Failed to update submodule '{}': {}", path, e.message())))?;
+        let nested = submodule.open()
+            .map_err(|e| AppError::Internal(format!("Failed to open submodule '{}': {}", path, e.message())))?;
+        updated += 1 + update_recursive(&nested, creds, depth + 1)?;
+    }
+    Ok(updated)
+}
+
+/// Directories of the site repository's submodules, relative to ROOT.
+/// Empty when ROOT is not a repository.
+pub fn submodule_paths_internal() -> Vec<String> {
+    let Ok(repo) = open_repo() else { return Vec::new() };
+    repo.submodules()
+        .map(|submodules| submodules.iter().map(|s| s.path().to_string_lossy().into_owned()).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,242 @@
+// backend_simple_web/src/api/git/tags.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use git2::{Oid, Repository};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::repo_lock::{RepoRead, RepoWrite};
+use super::credentials::load_git_credentials;
+use super::identity::{Identity, IdentityConfig};
+use super::remotes::{self, PushResponse};
+use super::{git_result, open_repo, signature, GitStatus};
+use crate::api::error::AppError;
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TagInfo {
+    pub name: String,
+    /// Commit the tag points to
+    pub target: String,
+    pub annotated: bool,
+    /// Message of an annotated tag
+    pub message: Option<String>,
+    /// `Name <email>` of whoever created an annotated tag
+    pub tagger: Option<String>,
+    /// Unix timestamp (seconds) of the tag, or of the commit for lightweight tags
+    pub date: i64,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TagListResponse {
+    pub success: bool,
+    pub message: String,
+    pub tags: Vec<TagInfo>,
+}
+
+impl TagListResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), tags: vec![] }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct CreateTagRequest {
+    name: String,
+    /// Commit to tag; HEAD when unset
+    commit: Option<String>,
+    message: Option<String>,
+    /// Author profile id to tag as; the server committer when unset
+    author: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PushTagsRequest {
+    /// Tag to push; every tag when unset
+    name: Option<String>,
+    /// Remote to push to; origin and every mirror when unset
+    remote: Option<String>,
+}
+
+fn validate_name(name: &str) -> Result<(), AppError> {
+    if !name.is_empty() && git2::Reference::is_valid_name(&format!("refs/tags/{}", name)) {
+        Ok(())
+    } else {
+        Err(AppError::BadRequest(format!("'{}' is not a valid tag name", name)))
+    }
+}
+
+fn tag_names(repo: &Repository) -> Result<Vec<String>, AppError> {
+    let names = repo.tag_names(None)
+        .map_err(|e| AppError::Internal(format!("Failed to list tags: {}", e)))?;
+    Ok(names.iter().flatten().map(str::to_string).collect())
+}
+
+fn tag_info(repo: &Repository, name: &str) -> Option<TagInfo> {
+    let object = repo.revparse_single(&format!("refs/tags/{}", name)).ok()?;
+    let commit = object.peel_to_commit().ok()?;
+    let info = match object.as_tag() {
+        Some(tag) => TagInfo {
+            name: name.to_string(),
+            target: commit.id().to_string(),
+            annotated: true,
+            message: tag.message().map(|m| m.trim_end().to_string()),
+            tagger: tag.tagger().map(|t| format!("{} <{}>", t.name().unwrap_or(""), t.email().unwrap_or(""))),
+            date: tag.tagger().map_or(commit.time().seconds(), |t| t.when().seconds()),
+        },
+        None => TagInfo {
+            name: name.to_string(),
+            target: commit.id().to_string(),
+            annotated: false,
+            message: None,
+            tagger: None,
+            date: commit.time().seconds(),
+        },
+    };
+    Some(info)
+}
+
+/// Tag names by the commit they point to, for marking commits in the history.
+pub(super) fn tags_by_commit(repo: &Repository) -> HashMap<Oid, Vec<String>> {
+    let mut tags: HashMap<Oid, Vec<String>> = HashMap::new();
+    for name in tag_names(repo).unwrap_or_default() {
+        let commit = repo.revparse_single(&format!("refs/tags/{}", name))
+            .and_then(|object| object.peel_to_commit());
+        if let Ok(commit) = commit {
+            tags.entry(commit.id()).or_default().push(name);
+        }
+    }
+    tags
+}
+
+fn list_inner() -> Result<TagListResponse, AppError> {
+    let repo = open_repo()?;
+    let mut tags: Vec<TagInfo> = tag_names(&repo)?.iter()
+        .filter_map(|name| tag_info(&repo, name))
+        .collect();
+    tags.sort_by(|a, b| b.date.cmp(&a.date).then_with(|| a.name.cmp(&b.name)));
+    Ok(TagListResponse {
+        success: true,
+        message: format!("{} tags", tags.len()),
+        tags,
+    })
+}
+
+fn create_inner(request: &CreateTagRequest, tagger: Option<&Identity>) -> Result<GitStatus, AppError> {
+    let name = request.name.trim();
+    validate_name(name)?;
+    let repo = open_repo()?;
+    if repo.find_reference(&format!("refs/tags/{}", name)).is_ok() {
+        return Err(AppError::BadRequest(format!("Tag '{}' already exists", name)));
+    }
+    let rev = request.commit.as_deref().map(str::trim).filter(|c| !c.is_empty()).unwrap_or("HEAD");
+    let commit = repo.revparse_single(rev)
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| AppError::BadRequest(format!("Unknown commit '{}': {}", rev, e.message())))?;
+
+    let tagger = match tagger {
+        Some(identity) => identity.signature()
+            .map_err(|e| AppError::BadRequest(format!("Invalid author: {}", e.message())))?,
+        None => signature(&repo),
+    };
+    let message = request.message.as_deref().map(str::trim).filter(|m| !m.is_empty()).unwrap_or(name);
+    repo.tag(name, commit.as_object(), &tagger, message, false)
+        .map_err(|e| AppError::Internal(format!("Failed to create tag: {}", e.message())))?;
+
+    info!("Tagged {} as {}", commit.id(), name);
+    Ok(GitStatus::ok(
+        format!("Tagged {} as '{}'", &commit.id().to_string()[..7], name),
+        Some(commit.id().to_string()),
+    ))
+}
+
+fn delete_inner(name: &str) -> Result<GitStatus, AppError> {
+    let repo = open_repo()?;
+    if repo.find_reference(&format!("refs/tags/{}", name)).is_err() {
+        return Err(AppError::NotFound(format!("Tag '{}' not found", name)));
+    }
+    repo.tag_delete(name)
+        .map_err(|e| AppError::Internal(format!("Failed to delete tag: {}", e.message())))?;
+    info!("Deleted tag {}", name);
+    Ok(GitStatus::ok(format!("Deleted tag '{}'", name), None))
+}
+
+async fn push_inner(request: &PushTagsRequest) -> Result<PushResponse, AppError> {
+    // Load credentials before any git2 operations (git2 types aren't Send)
+    let creds = load_git_credentials().await;
+
+    let repo = open_repo()?;
+    let refspecs = match request.name.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+        Some(name) => {
+            if repo.find_reference(&format!("refs/tags/{}", name)).is_err() {
+                return Err(AppError::NotFound(format!("Tag '{}' not found", name)));
+            }
+            vec![format!("refs/tags/{0}:refs/tags/{0}", name)]
+        }
+        None => tag_names(&repo)?.iter().map(|name| format!("refs/tags/{0}:refs/tags/{0}", name)).collect(),
+    };
+    if refspecs.is_empty() {
+        return Err(AppError::BadRequest("There are no tags to push".into()));
+    }
+    let targets = match request.remote.as_deref().map(str::trim).filter(|r| !r.is_empty()) {
+        Some(name) => vec![name.to_string()],
+        None => remotes::push_targets(&repo)?,
+    };
+    Ok(remotes::push_to_remotes(&repo, &targets, &refspecs, &creds, None))
+}
+
+/// Tags, newest first, with the message and tagger of annotated tags
+/// ### Examples:
+/// - GET /api/git/tags
+#[get("/git/tags")]
+pub async fn list_tags(_admin: Admin, _lock: RepoRead) -> Json<TagListResponse> {
+    match list_inner() {
+        Ok(response) => Json(response),
+        Err(e) => Json(TagListResponse::err(e.to_string())),
+    }
+}
+
+/// Create an annotated tag on HEAD or a given commit. The message defaults
+/// to the tag name.
+/// ### Examples:
+/// - POST /api/git/tags  JSON ```{"name":"v1.0"}```
+/// - POST /api/git/tags  JSON ```{"name":"launch","commit":"a1b2c3d","message":"Site as launched","author":"…"}```
+#[post("/git/tags", data = "<request>")]
+pub async fn create_tag(request: Json<CreateTagRequest>, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    let tagger = match &request.author {
+        Some(id) => match IdentityConfig::load().await.and_then(|config| config.author(id)) {
+            Ok(identity) => Some(identity),
+            Err(e) => return git_result(Err(e)),
+        },
+        None => None,
+    };
+    git_result(create_inner(&request, tagger.as_ref()))
+}
+
+/// Delete a local tag. Tags already pushed stay on the remotes.
+/// ### Examples:
+/// - DELETE /api/git/tags/v1.0
+/// - DELETE /api/git/tags/release/2026-10
+#[delete("/git/tags/<name..>")]
+pub async fn delete_tag(name: PathBuf, _admin: Admin, _lock: RepoWrite) -> Json<GitStatus> {
+    git_result(delete_inner(&name.to_string_lossy()))
+}
+
+/// Push one tag or every tag to a remote, or to origin and every mirror
+/// ### Examples:
+/// - POST /api/git/tags/push  JSON ```{}```
+/// - POST /api/git/tags/push  JSON ```{"name":"v1.0","remote":"backup"}```
+#[post("/git/tags/push", data = "<request>")]
+pub async fn push_tags(request: Json<PushTagsRequest>, _admin: Admin, _lock: RepoWrite) -> Json<PushResponse> {
+    info!("Pushing tags to {}", request.remote.as_deref().unwrap_or("origin and mirrors"));
+    match push_inner(&request).await {
+        Ok(response) => Json(response),
+        Err(e) => {
+            error!("{}", e);
+            Json(PushResponse::err(e.to_string()))
+        }
+    }
+}
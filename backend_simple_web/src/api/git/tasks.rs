@@ -0,0 +1,354 @@
+// backend_simple_web/src/api/git/tasks.rs
+//
+// Clone, pull and push as background tasks, so large transfers don't hold a
+// request open. Tasks run one at a time, holding the repository lock, on a
+// blocking thread (git2 types aren't Send), and the remote callbacks of
+// whatever they run report libgit2's transfer progress to them.
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use rocket::tokio::{self, sync::Mutex as AsyncMutex};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use git2::RemoteCallbacks;
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::api::error::AppError;
+use crate::api::repo_lock;
+use crate::storage::now_secs;
+use super::{
+    force_pull_inner, pull_repo_inner, push_repo_inner, setup_git_repo_inner, GitRepoConfig, GitStatus, PullMode,
+    DEFAULT_REMOTE,
+};
+
+/// Finished tasks kept for the list; older ones are forgotten
+const KEEP_FINISHED: usize = 20;
+
+/// Held by the running task, so queued ones wait their turn
+static RUN_LOCK: AsyncMutex<()> = AsyncMutex::const_new(());
+static TASKS: Mutex<VecDeque<Arc<TaskHandle>>> = Mutex::new(VecDeque::new());
+
+thread_local! {
+    /// Task whose operation runs on this thread
+    static CURRENT: RefCell<Option<Arc<TaskHandle>>> = const { RefCell::new(None) };
+}
+
+/// Operation to run in the background, with the options of its endpoint
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde", tag = "operation", rename_all = "snake_case")]
+pub enum TaskRequest {
+    /// As POST /api/git/setup
+    Clone(GitRepoConfig),
+    /// As POST /api/git/pull
+    Pull {
+        #[serde(default)]
+        mode: PullMode,
+        #[serde(default)]
+        stash: bool,
+        remote: Option<String>,
+    },
+    /// As POST /api/git/force-pull
+    ForcePull,
+    /// As POST /api/git/push
+    Push { remote: Option<String> },
+}
+
+impl TaskRequest {
+    fn operation(&self) -> &'static str {
+        match self {
+            TaskRequest::Clone(_) => "clone",
+            TaskRequest::Pull { .. } => "pull",
+            TaskRequest::ForcePull => "force_pull",
+            TaskRequest::Push { .. } => "push",
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum TaskState {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Serialize, Clone, Default)]
+#[serde(crate = "rocket::serde")]
+pub struct TaskProgress {
+    /// `receiving` or `resolving` objects when fetching, `packing` or
+    /// `uploading` them when pushing
+    pub stage: Option<String>,
+    pub current: usize,
+    pub total: usize,
+    /// Bytes received or sent so far
+    pub bytes: usize,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct GitTask {
+    pub id: String,
+    /// `clone`, `pull`, `force_pull` or `push`
+    pub operation: String,
+    pub state: TaskState,
+    pub progress: TaskProgress,
+    /// Outcome, once finished
+    pub message: Option<String>,
+    pub commit_hash: Option<String>,
+    /// Unix timestamps (seconds)
+    pub created_at: u64,
+    pub finished_at: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TaskResponse {
+    pub success: bool,
+    pub message: String,
+    pub task: Option<GitTask>,
+}
+
+impl TaskResponse {
+    fn err(message: impl Into<String>) -> Self {
+        Self { success: false, message: message.into(), task: None }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct TaskListResponse {
+    pub success: bool,
+    pub message: String,
+    pub tasks: Vec<GitTask>,
+}
+
+struct TaskHandle {
+    task: Mutex<GitTask>,
+    cancel: AtomicBool,
+}
+
+impl TaskHandle {
+    fn snapshot(&self) -> GitTask {
+        self.task.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn update(&self, change: impl FnOnce(&mut GitTask)) {
+        change(&mut self.task.lock().unwrap_or_else(|e| e.into_inner()));
+    }
+
+    fn set_progress(&self, stage: &str, current: usize, total: usize, bytes: usize) {
+        self.update(|task| {
+            task.progress = TaskProgress { stage: Some(stage.to_string()), current, total, bytes };
+        });
+    }
+
+    fn cancelled(&self) -> bool {
+        self.cancel.load(Ordering::Relaxed)
+    }
+
+    fn finish(&self, status: GitStatus) {
+        let state = if status.success {
+            TaskState::Succeeded
+        } else if self.cancelled() {
+            TaskState::Cancelled
+        } else {
+            TaskState::Failed
+        };
+        self.update(|task| {
+            task.state = state;
+            task.message = Some(status.message);
+            task.commit_hash = status.commit_hash;
+            task.finished_at = Some(now_secs());
+        });
+    }
+
+    fn is_finished(&self) -> bool {
+        matches!(self.snapshot().state, TaskState::Succeeded | TaskState::Failed | TaskState::Cancelled)
+    }
+}
+
+fn tasks() -> std::sync::MutexGuard<'static, VecDeque<Arc<TaskHandle>>> {
+    TASKS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn find_task(id: &str) -> Option<Arc<TaskHandle>> {
+    tasks().iter().find(|handle| handle.snapshot().id == id).cloned()
+}
+
+/// Report transfer progress to the task running on this thread, if any, and
+/// stop the transfer when it is cancelled. A push can only be stopped before
+/// it starts uploading.
+pub(super) fn attach_progress(callbacks: &mut RemoteCallbacks<'_>) {
+    let Some(task) = CURRENT.with(|current| current.borrow().clone()) else { return };
+
+    let fetching = task.clone();
+    callbacks.transfer_progress(move |progress| {
+        if progress.total_deltas() == 0 || progress.received_objects() < progress.total_objects() {
+            fetching.set_progress("receiving", progress.received_objects(), progress.total_objects(), progress.received_bytes());
+        } else {
+            fetching.set_progress("resolving", progress.indexed_deltas(), progress.total_deltas(), progress.received_bytes());
+        }
+        !fetching.cancelled()
+    });
+    let sideband = task.clone();
+    callbacks.sideband_progress(move |_| !sideband.cancelled());
+    let packing = task.clone();
+    callbacks.pack_progress(move |_, current, total| packing.set_progress("packing", current, total, 0));
+    let uploading = task.clone();
+    callbacks.push_transfer_progress(move |current, total, bytes| uploading.set_progress("uploading", current, total, bytes));
+    callbacks.push_negotiation(move |_| {
+        if task.cancelled() {
+            Err(git2::Error::from_str("Cancelled"))
+        } else {
+            Ok(())
+        }
+    });
+}
+
+/// Run a git operation on a blocking thread. libgit2 blocks on the network
+/// during fetches and pushes, which would otherwise stall an async worker.
+pub(super) async fn blocking<F, Fut, T>(operation: F) -> Result<T, AppError>
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = Result<T, AppError>>,
+    T: Send + 'static,
+{
+    let runtime = tokio::runtime::Handle::current();
+    tokio::task::spawn_blocking(move || runtime.block_on(operation())).await
+        .unwrap_or_else(|e| Err(AppError::Internal(format!("The operation stopped unexpectedly: {}", e))))
+}
+
+async fn run(request: TaskRequest) -> Result<GitStatus, AppError> {
+    match request {
+        TaskRequest::Clone(config) => setup_git_repo_inner(&config).await,
+        TaskRequest::Pull { mode, stash, remote } => {
+            pull_repo_inner(mode, stash, remote.as_deref().unwrap_or(DEFAULT_REMOTE)).await
+        }
+        TaskRequest::ForcePull => force_pull_inner().await,
+        TaskRequest::Push { remote } => push_repo_inner(remote.as_deref()).await.map(|response| GitStatus {
+            success: response.success,
+            message: response.message,
+            commit_hash: response.commit_hash,
+        }),
+    }
+}
+
+/// Queue `request` and return the new task
+fn start(request: TaskRequest) -> GitTask {
+    let handle = Arc::new(TaskHandle {
+        task: Mutex::new(GitTask {
+            id: uuid::Uuid::new_v4().to_string(),
+            operation: request.operation().to_string(),
+            state: TaskState::Queued,
+            progress: TaskProgress::default(),
+            message: None,
+            commit_hash: None,
+            created_at: now_secs(),
+            finished_at: None,
+        }),
+        cancel: AtomicBool::new(false),
+    });
+    let task = handle.snapshot();
+    {
+        let mut tasks = tasks();
+        tasks.push_back(handle.clone());
+        while tasks.iter().filter(|t| t.is_finished()).count() > KEEP_FINISHED {
+            let Some(oldest) = tasks.iter().position(|t| t.is_finished()) else { break };
+            tasks.remove(oldest);
+        }
+    }
+
+    tokio::spawn(async move {
+        let _turn = RUN_LOCK.lock().await;
+        if handle.cancelled() {
+            handle.finish(GitStatus { success: false, message: "Cancelled before it started".into(), commit_hash: None });
+            return;
+        }
+        let operation = handle.snapshot().operation.replace('_', " ");
+        let _lock = match repo_lock::write(format!("{} task", operation)).await {
+            Ok(lock) => lock,
+            Err(e) => {
+                handle.finish(GitStatus { success: false, message: e.to_string(), commit_hash: None });
+                return;
+            }
+        };
+        handle.update(|task| task.state = TaskState::Running);
+        info!("Running git task {} ({})", handle.snapshot().id, handle.snapshot().operation);
+
+        let running = handle.clone();
+        let status = blocking(move || async move {
+            CURRENT.with(|current| *current.borrow_mut() = Some(running));
+            let status = run(request).await;
+            CURRENT.with(|current| current.borrow_mut().take());
+            status
+        }).await;
+        handle.finish(status.unwrap_or_else(|e| GitStatus { success: false, message: e.to_string(), commit_hash: None }));
+    });
+    task
+}
+
+/// Start a clone, pull, force pull or push in the background. Poll the
+/// returned task for its progress and outcome.
+/// ### Examples:
+/// - POST /api/git/tasks  JSON ```{"operation":"clone","url":"https://github.com/user/site.git","depth":1}```
+/// - POST /api/git/tasks  JSON ```{"operation":"pull","mode":"rebase","stash":true}```
+/// - POST /api/git/tasks  JSON ```{"operation":"push","remote":"backup"}```
+#[post("/git/tasks", data = "<request>")]
+pub async fn start_task(request: Json<TaskRequest>, _admin: Admin) -> Json<TaskResponse> {
+    let task = start(request.into_inner());
+    Json(TaskResponse {
+        success: true,
+        message: format!("Queued {}", task.operation.replace('_', " ")),
+        task: Some(task),
+    })
+}
+
+/// Running and queued tasks, then recently finished ones, newest first
+/// ### Examples:
+/// - GET /api/git/tasks
+#[get("/git/tasks")]
+pub async fn list_tasks(_admin: Admin) -> Json<TaskListResponse> {
+    let mut tasks: Vec<GitTask> = tasks().iter().map(|handle| handle.snapshot()).collect();
+    tasks.reverse();
+    tasks.sort_by_key(|task| task.finished_at.is_some());
+    Json(TaskListResponse {
+        success: true,
+        message: format!("{} tasks", tasks.len()),
+        tasks,
+    })
+}
+
+/// State, progress and outcome of a task
+/// ### Examples:
+/// - GET /api/git/tasks/3f2b6c1e-…
+#[get("/git/tasks/<id>")]
+pub async fn get_task(id: &str, _admin: Admin) -> Json<TaskResponse> {
+    match find_task(id) {
+        Some(handle) => {
+            let task = handle.snapshot();
+            let message = task.message.clone().unwrap_or_else(|| "In progress".into());
+            Json(TaskResponse { success: true, message, task: Some(task) })
+        }
+        None => Json(TaskResponse::err(format!("No task '{}'", id))),
+    }
+}
+
+/// Cancel a queued task, or stop a running one's transfer. Work already
+/// done, such as a completed push to one of several remotes, is kept.
+/// ### Examples:
+/// - POST /api/git/tasks/3f2b6c1e-…/cancel
+#[post("/git/tasks/<id>/cancel")]
+pub async fn cancel_task(id: &str, _admin: Admin) -> Json<GitStatus> {
+    let Some(handle) = find_task(id) else {
+        return Json(GitStatus { success: false, message: format!("No task '{}'", id), commit_hash: None });
+    };
+    if handle.is_finished() {
+        return Json(GitStatus { success: false, message: "The task has already finished".into(), commit_hash: None });
+    }
+    handle.cancel.store(true, Ordering::Relaxed);
+    info!("Cancelling git task {}", id);
+    Json(GitStatus::ok("Cancelling", None))
+}
@@ -0,0 +1,570 @@
+// backend_simple_web/src/api/git/webhooks.rs
+use rocket::data::{Data, ToByteUnit};
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::serde::{json::Json, Deserialize, Serialize};
+use rocket::tokio::sync::Mutex;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+use std::collections::VecDeque;
+use std::path::Path;
+
+use prisma_auth::backend::AuthGuard as Admin;
+use super::credentials::{hex, mask, random_bytes};
+use super::{current_branch_name, git_result, open_repo, pull_repo_internal, GitStatus, PullPolicy};
+use crate::api::error::AppError;
+use crate::storage::{self, now_secs};
+
+const WEBHOOKS_PATH: &str = "/app/data/git_webhooks.json";
+const DELIVERIES_PATH: &str = "/app/data/git_webhook_deliveries.json";
+/// Older deliveries are dropped once the log grows beyond this
+const MAX_DELIVERIES: usize = 200;
+/// Push payloads of large pushes stay well below this
+const MAX_PAYLOAD_MIB: u64 = 5;
+/// Shortest secret accepted when one is set by hand
+const MIN_SECRET_LEN: usize = 16;
+
+/// Serializes changes to the webhook and delivery files
+static STORE_LOCK: Mutex<()> = Mutex::const_new(());
+/// Pulls waiting to run, each with the deliveries it covers
+static PENDING: Mutex<Vec<PendingPull>> = Mutex::const_new(Vec::new());
+/// Held while a webhook pull runs, so queued pulls run one after another
+static PULL_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Webhook {
+    pub id: String,
+    pub name: String,
+    /// Shared secret: the HMAC key for GitHub, Gitea and Forgejo, the token for GitLab
+    pub secret: String,
+    /// Branch whose pushes trigger a pull; the checked-out branch when unset
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub policy: PullPolicy,
+    pub enabled: bool,
+}
+
+/// A queued pull. Deliveries for the same branch and policy join it; others
+/// queue a pull of their own.
+struct PendingPull {
+    branch: String,
+    policy: PullPolicy,
+    deliveries: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(crate = "rocket::serde")]
+struct WebhookStore {
+    hooks: Vec<Webhook>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum Provider {
+    Github,
+    Gitea,
+    Forgejo,
+    Gitlab,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(crate = "rocket::serde", rename_all = "snake_case")]
+pub enum DeliveryStatus {
+    /// Signature or token did not match. Answered but not logged, so
+    /// unauthenticated requests cannot push real deliveries out of the log.
+    Rejected,
+    /// Not a push, a push to another branch, or the branch was no longer
+    /// checked out when the pull ran
+    Ignored,
+    /// Waiting for its pull
+    Queued,
+    Succeeded,
+    Failed,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct Delivery {
+    pub id: String,
+    pub hook_id: String,
+    pub provider: Option<Provider>,
+    pub event: Option<String>,
+    /// Branch the push went to
+    pub branch: Option<String>,
+    /// Unix timestamp (seconds)
+    pub received_at: u64,
+    pub status: DeliveryStatus,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(crate = "rocket::serde")]
+struct DeliveryLog {
+    /// Oldest first
+    deliveries: VecDeque<Delivery>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct WebhookInfo {
+    pub id: String,
+    pub name: String,
+    /// Masked secret; the full one is only returned when it is set
+    pub secret: String,
+    pub branch: Option<String>,
+    pub policy: PullPolicy,
+    pub enabled: bool,
+    /// Path to configure on the git host, relative to the API URL
+    pub path: String,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SaveWebhookResponse {
+    pub success: bool,
+    pub message: String,
+    /// Id of the saved hook
+    pub commit_hash: Option<String>,
+    /// The new secret, when the hook was created or its secret replaced
+    pub secret: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct WebhookListResponse {
+    pub success: bool,
+    pub message: String,
+    pub hooks: Vec<WebhookInfo>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DeliveryListResponse {
+    pub success: bool,
+    pub message: String,
+    pub deliveries: Vec<Delivery>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SaveWebhookRequest {
+    /// Hook to update; a new one is created when unset
+    id: Option<String>,
+    name: String,
+    branch: Option<String>,
+    #[serde(default)]
+    policy: PullPolicy,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+    /// Replace the secret of an existing hook
+    #[serde(default)]
+    regenerate_secret: bool,
+    /// Secret to use instead of a random one, e.g. one the git host generated
+    secret: Option<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Answer to the git host
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct HookResponse {
+    pub success: bool,
+    pub message: String,
+    pub delivery: Option<String>,
+}
+
+/// Headers the supported git hosts send with a webhook
+pub struct HookHeaders {
+    provider: Option<Provider>,
+    event: Option<String>,
+    /// Hex HMAC-SHA256 of the body, or the plain token for GitLab
+    signature: Option<String>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for HookHeaders {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ()> {
+        let header = |name: &str| req.headers().get_one(name).map(str::to_string);
+        // Forgejo and Gitea also send GitHub's headers, so they are checked first
+        let (provider, event, signature) = if let Some(signature) = header("X-Forgejo-Signature") {
+            (Some(Provider::Forgejo), header("X-Forgejo-Event"), Some(signature))
+        } else if let Some(signature) = header("X-Gitea-Signature") {
+            (Some(Provider::Gitea), header("X-Gitea-Event"), Some(signature))
+        } else if let Some(signature) = header("X-Hub-Signature-256") {
+            let signature = signature.strip_prefix("sha256=").map(str::to_string);
+            (Some(Provider::Github), header("X-GitHub-Event"), signature)
+        } else if let Some(event) = header("X-Gitlab-Event") {
+            (Some(Provider::Gitlab), Some(event), header("X-Gitlab-Token"))
+        } else {
+            (None, None, None)
+        };
+        Outcome::Success(HookHeaders { provider, event, signature })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+struct PushPayload {
+    #[serde(rename = "ref")]
+    git_ref: Option<String>,
+}
+
+fn new_secret() -> String {
+    hex(&random_bytes::<32>())
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Option<Vec<u8>> {
+    let key = PKey::hmac(key).ok()?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).ok()?;
+    signer.update(data).ok()?;
+    signer.sign_to_vec().ok()
+}
+
+/// Compare without returning early, so timing does not reveal the secret
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && openssl::memcmp::eq(a, b)
+}
+
+/// Whether the delivery carries a valid signature (or token) for `secret`.
+fn verify(headers: &HookHeaders, secret: &str, body: &[u8]) -> bool {
+    let Some(signature) = headers.signature.as_deref() else {
+        return false;
+    };
+    match headers.provider {
+        Some(Provider::Gitlab) => constant_time_eq(signature.as_bytes(), secret.as_bytes()),
+        Some(_) => hmac_sha256(secret.as_bytes(), body)
+            .is_some_and(|mac| constant_time_eq(signature.trim().to_lowercase().as_bytes(), hex(&mac).as_bytes())),
+        None => false,
+    }
+}
+
+fn is_push(provider: Provider, event: &str) -> bool {
+    match provider {
+        Provider::Gitlab => event == "Push Hook",
+        _ => event == "push",
+    }
+}
+
+async fn load_hooks() -> WebhookStore {
+    match storage::load_json(Path::new(WEBHOOKS_PATH)).await {
+        Ok(store) => store.unwrap_or_default(),
+        Err(e) => {
+            error!("Failed to load webhooks: {}", e);
+            WebhookStore::default()
+        }
+    }
+}
+
+async fn load_deliveries() -> DeliveryLog {
+    match storage::load_json(Path::new(DELIVERIES_PATH)).await {
+        Ok(log) => log.unwrap_or_default(),
+        Err(e) => {
+            error!("Failed to load webhook deliveries, starting empty: {}", e);
+            DeliveryLog::default()
+        }
+    }
+}
+
+async fn record_delivery(delivery: Delivery) {
+    let _guard = STORE_LOCK.lock().await;
+    let mut log = load_deliveries().await;
+    log.deliveries.push_back(delivery);
+    while log.deliveries.len() > MAX_DELIVERIES {
+        log.deliveries.pop_front();
+    }
+    if let Err(e) = storage::save_json(Path::new(DELIVERIES_PATH), &log).await {
+        error!("Failed to persist webhook deliveries: {}", e);
+    }
+}
+
+/// Set the outcome of the pull on every delivery it covered.
+async fn finish_deliveries(ids: &[String], (status, message): (DeliveryStatus, String)) {
+    let _guard = STORE_LOCK.lock().await;
+    let mut log = load_deliveries().await;
+    for delivery in log.deliveries.iter_mut().filter(|d| ids.contains(&d.id)) {
+        delivery.status = status;
+        delivery.message = message.clone();
+    }
+    if let Err(e) = storage::save_json(Path::new(DELIVERIES_PATH), &log).await {
+        error!("Failed to persist webhook deliveries: {}", e);
+    }
+}
+
+/// Queue a pull of `branch` for `delivery_id`. Deliveries arriving before the
+/// pull starts join it when they want the same branch and policy.
+async fn queue_pull(delivery_id: String, branch: String, policy: PullPolicy) -> &'static str {
+    let mut pending = PENDING.lock().await;
+    if let Some(pull) = pending.iter_mut().find(|p| p.branch == branch && p.policy == policy) {
+        pull.deliveries.push(delivery_id);
+        return "Joined the pull already queued";
+    }
+    pending.push(PendingPull { branch: branch.clone(), policy, deliveries: vec![delivery_id] });
+    rocket::tokio::spawn(async move {
+        let _running = PULL_LOCK.lock().await;
+        let ids = {
+            let mut pending = PENDING.lock().await;
+            match pending.iter().position(|p| p.branch == branch && p.policy == policy) {
+                Some(index) => pending.remove(index).deliveries,
+                None => Vec::new(),
+            }
+        };
+        let outcome = pull_branch(&branch, policy).await;
+        finish_deliveries(&ids, outcome).await;
+    });
+    "Pull queued"
+}
+
+/// Pull if `branch` is still the checked-out one when the pull's turn comes.
+async fn pull_branch(branch: &str, policy: PullPolicy) -> (DeliveryStatus, String) {
+    let current = match open_repo().and_then(|repo| current_branch_name(&repo)) {
+        Ok(current) => current,
+        Err(e) => return (DeliveryStatus::Failed, e.to_string()),
+    };
+    if current != branch {
+        return (DeliveryStatus::Ignored, format!("Push to '{}', but '{}' is checked out", branch, current));
+    }
+    match pull_repo_internal(policy).await {
+        Ok(status) if status.success => {
+            info!("Webhook pull: {}", status.message);
+            (DeliveryStatus::Succeeded, status.message)
+        }
+        Ok(status) => (DeliveryStatus::Failed, status.message),
+        Err(e) => {
+            error!("Webhook pull failed: {}", e);
+            (DeliveryStatus::Failed, e)
+        }
+    }
+}
+
+/// Which branch a hook follows: its own, or the checked-out one.
+fn watched_branch(hook: &Webhook) -> Result<String, AppError> {
+    match hook.branch.clone().filter(|b| !b.is_empty()) {
+        Some(branch) => Ok(branch),
+        None => current_branch_name(&open_repo()?),
+    }
+}
+
+async fn receive(hook: &Webhook, headers: &HookHeaders, body: &[u8]) -> (Status, Delivery) {
+    let mut delivery = Delivery {
+        id: uuid::Uuid::new_v4().to_string(),
+        hook_id: hook.id.clone(),
+        provider: headers.provider,
+        event: headers.event.clone(),
+        branch: None,
+        received_at: now_secs(),
+        status: DeliveryStatus::Ignored,
+        message: String::new(),
+    };
+    let (Some(provider), Some(event)) = (headers.provider, headers.event.as_deref()) else {
+        delivery.status = DeliveryStatus::Rejected;
+        delivery.message = "Not a GitHub, Gitea, Forgejo or GitLab webhook".into();
+        return (Status::BadRequest, delivery);
+    };
+    if !verify(headers, &hook.secret, body) {
+        delivery.status = DeliveryStatus::Rejected;
+        delivery.message = "Signature does not match the secret".into();
+        return (Status::Unauthorized, delivery);
+    }
+    if !hook.enabled {
+        delivery.message = "Webhook is disabled".into();
+        return (Status::Ok, delivery);
+    }
+    if !is_push(provider, event) {
+        delivery.message = format!("Ignored '{}' event", event);
+        return (Status::Ok, delivery);
+    }
+
+    let pushed = rocket::serde::json::from_slice::<PushPayload>(body).ok()
+        .and_then(|payload| payload.git_ref)
+        .and_then(|git_ref| git_ref.strip_prefix("refs/heads/").map(str::to_string));
+    let Some(pushed) = pushed else {
+        delivery.message = "Push is not to a branch".into();
+        return (Status::Ok, delivery);
+    };
+    delivery.branch = Some(pushed.clone());
+    let watched = match watched_branch(hook) {
+        Ok(branch) => branch,
+        Err(e) => {
+            delivery.status = DeliveryStatus::Failed;
+            delivery.message = e.to_string();
+            return (Status::InternalServerError, delivery);
+        }
+    };
+    if pushed != watched {
+        delivery.message = format!("Push to '{}', watching '{}'", pushed, watched);
+        return (Status::Ok, delivery);
+    }
+
+    delivery.status = DeliveryStatus::Queued;
+    delivery.message = queue_pull(delivery.id.clone(), watched, hook.policy).await.to_string();
+    (Status::Accepted, delivery)
+}
+
+/// Receive a push webhook from GitHub, Gitea, Forgejo or GitLab. The body is
+/// checked against the hook's secret (HMAC-SHA256 signature, or GitLab's
+/// token); a push to the watched branch queues a pull.
+/// ### Examples:
+/// - POST /api/hooks/git/0b7c9c3e-4a52-4f0e-9a53-2f1d7f0c1e7a  (sent by the git host)
+#[post("/hooks/git/<id>", data = "<data>")]
+pub async fn receive_webhook(id: &str, headers: HookHeaders, data: Data<'_>) -> (Status, Json<HookResponse>) {
+    let respond = |status: Status, success: bool, message: String, delivery: Option<String>| {
+        (status, Json(HookResponse { success, message, delivery }))
+    };
+    let Some(hook) = load_hooks().await.hooks.into_iter().find(|h| h.id == id) else {
+        return respond(Status::NotFound, false, "Unknown webhook".into(), None);
+    };
+    let body = match data.open(MAX_PAYLOAD_MIB.mebibytes()).into_bytes().await {
+        Ok(body) if body.is_complete() => body.into_inner(),
+        Ok(_) => return respond(Status::PayloadTooLarge, false, "Payload too large".into(), None),
+        Err(e) => return respond(Status::BadRequest, false, format!("Failed to read payload: {}", e), None),
+    };
+
+    // Answer a ping as soon as the signature checks out
+    if headers.event.as_deref() == Some("ping") && verify(&headers, &hook.secret, &body) {
+        return respond(Status::Ok, true, "pong".into(), None);
+    }
+    let (status, delivery) = receive(&hook, &headers, &body).await;
+    if delivery.status == DeliveryStatus::Rejected {
+        warn!("Webhook {} rejected a delivery: {}", hook.name, delivery.message);
+        return respond(status, false, delivery.message, None);
+    }
+    info!("Webhook {} delivery: {}", hook.name, delivery.message);
+    let success = delivery.status != DeliveryStatus::Failed;
+    let response = respond(status, success, delivery.message.clone(), Some(delivery.id.clone()));
+    record_delivery(delivery).await;
+    response
+}
+
+/// Configured webhooks, with masked secrets and their receiving paths
+/// ### Examples:
+/// - GET /api/git/webhooks
+#[get("/git/webhooks")]
+pub async fn list_webhooks(_admin: Admin) -> Json<WebhookListResponse> {
+    let hooks: Vec<WebhookInfo> = load_hooks().await.hooks.into_iter()
+        .map(|hook| WebhookInfo {
+            path: format!("/api/hooks/git/{}", hook.id),
+            secret: mask(&hook.secret),
+            id: hook.id, name: hook.name, branch: hook.branch, policy: hook.policy, enabled: hook.enabled,
+        })
+        .collect();
+    Json(WebhookListResponse {
+        success: true,
+        message: format!("{} webhooks", hooks.len()),
+        hooks,
+    })
+}
+
+async fn save_inner(request: &SaveWebhookRequest) -> Result<SaveWebhookResponse, AppError> {
+    let name = request.name.trim();
+    if name.is_empty() {
+        return Err(AppError::BadRequest("Webhook name cannot be empty".into()));
+    }
+    let branch = request.branch.as_deref().map(str::trim).filter(|b| !b.is_empty()).map(str::to_string);
+    if let Some(invalid) = branch.as_deref().filter(|b| !git2::Branch::name_is_valid(b).unwrap_or(false)) {
+        return Err(AppError::BadRequest(format!("'{}' is not a valid branch name", invalid)));
+    }
+    let chosen_secret = request.secret.as_deref().map(str::trim).filter(|s| !s.is_empty());
+    if chosen_secret.is_some_and(|secret| secret.len() < MIN_SECRET_LEN) {
+        return Err(AppError::BadRequest(format!("The secret must be at least {} characters", MIN_SECRET_LEN)));
+    }
+    let set_secret = chosen_secret.map(str::to_string)
+        .or_else(|| (request.id.is_none() || request.regenerate_secret).then(new_secret));
+
+    let _guard = STORE_LOCK.lock().await;
+    let mut store = load_hooks().await;
+    let id = match &request.id {
+        Some(id) => {
+            let hook = store.hooks.iter_mut().find(|h| h.id == *id)
+                .ok_or_else(|| AppError::NotFound(format!("Webhook '{}' not found", id)))?;
+            hook.name = name.to_string();
+            hook.branch = branch;
+            hook.policy = request.policy;
+            hook.enabled = request.enabled;
+            if let Some(secret) = &set_secret {
+                hook.secret = secret.clone();
+            }
+            id.clone()
+        }
+        None => {
+            let id = uuid::Uuid::new_v4().to_string();
+            store.hooks.push(Webhook {
+                id: id.clone(),
+                name: name.to_string(),
+                secret: set_secret.clone().unwrap_or_else(new_secret),
+                branch,
+                policy: request.policy,
+                enabled: request.enabled,
+            });
+            id
+        }
+    };
+    storage::save_json(Path::new(WEBHOOKS_PATH), &store).await?;
+    info!("Saved webhook {}", name);
+    let message = match set_secret {
+        Some(_) => format!("Saved webhook '{}'. Copy the secret now; it is not shown again.", name),
+        None => format!("Saved webhook '{}'", name),
+    };
+    Ok(SaveWebhookResponse { success: true, message, commit_hash: Some(id), secret: set_secret })
+}
+
+/// Create a webhook, or update one when `id` is given. New hooks get a random
+/// secret unless one is given; the id is returned in `commit_hash`, and a new
+/// secret in `secret`, the only time it is shown in full.
+/// ### Examples:
+/// - POST /api/git/webhooks  JSON ```{"name":"Forgejo"}```
+/// - POST /api/git/webhooks  JSON ```{"name":"GitLab","secret":"token-from-the-git-host"}```
+/// - POST /api/git/webhooks  JSON ```{"id":"…","name":"GitHub","branch":"main","policy":{"dirty":"stash","diverged":"skip"},"enabled":true,"regenerate_secret":true}```
+#[post("/git/webhooks", data = "<request>")]
+pub async fn save_webhook(request: Json<SaveWebhookRequest>, _admin: Admin) -> Json<SaveWebhookResponse> {
+    match save_inner(&request).await {
+        Ok(response) => Json(response),
+        Err(e) => {
+            error!("{}", e);
+            Json(SaveWebhookResponse { success: false, message: e.to_string(), commit_hash: None, secret: None })
+        }
+    }
+}
+
+async fn delete_inner(id: &str) -> Result<GitStatus, AppError> {
+    let _guard = STORE_LOCK.lock().await;
+    let mut store = load_hooks().await;
+    let before = store.hooks.len();
+    store.hooks.retain(|h| h.id != id);
+    if store.hooks.len() == before {
+        return Err(AppError::NotFound(format!("Webhook '{}' not found", id)));
+    }
+    storage::save_json(Path::new(WEBHOOKS_PATH), &store).await?;
+    Ok(GitStatus::ok("Deleted webhook", None))
+}
+
+/// Delete a webhook; its deliveries stay in the log
+/// ### Examples:
+/// - DELETE /api/git/webhooks/0b7c9c3e-4a52-4f0e-9a53-2f1d7f0c1e7a
+#[delete("/git/webhooks/<id>")]
+pub async fn delete_webhook(id: &str, _admin: Admin) -> Json<GitStatus> {
+    git_result(delete_inner(id).await)
+}
+
+/// Recent deliveries, newest first, optionally of one webhook
+/// ### Examples:
+/// - GET /api/git/webhooks/deliveries
+/// - GET /api/git/webhooks/deliveries?hook=0b7c9c3e-4a52-4f0e-9a53-2f1d7f0c1e7a&limit=20
+#[get("/git/webhooks/deliveries?<hook>&<limit>")]
+pub async fn list_deliveries(hook: Option<&str>, limit: Option<usize>, _admin: Admin) -> Json<DeliveryListResponse> {
+    let deliveries: Vec<Delivery> = load_deliveries().await.deliveries.into_iter().rev()
+        .filter(|d| hook.is_none_or(|id| d.hook_id == id))
+        .take(limit.unwrap_or(50).min(MAX_DELIVERIES))
+        .collect();
+    Json(DeliveryListResponse {
+        success: true,
+        message: format!("{} deliveries", deliveries.len()),
+        deliveries,
+    })
+}
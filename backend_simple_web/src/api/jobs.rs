@@ -0,0 +1,219 @@
+// backend_simple_web/src/api/jobs.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::job_history::{JobRun, RunSummary, MAX_RUNS};
+use crate::scheduler::{default_pause_after_failures, default_timezone, get_scheduler, JobAction, JobSchedule, JobStatus, RetryPolicy, ScheduledJob};
+use super::error::AppError;
+use super::git::PullPolicy;
+
+/// Job fields accepted on create and update
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JobSpec {
+    name: String,
+    #[serde(default = "default_enabled")]
+    enabled: bool,
+    schedule: JobSchedule,
+    #[serde(default = "default_timezone")]
+    timezone: String,
+    action: JobAction,
+    #[serde(default)]
+    pull_policy: PullPolicy,
+    #[serde(default)]
+    retry: RetryPolicy,
+    #[serde(default = "default_pause_after_failures")]
+    pause_after_failures: u32,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl JobSpec {
+    fn into_job(self, id: String) -> Result<ScheduledJob, AppError> {
+        let job = ScheduledJob {
+            id,
+            name: self.name.trim().to_string(),
+            enabled: self.enabled,
+            schedule: self.schedule,
+            timezone: self.timezone.trim().to_string(),
+            action: self.action,
+            pull_policy: self.pull_policy,
+            retry: self.retry,
+            pause_after_failures: self.pause_after_failures,
+            last_run: None,
+            consecutive_failures: 0,
+            paused: false,
+        };
+        job.validate().map_err(AppError::BadRequest)?;
+        Ok(job)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JobInfo {
+    #[serde(flatten)]
+    job: ScheduledJob,
+    /// Unix timestamp (seconds) of the next scheduled run
+    next_run: Option<u64>,
+    #[serde(flatten)]
+    runs: RunSummary,
+}
+
+impl From<JobStatus> for JobInfo {
+    fn from(status: JobStatus) -> Self {
+        Self { job: status.job, next_run: status.next_run, runs: status.runs }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JobListResponse {
+    pub jobs: Vec<JobInfo>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JobHistoryResponse {
+    pub runs: Vec<JobRun>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JobResponse {
+    pub success: bool,
+    pub message: String,
+    pub job: Option<JobInfo>,
+}
+
+async fn job_info(id: &str) -> Result<JobInfo, AppError> {
+    get_scheduler().await.get_job(id).await
+        .map(JobInfo::from)
+        .ok_or_else(|| AppError::NotFound(format!("Job '{}' not found", id)))
+}
+
+/// List all scheduled jobs
+/// ### Examples:
+/// - GET /api/jobs
+#[get("/jobs")]
+pub async fn list_jobs(_admin: Admin) -> Json<JobListResponse> {
+    let jobs = get_scheduler().await.list_jobs().await;
+    Json(JobListResponse { jobs: jobs.into_iter().map(JobInfo::from).collect() })
+}
+
+/// Recorded job runs, most recent first (default limit 50)
+/// ### Examples:
+/// - GET /api/jobs/history
+/// - GET /api/jobs/history?job=auto-pull&limit=10
+#[get("/jobs/history?<job>&<limit>")]
+pub async fn get_job_history(_admin: Admin, job: Option<&str>, limit: Option<usize>) -> Json<JobHistoryResponse> {
+    let limit = limit.unwrap_or(50).min(MAX_RUNS);
+    let runs = get_scheduler().await.history(job, limit).await;
+    Json(JobHistoryResponse { runs })
+}
+
+/// Get a single scheduled job
+/// ### Examples:
+/// - GET /api/jobs/auto-pull
+#[get("/jobs/<id>")]
+pub async fn get_job(_admin: Admin, id: &str) -> Result<Json<JobResponse>, AppError> {
+    Ok(Json(JobResponse {
+        success: true,
+        message: "Job retrieved successfully".to_string(),
+        job: Some(job_info(id).await?),
+    }))
+}
+
+/// Create a scheduled job
+/// ### Examples:
+/// - POST /api/jobs  JSON ```{"name":"Nightly push","schedule":{"type":"cron","expression":"0 3 * * *"},"timezone":"Europe/Madrid","action":"push"}```
+#[post("/jobs", format = "json", data = "<spec>")]
+pub async fn create_job(_admin: Admin, spec: Json<JobSpec>) -> Result<Json<JobResponse>, AppError> {
+    let job = spec.into_inner().into_job(uuid::Uuid::new_v4().to_string())?;
+    let job = get_scheduler().await.upsert_job(job).await
+        .map_err(|e| AppError::Internal(format!("Failed to create job: {}", e)))?;
+
+    Ok(Json(JobResponse {
+        success: true,
+        message: format!("Job '{}' created successfully", job.name),
+        job: Some(job_info(&job.id).await?),
+    }))
+}
+
+/// Update a scheduled job
+/// ### Examples:
+/// - POST /api/jobs/auto-pull  JSON ```{"name":"Auto-pull","schedule":{"type":"interval","minutes":120},"action":"pull"}```
+#[post("/jobs/<id>", format = "json", data = "<spec>")]
+pub async fn update_job(_admin: Admin, id: &str, spec: Json<JobSpec>) -> Result<Json<JobResponse>, AppError> {
+    let scheduler = get_scheduler().await;
+    if scheduler.get_job(id).await.is_none() {
+        return Err(AppError::NotFound(format!("Job '{}' not found", id)));
+    }
+
+    let job = spec.into_inner().into_job(id.to_string())?;
+    let job = scheduler.upsert_job(job).await
+        .map_err(|e| AppError::Internal(format!("Failed to update job: {}", e)))?;
+
+    Ok(Json(JobResponse {
+        success: true,
+        message: format!("Job '{}' updated successfully", job.name),
+        job: Some(job_info(&job.id).await?),
+    }))
+}
+
+/// Delete a scheduled job
+/// ### Examples:
+/// - DELETE /api/jobs/auto-pull
+#[delete("/jobs/<id>")]
+pub async fn delete_job(_admin: Admin, id: &str) -> Result<Json<JobResponse>, AppError> {
+    let deleted = get_scheduler().await.delete_job(id).await
+        .map_err(|e| AppError::Internal(format!("Failed to delete job: {}", e)))?;
+    if !deleted {
+        return Err(AppError::NotFound(format!("Job '{}' not found", id)));
+    }
+
+    Ok(Json(JobResponse {
+        success: true,
+        message: format!("Job '{}' deleted successfully", id),
+        job: None,
+    }))
+}
+
+/// Resume a job paused after too many consecutive failures
+/// ### Examples:
+/// - POST /api/jobs/auto-pull/resume
+#[post("/jobs/<id>/resume")]
+pub async fn resume_job(_admin: Admin, id: &str) -> Result<Json<JobResponse>, AppError> {
+    let resumed = get_scheduler().await.resume_job(id).await
+        .map_err(|e| AppError::Internal(format!("Failed to resume job: {}", e)))?;
+    if !resumed {
+        return Err(AppError::NotFound(format!("Job '{}' not found", id)));
+    }
+
+    Ok(Json(JobResponse {
+        success: true,
+        message: format!("Job '{}' resumed", id),
+        job: Some(job_info(id).await?),
+    }))
+}
+
+/// Run a scheduled job right now, outside its schedule
+/// ### Examples:
+/// - POST /api/jobs/auto-pull/run
+#[post("/jobs/<id>/run")]
+pub async fn run_job(_admin: Admin, id: &str) -> Result<Json<JobResponse>, AppError> {
+    let outcome = get_scheduler().await.run_job_now(id).await
+        .ok_or_else(|| AppError::NotFound(format!("Job '{}' not found", id)))?;
+    let (success, message) = match outcome {
+        Ok(message) => (true, message),
+        Err(message) => (false, message),
+    };
+
+    Ok(Json(JobResponse {
+        success,
+        message,
+        job: Some(job_info(id).await?),
+    }))
+}
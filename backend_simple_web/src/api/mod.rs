@@ -6,6 +6,10 @@ pub mod upload;
 pub mod move_ops;
 pub mod git;
 pub mod themes;
+pub mod jobs;
+pub mod publications;
+pub mod backups;
+pub mod repo_lock;
 
 pub const ROOT: &str = "/public_site";
 
@@ -22,4 +26,25 @@ pub use files::{list_files, get_file, save_file, delete_file};
 pub use upload::upload as upload_files;
 pub use move_ops::move_entry;
 pub use git::{setup_git_repo, pull_repo, test_git_repo, get_auto_pull_config, set_auto_pull_config, get_git_status, commit_changes, push_repo, force_pull_repo};
-pub use themes::{list_themes, get_theme, save_theme, delete_theme};
\ No newline at end of file
+pub use git::blame::get_blame;
+pub use git::branches::{list_branches, create_branch, checkout_branch, rename_branch, set_branch_upstream, delete_branch};
+pub use git::credentials::{delete_credentials, list_credentials, save_credentials};
+pub use git::diff::get_diff;
+pub use git::history::get_log;
+pub use git::merge::{abort_merge, continue_merge, get_merge_status, resolve_conflict};
+pub use git::remotes::{add_remote, delete_remote, list_remotes, update_remote};
+pub use git::identity::{delete_author, get_identity, save_author, set_committer};
+pub use git::revert::{reset_branch, restore_path, revert_commit};
+pub use git::ssh::{
+    add_known_host, delete_ssh_key, generate_ssh_key, get_ssh_config, import_ssh_key, remove_known_host,
+    scan_known_host,
+};
+pub use git::staging::{stage, stage_hunk, unstage, unstage_hunk};
+pub use git::stash::{apply_stash, drop_stash, get_stash_diff, list_stashes, pop_stash, save_stash};
+pub use git::tags::{create_tag, delete_tag, list_tags, push_tags};
+pub use git::tasks::{cancel_task, get_task, list_tasks, start_task};
+pub use git::webhooks::{delete_webhook, list_deliveries, list_webhooks, receive_webhook, save_webhook};
+pub use themes::{list_themes, get_theme, save_theme, delete_theme};
+pub use jobs::{list_jobs, get_job_history, get_job, create_job, update_job, delete_job, run_job, resume_job};
+pub use publications::{list_publications, create_publication, cancel_publication};
+pub use backups::{list_backups, create_backup, download_backup, restore_backup};
\ No newline at end of file
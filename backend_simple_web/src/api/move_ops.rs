@@ -4,6 +4,7 @@ use rocket::serde::{json::Json, Deserialize};
 use rocket::tokio::fs;
 
 use prisma_auth::backend::AuthGuard as Admin;
+use super::repo_lock::RepoWrite;
 use super::error::AppError;
 use super::path::ValidatedPath;
 
@@ -21,7 +22,7 @@ pub struct FileMove {
 /// ### Examples:
 /// - POST /api/move  JSON ```{"from":"old.html","to":"new.html"}```
 #[post("/move", data = "<payload>")]
-pub async fn move_entry(payload: Json<FileMove>, _admin: Admin) -> Result<Status, AppError> {
+pub async fn move_entry(payload: Json<FileMove>, _admin: Admin, _lock: RepoWrite) -> Result<Status, AppError> {
     let src = ValidatedPath::existing(&payload.from).await?;
     let dst = ValidatedPath::new_destination(&payload.to)?;
 
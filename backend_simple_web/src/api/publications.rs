@@ -0,0 +1,156 @@
+// backend_simple_web/src/api/publications.rs
+use rocket::serde::{json::Json, Deserialize, Serialize};
+
+use prisma_auth::backend::AuthGuard as Admin;
+use crate::publish::{discard_staged, get_publisher, is_publishing, stage_file, Publication, PublicationStatus, PublishSource};
+use super::error::AppError;
+use super::path::ValidatedPath;
+use super::clean;
+use crate::storage;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct StagedFile {
+    path: String,
+    content: String,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde", tag = "type", rename_all = "snake_case")]
+pub enum PublishRequestSource {
+    Files { files: Vec<StagedFile> },
+    GitRef { reference: String },
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PublishRequest {
+    name: String,
+    /// Unix timestamp (seconds)
+    publish_at: u64,
+    source: PublishRequestSource,
+    #[serde(default)]
+    commit: bool,
+    #[serde(default)]
+    push: bool,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PublicationListResponse {
+    pub publications: Vec<Publication>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct PublicationResponse {
+    pub success: bool,
+    pub message: String,
+    pub publication: Option<Publication>,
+}
+
+/// List pending and recently finished publications, soonest first
+/// ### Examples:
+/// - GET /api/publications
+#[get("/publications")]
+pub async fn list_publications(_admin: Admin) -> Json<PublicationListResponse> {
+    let publications = get_publisher().await.list().await;
+    Json(PublicationListResponse { publications })
+}
+
+/// Schedule staged files or a git ref to go live at `publish_at`
+/// ### Examples:
+/// - POST /api/publications  JSON ```{"name":"Launch","publish_at":1767254400,"source":{"type":"files","files":[{"path":"index.html","content":"<h1>Live</h1>"}]},"commit":true,"push":true}```
+/// - POST /api/publications  JSON ```{"name":"Launch","publish_at":1767254400,"source":{"type":"git_ref","reference":"origin/launch"}}```
+#[post("/publications", format = "json", data = "<request>")]
+pub async fn create_publication(_admin: Admin, request: Json<PublishRequest>) -> Result<Json<PublicationResponse>, AppError> {
+    let request = request.into_inner();
+    let name = request.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::BadRequest("Publication name cannot be empty".into()));
+    }
+    let now = storage::now_secs();
+    if request.publish_at <= now {
+        return Err(AppError::BadRequest("Publish time must be in the future".into()));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let source = match request.source {
+        PublishRequestSource::Files { files } => {
+            if files.is_empty() {
+                return Err(AppError::BadRequest("No files to publish".into()));
+            }
+            let mut paths = Vec::with_capacity(files.len());
+            for file in &files {
+                ValidatedPath::new_destination(&file.path)?;
+                let rel = clean(&file.path);
+                if let Err(e) = stage_file(&id, &rel, file.content.as_bytes()).await {
+                    discard_staged(&id).await;
+                    return Err(AppError::Internal(format!("Failed to stage {}: {}", rel, e)));
+                }
+                paths.push(rel);
+            }
+            PublishSource::Files { paths }
+        }
+        PublishRequestSource::GitRef { reference } => {
+            let reference = reference.trim().to_string();
+            if reference.is_empty() || reference.contains(char::is_whitespace) {
+                return Err(AppError::BadRequest("Invalid git ref".into()));
+            }
+            PublishSource::GitRef { reference }
+        }
+    };
+
+    let publication = Publication {
+        id: id.clone(),
+        name,
+        publish_at: request.publish_at,
+        source,
+        commit: request.commit,
+        push: request.push,
+        status: PublicationStatus::Pending,
+        message: None,
+        created_at: now,
+        finished_at: None,
+    };
+    let added = get_publisher().await.add(publication).await.map_err(|e| e.to_string());
+    let publication = match added {
+        Ok(publication) => publication,
+        Err(e) => {
+            discard_staged(&id).await;
+            return Err(AppError::Internal(format!("Failed to schedule publication: {}", e)));
+        }
+    };
+
+    Ok(Json(PublicationResponse {
+        success: true,
+        message: format!("Publication '{}' scheduled", publication.name),
+        publication: Some(publication),
+    }))
+}
+
+/// Cancel a pending publication
+/// ### Examples:
+/// - DELETE /api/publications/0b7c9c3e-4a52-4f0e-9a53-2f1d7f0c1e7a
+#[delete("/publications/<id>")]
+pub async fn cancel_publication(_admin: Admin, id: &str) -> Result<Json<PublicationResponse>, AppError> {
+    let publisher = get_publisher().await;
+    let existing = publisher.get(id).await
+        .ok_or_else(|| AppError::NotFound(format!("Publication '{}' not found", id)))?;
+    if existing.status != PublicationStatus::Pending {
+        return Err(AppError::BadRequest(format!("Publication '{}' is no longer pending", existing.name)));
+    }
+    if is_publishing(id) {
+        return Err(AppError::BadRequest(format!("Publication '{}' is being published", existing.name)));
+    }
+
+    let publication = publisher.cancel(id).await
+        .map_err(AppError::Internal)?
+        .ok_or_else(|| AppError::NotFound(format!("Publication '{}' not found", id)))?;
+
+    Ok(Json(PublicationResponse {
+        success: true,
+        message: format!("Publication '{}' cancelled", publication.name),
+        publication: Some(publication),
+    }))
+}
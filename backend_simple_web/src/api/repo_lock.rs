@@ -0,0 +1,178 @@
+// backend_simple_web/src/api/repo_lock.rs
+//
+// One lock over ROOT for git operations and file writes, so an auto-pull
+// can't reset the tree under a save or two commits race for the index.
+// Reads share it; anything that changes the index, HEAD, the repository
+// config or the files holds it alone. Waiting for it gives up after
+// REPO_LOCK_TIMEOUT_SECS with a "repository busy" error naming the holder.
+use rocket::http::Status;
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket::serde::{json::Json, Serialize};
+use rocket::tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use rocket::tokio::time::timeout;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use super::error::AppError;
+use crate::storage::now_secs;
+
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+static LOCK: RwLock<()> = RwLock::const_new(());
+static HOLDERS: Mutex<Vec<(u64, LockHolder)>> = Mutex::new(Vec::new());
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Serialize, Clone)]
+#[serde(crate = "rocket::serde")]
+pub struct LockHolder {
+    /// What holds the lock, e.g. `POST /api/git/pull` or `automatic pull`
+    pub operation: String,
+    /// Held for writing rather than shared with other readers
+    pub exclusive: bool,
+    /// Unix timestamp (seconds) it was acquired at
+    pub since: u64,
+}
+
+enum Held {
+    Read { _guard: RwLockReadGuard<'static, ()> },
+    Write { _guard: RwLockWriteGuard<'static, ()> },
+}
+
+/// Holds the repository lock until dropped
+pub struct RepoGuard {
+    id: u64,
+    _held: Held,
+}
+
+/// Holds the repository lock alone until dropped. Functions that change the
+/// repository without locking it themselves take a reference to one.
+pub struct RepoWriteGuard(RepoGuard);
+
+impl From<RepoWriteGuard> for RepoGuard {
+    fn from(guard: RepoWriteGuard) -> Self {
+        guard.0
+    }
+}
+
+impl Drop for RepoGuard {
+    fn drop(&mut self) {
+        holders_list().retain(|(id, _)| *id != self.id);
+    }
+}
+
+fn holders_list() -> std::sync::MutexGuard<'static, Vec<(u64, LockHolder)>> {
+    HOLDERS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn wait_limit() -> Duration {
+    let secs = std::env::var("REPO_LOCK_TIMEOUT_SECS").ok()
+        .and_then(|v| v.trim().parse().ok())
+        .unwrap_or(DEFAULT_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
+/// Who holds the lock right now, longest-held first
+pub fn holders() -> Vec<LockHolder> {
+    holders_list().iter().map(|(_, holder)| holder.clone()).collect()
+}
+
+fn register(operation: String, exclusive: bool, held: Held) -> RepoGuard {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    holders_list().push((id, LockHolder { operation, exclusive, since: now_secs() }));
+    RepoGuard { id, _held: held }
+}
+
+fn busy(operation: &str) -> AppError {
+    let now = now_secs();
+    let held_by: Vec<String> = holders().iter()
+        .map(|holder| format!("{} (for {}s)", holder.operation, now.saturating_sub(holder.since)))
+        .collect();
+    warn!("{} gave up waiting for the repository lock held by {:?}", operation, held_by);
+    if held_by.is_empty() {
+        AppError::Busy("try again shortly".into())
+    } else {
+        AppError::Busy(format!("held by {}; try again shortly", held_by.join(", ")))
+    }
+}
+
+/// Share the lock with other readers, for operations that only look at the
+/// repository.
+pub async fn read(operation: impl Into<String>) -> Result<RepoGuard, AppError> {
+    let operation = operation.into();
+    match timeout(wait_limit(), LOCK.read()).await {
+        Ok(guard) => Ok(register(operation, false, Held::Read { _guard: guard })),
+        Err(_) => Err(busy(&operation)),
+    }
+}
+
+/// Hold the lock alone, for operations that change the repository or the
+/// site files.
+pub async fn write(operation: impl Into<String>) -> Result<RepoWriteGuard, AppError> {
+    let operation = operation.into();
+    match timeout(wait_limit(), LOCK.write()).await {
+        Ok(guard) => Ok(RepoWriteGuard(register(operation, true, Held::Write { _guard: guard }))),
+        Err(_) => Err(busy(&operation)),
+    }
+}
+
+/// Message of the busy error, for the catcher to answer with
+struct BusyMessage(Option<String>);
+
+fn request_label(req: &Request<'_>) -> String {
+    format!("{} {}", req.method(), req.uri().path())
+}
+
+fn guard_outcome<G, T>(req: &Request<'_>, result: Result<G, AppError>, wrap: fn(G) -> T) -> Outcome<T, AppError> {
+    match result {
+        Ok(guard) => Outcome::Success(wrap(guard)),
+        Err(e) => {
+            req.local_cache(|| BusyMessage(Some(e.to_string())));
+            Outcome::Error((Status::ServiceUnavailable, e))
+        }
+    }
+}
+
+/// Request guard sharing the lock for the whole request
+pub struct RepoRead {
+    _guard: RepoGuard,
+}
+
+/// Request guard holding the lock alone for the whole request
+pub struct RepoWrite {
+    _guard: RepoWriteGuard,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RepoRead {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        guard_outcome(req, read(request_label(req)).await, |_guard| RepoRead { _guard })
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RepoWrite {
+    type Error = AppError;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        guard_outcome(req, write(request_label(req)).await, |_guard| RepoWrite { _guard })
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct BusyBody {
+    success: bool,
+    message: String,
+}
+
+/// Answer requests whose lock guard gave up with the reason, in the shape of
+/// the other API errors.
+#[catch(503)]
+pub fn repository_busy(req: &Request<'_>) -> Json<BusyBody> {
+    let message = req.local_cache(|| BusyMessage(None)).0.clone()
+        .unwrap_or_else(|| "Service unavailable".to_string());
+    Json(BusyBody { success: false, message })
+}
@@ -5,6 +5,7 @@ use rocket::http::Status;
 use rocket::tokio::fs;
 
 use prisma_auth::backend::AuthGuard as Admin;
+use super::repo_lock;
 use super::error::AppError;
 use super::path::ValidatedPath;
 use super::clean;
@@ -26,6 +27,8 @@ pub struct Upload<'r> {
 /// - POST /api/upload  JSON ```{"files":[],"base_path":"img/logo.png"}```
 #[post("/upload", data = "<payload>")]
 pub async fn upload(mut payload: Form<Upload<'_>>, _admin: Admin) -> Result<Status, AppError> {
+    // Locked only once the files are in, so a slow client doesn't hold up git
+    let _lock = repo_lock::write("POST /api/upload").await?;
     // Validate base path if provided
     let raw_base = payload.base_path.take().unwrap_or_default();
     let base = clean(&raw_base);
@@ -0,0 +1,457 @@
+// backend_simple_web/src/backup.rs
+//
+// Compressed snapshots of the public site and the data directory, with
+// retention and restore.
+
+use chrono::{DateTime, Datelike};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::api::error::AppError;
+use crate::api::{repo_lock, ROOT};
+use crate::{scheduler, storage};
+
+const DATA_DIR: &str = "/app/data";
+const DEFAULT_BACKUP_DIR: &str = "/app/data/backups";
+const DEFAULT_KEEP_DAILY: usize = 7;
+const DEFAULT_KEEP_WEEKLY: usize = 4;
+/// Secrets in the data directory that backups leave out: the SSH key, the git
+/// credential store and the webhook secrets. A downloaded backup never holds them.
+const SECRET_DATA: &[&str] = &["ssh", "git_credentials.enc.json", "git_credentials.json", "git_webhooks.json"];
+
+/// Where snapshots are written; set with `BACKUP_DIR`.
+pub fn backup_dir() -> PathBuf {
+    std::env::var("BACKUP_DIR")
+        .ok()
+        .filter(|dir| !dir.trim().is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_BACKUP_DIR))
+}
+
+fn keep_count(var: &str, default: usize) -> usize {
+    std::env::var(var).ok().and_then(|v| v.trim().parse().ok()).unwrap_or(default)
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BackupInfo {
+    /// File name inside the backup directory, used as the backup id
+    pub name: String,
+    /// Unix timestamp (seconds)
+    pub created_at: u64,
+    /// Size in bytes
+    pub size: u64,
+}
+
+/// What a restore changes, as paths prefixed with `site/` or `data/`.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RestorePlan {
+    pub added: Vec<String>,
+    pub modified: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
+impl RestorePlan {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+
+    /// Files of the data directory are written
+    pub fn touches_data(&self) -> bool {
+        self.added.iter().chain(&self.modified).any(|entry| entry.starts_with("data/"))
+    }
+}
+
+/// Name of the `seq`th backup taken in the second `timestamp`
+fn backup_name(timestamp: u64, seq: u32) -> String {
+    match seq {
+        0 => format!("backup-{}.tar.gz", timestamp),
+        seq => format!("backup-{}-{}.tar.gz", timestamp, seq),
+    }
+}
+
+/// Creation time and sequence number encoded in a backup file name, `None`
+/// for anything else.
+fn parse_backup_name(name: &str) -> Option<(u64, u32)> {
+    let stem = name.strip_prefix("backup-")?.strip_suffix(".tar.gz")?;
+    match stem.split_once('-') {
+        Some((timestamp, seq)) => Some((timestamp.parse().ok()?, seq.parse().ok().filter(|seq| *seq > 0)?)),
+        None => Some((stem.parse().ok()?, 0)),
+    }
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> io::Result<T> + Send + 'static) -> io::Result<T> {
+    rocket::tokio::task::spawn_blocking(f).await.map_err(io::Error::other)?
+}
+
+/// Write a `.tar.gz` snapshot of ROOT (without `.git`) and the data directory
+/// (without the backups and secrets), prune old snapshots and return the new
+/// one's path. Shares the repository lock, so no write lands mid-snapshot.
+pub async fn create_snapshot() -> Result<PathBuf, AppError> {
+    let _lock = repo_lock::read("backup").await?;
+    let path = blocking(|| {
+        let dir = backup_dir();
+        let path = write_snapshot(&dir)?;
+        if let Err(e) = apply_retention(&dir) {
+            warn!("Failed to prune old backups: {}", e);
+        }
+        Ok(path)
+    }).await?;
+    Ok(path)
+}
+
+fn write_snapshot(backup_dir: &Path) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(backup_dir)?;
+
+    let timestamp = storage::now_secs();
+    // Written under a temp name so a half-written archive never shows up in the list
+    let tmp_path = backup_dir.join(format!(".backup-{}.{}.tmp", timestamp, uuid::Uuid::new_v4()));
+
+    let encoder = GzEncoder::new(File::create(&tmp_path)?, Compression::default());
+    let mut archive = tar::Builder::new(encoder);
+    append_dir(&mut archive, Path::new(ROOT), Path::new("site"), backup_dir)?;
+    if Path::new(DATA_DIR).is_dir() {
+        append_dir(&mut archive, Path::new(DATA_DIR), Path::new("data"), backup_dir)?;
+    }
+    archive.into_inner()?.finish()?.sync_all()?;
+    let path = claim_name(backup_dir, &tmp_path, timestamp)?;
+
+    info!("Backup written to {}", path.display());
+    Ok(path)
+}
+
+/// Give the finished archive the first free name for `timestamp`. Linking
+/// fails instead of replacing an existing file, so a backup taken in the same
+/// second, e.g. the one before a restore, never overwrites another.
+fn claim_name(backup_dir: &Path, tmp_path: &Path, timestamp: u64) -> io::Result<PathBuf> {
+    let mut seq = 0;
+    loop {
+        let path = backup_dir.join(backup_name(timestamp, seq));
+        match std::fs::hard_link(tmp_path, &path) {
+            Ok(()) => {
+                std::fs::remove_file(tmp_path)?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => seq += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Left out of the archive: `.git`, the backups themselves and the secrets of
+/// the data directory
+fn excluded(path: &Path, backup_dir: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == ".git")
+        || path == backup_dir
+        || SECRET_DATA.iter().any(|name| path == Path::new(DATA_DIR).join(name))
+}
+
+fn append_dir<W: io::Write>(archive: &mut tar::Builder<W>, dir: &Path, name: &Path, backup_dir: &Path) -> io::Result<()> {
+    archive.append_dir(name, dir)?;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if excluded(&path, backup_dir) {
+            continue;
+        }
+        let entry_name = name.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            append_dir(archive, &path, &entry_name, backup_dir)?;
+        } else {
+            archive.append_path_with_name(&path, &entry_name)?;
+        }
+    }
+    Ok(())
+}
+
+fn read_backups(backup_dir: &Path) -> io::Result<Vec<BackupInfo>> {
+    let mut backups = Vec::new();
+    let entries = match std::fs::read_dir(backup_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if let Some((created_at, seq)) = parse_backup_name(&name) {
+            backups.push((seq, BackupInfo { name, created_at, size: entry.metadata()?.len() }));
+        }
+    }
+    backups.sort_by_key(|(seq, b)| std::cmp::Reverse((b.created_at, *seq)));
+    Ok(backups.into_iter().map(|(_, b)| b).collect())
+}
+
+/// All backups, newest first.
+pub async fn list_backups() -> io::Result<Vec<BackupInfo>> {
+    blocking(|| read_backups(&backup_dir())).await
+}
+
+/// Path of an existing backup, or `None` if the name is not a backup.
+pub fn backup_path(name: &str) -> Option<PathBuf> {
+    parse_backup_name(name)?;
+    let path = backup_dir().join(name);
+    path.is_file().then_some(path)
+}
+
+/// Names of backups outside the retention policy: the newest backup is always
+/// kept, plus the newest of each of the last `keep_daily` days and `keep_weekly`
+/// ISO weeks that have backups.
+fn expired_backups(backups: &[BackupInfo], keep_daily: usize, keep_weekly: usize) -> Vec<String> {
+    let mut days = HashSet::new();
+    let mut weeks = HashSet::new();
+    let mut expired = Vec::new();
+
+    // `backups` is sorted newest first, so the first one seen per day/week is kept
+    for (i, backup) in backups.iter().enumerate() {
+        let time = DateTime::from_timestamp(backup.created_at as i64, 0).unwrap_or_default();
+        let day = time.date_naive();
+        let week = (time.iso_week().year(), time.iso_week().week());
+
+        let mut keep = i == 0;
+        if !days.contains(&day) && days.len() < keep_daily {
+            days.insert(day);
+            keep = true;
+        }
+        if !weeks.contains(&week) && weeks.len() < keep_weekly {
+            weeks.insert(week);
+            keep = true;
+        }
+        if !keep {
+            expired.push(backup.name.clone());
+        }
+    }
+    expired
+}
+
+fn apply_retention(backup_dir: &Path) -> io::Result<()> {
+    let backups = read_backups(backup_dir)?;
+    let keep_daily = keep_count("BACKUP_KEEP_DAILY", DEFAULT_KEEP_DAILY);
+    let keep_weekly = keep_count("BACKUP_KEEP_WEEKLY", DEFAULT_KEEP_WEEKLY);
+    for name in expired_backups(&backups, keep_daily, keep_weekly) {
+        std::fs::remove_file(backup_dir.join(&name))?;
+        info!("Removed old backup {}", name);
+    }
+    Ok(())
+}
+
+/// Every file under `dir` keyed by its path relative to `dir`, skipping `.git`
+/// and the backup directory.
+fn collect_files(dir: &Path, rel: &Path, skip: &Path, out: &mut BTreeMap<PathBuf, PathBuf>) -> io::Result<()> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    for entry in entries {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_name() == ".git" || path == skip {
+            continue;
+        }
+        let entry_rel = rel.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(&path, &entry_rel, skip, out)?;
+        } else {
+            out.insert(entry_rel, path);
+        }
+    }
+    Ok(())
+}
+
+/// Compare a snapshot directory with the live one. Extra live files are only
+/// reported as deleted when `delete_extra` is set.
+fn diff_dirs(
+    snapshot: &Path,
+    live: &Path,
+    skip: &Path,
+    delete_extra: bool,
+    prefix: &str,
+    plan: &mut RestorePlan,
+) -> io::Result<()> {
+    let mut snapshot_files = BTreeMap::new();
+    collect_files(snapshot, Path::new(""), skip, &mut snapshot_files)?;
+    let mut live_files = BTreeMap::new();
+    collect_files(live, Path::new(""), skip, &mut live_files)?;
+
+    let label = |rel: &Path| format!("{}/{}", prefix, rel.to_string_lossy());
+    for (rel, snapshot_path) in &snapshot_files {
+        match live_files.get(rel) {
+            None => plan.added.push(label(rel)),
+            Some(live_path) => {
+                if std::fs::read(snapshot_path)? != std::fs::read(live_path)? {
+                    plan.modified.push(label(rel));
+                }
+            }
+        }
+    }
+    if delete_extra {
+        for rel in live_files.keys().filter(|rel| !snapshot_files.contains_key(*rel)) {
+            plan.deleted.push(label(rel));
+        }
+    }
+    Ok(())
+}
+
+/// Map a `site/…` or `data/…` plan entry to its live path and snapshot path.
+fn resolve_entry(entry: &str, extracted: &Path) -> Option<(PathBuf, PathBuf)> {
+    let (prefix, rel) = entry.split_once('/')?;
+    let live_root = match prefix {
+        "site" => Path::new(ROOT),
+        "data" => Path::new(DATA_DIR),
+        _ => return None,
+    };
+    Some((live_root.join(rel), extracted.join(prefix).join(rel)))
+}
+
+fn restore_blocking(name: &str, include_data: bool, dry_run: bool) -> io::Result<RestorePlan> {
+    let backup_dir = backup_dir();
+    let path = backup_path(name)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("Backup '{}' not found", name)))?;
+
+    let extracted = tempfile::tempdir()?;
+    // `unpack` refuses entries that would land outside the target directory
+    tar::Archive::new(GzDecoder::new(File::open(&path)?)).unpack(extracted.path())?;
+
+    let mut plan = RestorePlan::default();
+    diff_dirs(&extracted.path().join("site"), Path::new(ROOT), &backup_dir, true, "site", &mut plan)?;
+    if include_data {
+        // Data files are never deleted: newer state (e.g. new themes) is kept
+        diff_dirs(&extracted.path().join("data"), Path::new(DATA_DIR), &backup_dir, false, "data", &mut plan)?;
+    }
+    if dry_run || plan.is_empty() {
+        return Ok(plan);
+    }
+
+    // Keep the current state restorable too
+    write_snapshot(&backup_dir)?;
+    if plan.touches_data() {
+        storage::mark_data_restored();
+    }
+
+    for entry in plan.added.iter().chain(&plan.modified) {
+        let Some((live, snapshot)) = resolve_entry(entry, extracted.path()) else { continue };
+        if let Some(parent) = live.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&snapshot, &live)?;
+    }
+    for entry in &plan.deleted {
+        let Some((live, _)) = resolve_entry(entry, extracted.path()) else { continue };
+        std::fs::remove_file(&live)?;
+    }
+
+    info!("Restored backup {}: {} added, {} modified, {} deleted",
+          name, plan.added.len(), plan.modified.len(), plan.deleted.len());
+    Ok(plan)
+}
+
+/// Restore the site (and optionally the data directory) from a backup. With
+/// `dry_run` nothing is touched and the plan shows what would change. Before a
+/// real restore a snapshot of the current state is taken. Once data files are
+/// restored, scheduled jobs and publications stop and their state is no longer
+/// saved, so nothing overwrites the restored settings before the restart.
+pub async fn restore(name: &str, include_data: bool, dry_run: bool) -> io::Result<RestorePlan> {
+    let name = name.to_string();
+    let plan = blocking(move || restore_blocking(&name, include_data, dry_run)).await?;
+    if !dry_run && plan.touches_data() {
+        scheduler::get_scheduler().await.stop().await;
+    }
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    /// Backup taken at noon UTC on the given day
+    fn backup_on(year: i32, month: u32, day: u32) -> BackupInfo {
+        let created_at = NaiveDate::from_ymd_opt(year, month, day).unwrap()
+            .and_hms_opt(12, 0, 0).unwrap()
+            .and_utc().timestamp() as u64;
+        BackupInfo { name: backup_name(created_at, 0), created_at, size: 0 }
+    }
+
+    fn names(backups: &[&BackupInfo]) -> Vec<String> {
+        backups.iter().map(|backup| backup.name.clone()).collect()
+    }
+
+    #[test]
+    fn secrets_are_left_out() {
+        let backup_dir = Path::new(DEFAULT_BACKUP_DIR);
+        for secret in ["ssh", "git_credentials.enc.json", "git_webhooks.json"] {
+            assert!(excluded(&Path::new(DATA_DIR).join(secret), backup_dir), "{}", secret);
+        }
+        assert!(excluded(backup_dir, backup_dir));
+        assert!(excluded(&Path::new(ROOT).join(".git"), backup_dir));
+        assert!(!excluded(&Path::new(DATA_DIR).join("themes"), backup_dir));
+        // Only the data directory's own entries are secrets
+        assert!(!excluded(&Path::new(ROOT).join("ssh"), backup_dir));
+    }
+
+    #[test]
+    fn newest_backup_is_always_kept() {
+        let backups = [backup_on(2025, 3, 2), backup_on(2025, 3, 1)];
+        assert_eq!(expired_backups(&backups, 0, 0), names(&[&backups[1]]));
+    }
+
+    #[test]
+    fn keeps_newest_backup_of_each_recent_day() {
+        let mut second_of_day = backup_on(2025, 3, 3);
+        second_of_day.created_at -= 3600;
+        second_of_day.name = backup_name(second_of_day.created_at, 0);
+        let backups = [
+            backup_on(2025, 3, 3),
+            second_of_day,
+            backup_on(2025, 3, 2),
+            backup_on(2025, 3, 1),
+        ];
+        assert_eq!(expired_backups(&backups, 2, 0), names(&[&backups[1], &backups[3]]));
+    }
+
+    #[test]
+    fn weeks_follow_iso_week_boundaries() {
+        // Monday 2024-12-30 is in ISO week 2025-W01; the Sunday before is in 2024-W52
+        let backups = [
+            backup_on(2025, 1, 5),
+            backup_on(2024, 12, 30),
+            backup_on(2024, 12, 29),
+            backup_on(2024, 12, 28),
+            backup_on(2024, 12, 22),
+        ];
+        assert_eq!(expired_backups(&backups, 0, 2), names(&[&backups[1], &backups[3], &backups[4]]));
+    }
+
+    #[test]
+    fn week_spanning_new_year_counts_once() {
+        // Friday 2027-01-01 still belongs to ISO week 2026-W53, like Monday 2026-12-28
+        let backups = [backup_on(2027, 1, 1), backup_on(2026, 12, 28), backup_on(2026, 12, 27)];
+        assert_eq!(expired_backups(&backups, 0, 2), names(&[&backups[1]]));
+    }
+
+    #[test]
+    fn daily_and_weekly_rules_combine() {
+        let backups = [
+            backup_on(2025, 3, 12),
+            backup_on(2025, 3, 11),
+            backup_on(2025, 3, 10),
+            backup_on(2025, 3, 5),
+            backup_on(2025, 3, 4),
+            backup_on(2025, 2, 26),
+        ];
+        // Two days (12th and 11th), plus the newest of three weeks (12th, 5th, 26th)
+        assert_eq!(expired_backups(&backups, 2, 3), names(&[&backups[2], &backups[4]]));
+    }
+
+    #[test]
+    fn backup_names_round_trip() {
+        assert_eq!(parse_backup_name(&backup_name(1767254400, 0)), Some((1767254400, 0)));
+        assert_eq!(parse_backup_name(&backup_name(1767254400, 2)), Some((1767254400, 2)));
+        assert_eq!(parse_backup_name("backup-1767254400-0.tar.gz"), None);
+        assert_eq!(parse_backup_name("backup-latest.tar.gz"), None);
+    }
+}
@@ -0,0 +1,91 @@
+// backend_simple_web/src/job_history.rs
+//
+// Bounded record of scheduled job runs, persisted in the data directory.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::Path;
+
+use crate::scheduler::JobAction;
+use crate::storage;
+
+const HISTORY_PATH: &str = "/app/data/job_history.json";
+/// Older runs are dropped once the history grows beyond this
+pub const MAX_RUNS: usize = 500;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct JobRun {
+    pub job_id: String,
+    pub job_name: String,
+    pub action: JobAction,
+    /// Unix timestamps (seconds)
+    pub started_at: u64,
+    pub finished_at: u64,
+    pub success: bool,
+    pub message: String,
+    /// Including retries
+    #[serde(default = "default_attempts")]
+    pub attempts: u32,
+    /// HEAD of the site repository before and after the run
+    pub commit_before: Option<String>,
+    pub commit_after: Option<String>,
+}
+
+fn default_attempts() -> u32 {
+    1
+}
+
+/// Outcome of a job's most recent run.
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct RunSummary {
+    pub last_success: Option<bool>,
+    pub last_message: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+pub struct JobHistory {
+    /// Oldest first
+    runs: VecDeque<JobRun>,
+}
+
+impl JobHistory {
+    pub async fn load() -> Self {
+        match storage::load_json(Path::new(HISTORY_PATH)).await {
+            Ok(history) => history.unwrap_or_default(),
+            Err(e) => {
+                error!("Failed to load job history, starting empty: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Append a run, drop the oldest ones beyond `MAX_RUNS` and persist.
+    pub async fn record(&mut self, run: JobRun) {
+        self.runs.push_back(run);
+        while self.runs.len() > MAX_RUNS {
+            self.runs.pop_front();
+        }
+        if let Err(e) = storage::save_memory_state(Path::new(HISTORY_PATH), self).await {
+            error!("Failed to persist job history: {}", e);
+        }
+    }
+
+    /// Most recent runs first, optionally only those of one job.
+    pub fn recent(&self, job_id: Option<&str>, limit: usize) -> Vec<JobRun> {
+        self.runs.iter().rev()
+            .filter(|run| job_id.is_none_or(|id| run.job_id == id))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+
+    pub fn summary(&self, job_id: &str) -> RunSummary {
+        match self.runs.iter().rev().find(|run| run.job_id == job_id) {
+            Some(last) => RunSummary {
+                last_success: Some(last.success),
+                last_message: Some(last.message.clone()),
+            },
+            None => RunSummary::default(),
+        }
+    }
+}
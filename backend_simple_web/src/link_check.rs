@@ -0,0 +1,131 @@
+// backend_simple_web/src/link_check.rs
+//
+// Finds internal links in the site's HTML files that point at missing files.
+
+use std::io;
+use std::path::{Component, Path, PathBuf};
+
+use crate::api::ROOT;
+
+pub struct BrokenLink {
+    /// HTML file containing the link, relative to ROOT
+    pub file: String,
+    /// The link target as written in the file
+    pub target: String,
+}
+
+/// Check every HTML file under ROOT. Runs on a blocking thread.
+pub async fn check_site() -> io::Result<Vec<BrokenLink>> {
+    rocket::tokio::task::spawn_blocking(|| check_links(Path::new(ROOT)))
+        .await
+        .map_err(io::Error::other)?
+}
+
+fn check_links(root: &Path) -> io::Result<Vec<BrokenLink>> {
+    let mut html_files = Vec::new();
+    collect_html_files(root, &mut html_files)?;
+
+    let mut broken = Vec::new();
+    for file in html_files {
+        let Ok(content) = std::fs::read_to_string(&file) else { continue };
+        let dir = file.parent().unwrap_or(root);
+        for target in extract_links(&content) {
+            if !is_internal(&target) {
+                continue;
+            }
+            if !link_exists(root, dir, &target) {
+                broken.push(BrokenLink {
+                    file: file.strip_prefix(root).unwrap_or(&file).to_string_lossy().into_owned(),
+                    target,
+                });
+            }
+        }
+    }
+    Ok(broken)
+}
+
+fn collect_html_files(dir: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" {
+                collect_html_files(&path, out)?;
+            }
+        } else if matches!(path.extension().and_then(|e| e.to_str()), Some("html" | "htm")) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Values of all `href=` and `src=` attributes, quoted or not.
+fn extract_links(html: &str) -> Vec<String> {
+    // ASCII lowercasing keeps byte offsets identical to the original
+    let lower = html.to_ascii_lowercase();
+    let mut links = Vec::new();
+
+    for attr in ["href=", "src="] {
+        let mut pos = 0;
+        while let Some(found) = lower[pos..].find(attr) {
+            let start = pos + found;
+            pos = start + attr.len();
+
+            // Skip matches inside longer attribute names like `data-src=`
+            if start > 0 && !lower.as_bytes()[start - 1].is_ascii_whitespace() {
+                continue;
+            }
+
+            let rest = html[pos..].trim_start();
+            let value = match rest.chars().next() {
+                Some(quote @ ('"' | '\'')) => rest[1..].split(quote).next().unwrap_or(""),
+                _ => rest.split(|c: char| c.is_whitespace() || c == '>').next().unwrap_or(""),
+            };
+            links.push(value.to_string());
+        }
+    }
+    links
+}
+
+fn is_internal(target: &str) -> bool {
+    let target = target.trim();
+    !(target.is_empty()
+        || target.starts_with('#')
+        || target.starts_with("//")
+        || target.contains("://")
+        || ["mailto:", "tel:", "data:", "javascript:"].iter().any(|p| target.starts_with(p)))
+}
+
+fn link_exists(root: &Path, dir: &Path, target: &str) -> bool {
+    let path_part = target.trim().split(['?', '#']).next().unwrap_or("");
+    let decoded = urlencoding::decode(path_part)
+        .map(|d| d.into_owned())
+        .unwrap_or_else(|_| path_part.to_string());
+
+    let base = if decoded.starts_with('/') { root } else { dir };
+    let Some(resolved) = resolve(root, base, decoded.trim_start_matches('/')) else {
+        return false;
+    };
+
+    if resolved.is_dir() {
+        resolved.join("index.html").is_file()
+    } else {
+        resolved.exists()
+    }
+}
+
+/// Join `rel` onto `base` lexically, refusing to climb above `root`.
+fn resolve(root: &Path, base: &Path, rel: &str) -> Option<PathBuf> {
+    let mut resolved = base.to_path_buf();
+    for component in Path::new(rel).components() {
+        match component {
+            Component::Normal(part) => resolved.push(part),
+            Component::ParentDir if resolved == root => return None,
+            Component::ParentDir => {
+                resolved.pop();
+            }
+            _ => {}
+        }
+    }
+    Some(resolved)
+}
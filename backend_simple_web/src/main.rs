@@ -2,7 +2,12 @@
 #[macro_use] extern crate rocket;
 
 mod api;
+mod backup;
+mod job_history;
+mod link_check;
+mod publish;
 mod scheduler;
+mod storage;
 
 use rocket::{fs::FileServer, http::Method, serde::json::Json, State};
 use rocket_cors::{AllowedHeaders, AllowedOrigins, CorsOptions};
@@ -41,6 +46,7 @@ async fn rocket() -> _ {
 
     // Initialize the git scheduler
     scheduler::init_scheduler().await.expect("Failed to initialize git scheduler");
+    publish::init_publisher().await.expect("Failed to initialize publisher");
 
     // Initialize token store for authentication
     let token_store = prisma_auth::backend::TokenStore::new();
@@ -94,11 +100,84 @@ async fn rocket() -> _ {
             api::commit_changes,
             api::push_repo,
             api::force_pull_repo,
+            api::list_branches,
+            api::create_branch,
+            api::checkout_branch,
+            api::rename_branch,
+            api::set_branch_upstream,
+            api::delete_branch,
+            api::get_log,
+            api::get_diff,
+            api::stage,
+            api::unstage,
+            api::stage_hunk,
+            api::unstage_hunk,
+            api::get_identity,
+            api::set_committer,
+            api::save_author,
+            api::delete_author,
+            api::get_merge_status,
+            api::resolve_conflict,
+            api::continue_merge,
+            api::abort_merge,
+            api::list_stashes,
+            api::save_stash,
+            api::get_stash_diff,
+            api::apply_stash,
+            api::pop_stash,
+            api::drop_stash,
+            api::revert_commit,
+            api::reset_branch,
+            api::restore_path,
+            api::get_ssh_config,
+            api::generate_ssh_key,
+            api::import_ssh_key,
+            api::delete_ssh_key,
+            api::scan_known_host,
+            api::add_known_host,
+            api::remove_known_host,
+            api::list_credentials,
+            api::save_credentials,
+            api::delete_credentials,
+            api::list_remotes,
+            api::add_remote,
+            api::update_remote,
+            api::delete_remote,
+            api::list_tags,
+            api::create_tag,
+            api::delete_tag,
+            api::push_tags,
+            api::get_blame,
+            api::start_task,
+            api::list_tasks,
+            api::get_task,
+            api::cancel_task,
+            api::list_webhooks,
+            api::save_webhook,
+            api::delete_webhook,
+            api::list_deliveries,
+            api::receive_webhook,
             api::list_themes,
             api::get_theme,
             api::save_theme,
-            api::delete_theme
+            api::delete_theme,
+            api::list_jobs,
+            api::get_job_history,
+            api::get_job,
+            api::create_job,
+            api::update_job,
+            api::delete_job,
+            api::run_job,
+            api::resume_job,
+            api::list_publications,
+            api::create_publication,
+            api::cancel_publication,
+            api::list_backups,
+            api::create_backup,
+            api::download_backup,
+            api::restore_backup
         ])
+        .register("/api", catchers![api::repo_lock::repository_busy])
         // Anything under `public_site/` is  always available under /
         .mount("/", FileServer::from("/public_site"))
 }
\ No newline at end of file
@@ -0,0 +1,351 @@
+// backend_simple_web/src/publish.rs
+//
+// Scheduled publications: staged files or a git ref that go live at a set time.
+
+use rocket::tokio::{fs, sync::RwLock};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_cron_scheduler::{Job, JobScheduler};
+
+use crate::api::git::{commit_paths_inner, fast_forward_internal, push_repo_internal};
+use crate::api::repo_lock;
+use crate::api::ROOT;
+use crate::scheduler::git_outcome;
+use crate::storage::{self, now_secs};
+
+const STATE_PATH: &str = "/app/data/publications.json";
+/// Staged files live under `<STAGING_DIR>/<publication id>/<path>`
+const STAGING_DIR: &str = "/app/data/publications";
+/// Finished and cancelled publications kept for the list
+const MAX_FINISHED: usize = 100;
+
+/// Publications being published right now; they can no longer be cancelled
+static PUBLISHING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PublishSource {
+    /// Staged copies of these files (relative to ROOT) are moved into place
+    Files { paths: Vec<String> },
+    /// The current branch is fast-forwarded to this branch, tag or commit
+    GitRef { reference: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicationStatus {
+    Pending,
+    Published,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Publication {
+    pub id: String,
+    pub name: String,
+    /// Unix timestamp (seconds) at which the publication goes live
+    pub publish_at: u64,
+    pub source: PublishSource,
+    /// Commit the published files afterwards (staged files only)
+    pub commit: bool,
+    /// Push the branch afterwards
+    pub push: bool,
+    pub status: PublicationStatus,
+    /// Outcome of the publish, or why it was cancelled
+    pub message: Option<String>,
+    pub created_at: u64,
+    pub finished_at: Option<u64>,
+}
+
+fn staging_dir(id: &str) -> PathBuf {
+    Path::new(STAGING_DIR).join(id)
+}
+
+/// Store the staged copy of a file for a publication. `rel` must already be validated.
+pub async fn stage_file(id: &str, rel: &str, content: &[u8]) -> std::io::Result<()> {
+    storage::write_atomic(&staging_dir(id).join(rel), content).await
+}
+
+/// Drop the staged files of a publication.
+pub async fn discard_staged(id: &str) {
+    let dir = staging_dir(id);
+    match fs::remove_dir_all(&dir).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            warn!("Failed to remove staged files in {}: {}", dir.display(), e);
+        }
+        _ => {}
+    }
+}
+
+/// Copy every staged file next to its destination under a temporary name, and
+/// only once all of them are copied rename them into place, so a failed copy
+/// leaves the site as it was.
+async fn move_into_place(id: &str, staged: &Path, paths: &[String]) -> Result<(), String> {
+    let mut prepared: Vec<(PathBuf, PathBuf)> = Vec::with_capacity(paths.len());
+    for rel in paths {
+        let target = Path::new(ROOT).join(rel);
+        let file_name = target.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let tmp = target.with_file_name(format!(".{}.publish-{}.tmp", file_name, id));
+        let copied = match fs::read(staged.join(rel)).await {
+            Ok(content) => storage::write_atomic(&tmp, &content).await
+                .map_err(|e| format!("Failed to publish {}: {}", rel, e)),
+            Err(e) => Err(format!("Failed to read staged {}: {}", rel, e)),
+        };
+        if let Err(e) = copied {
+            for (tmp, _) in &prepared {
+                if let Err(e) = fs::remove_file(tmp).await {
+                    warn!("Failed to remove {}: {}", tmp.display(), e);
+                }
+            }
+            return Err(e);
+        }
+        prepared.push((tmp, target));
+    }
+    for (tmp, target) in &prepared {
+        fs::rename(tmp, target).await
+            .map_err(|e| format!("Failed to publish {}: {}", target.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Move staged files into place or fast-forward, then commit and push as requested.
+async fn publish(publication: &Publication) -> Result<String, String> {
+    let mut steps = Vec::new();
+
+    match &publication.source {
+        PublishSource::Files { paths } => {
+            let staged = staging_dir(&publication.id);
+            // Held until the commit, so no other write lands between the two
+            let lock = repo_lock::write("publish").await.map_err(|e| e.to_string())?;
+            move_into_place(&publication.id, &staged, paths).await?;
+            steps.push(format!("Published {} files", paths.len()));
+
+            if publication.commit {
+                let message = format!("Publish {}", publication.name);
+                steps.push(git_outcome(commit_paths_inner(&lock, paths, &message))?);
+            }
+        }
+        PublishSource::GitRef { reference } => {
+            steps.push(git_outcome(fast_forward_internal(reference).await)?);
+        }
+    }
+
+    if publication.push {
+        steps.push(git_outcome(push_repo_internal().await)?);
+    }
+    Ok(steps.join("; "))
+}
+
+async fn save_state(publications: &[Publication]) -> std::io::Result<()> {
+    storage::save_memory_state(Path::new(STATE_PATH), &publications).await
+}
+
+/// Keep every pending publication and the most recent finished ones.
+fn prune(publications: &mut Vec<Publication>) {
+    let finished = publications.iter().filter(|p| p.status != PublicationStatus::Pending).count();
+    let mut excess = finished.saturating_sub(MAX_FINISHED);
+    if excess == 0 {
+        return;
+    }
+    publications.sort_by_key(|p| p.finished_at.unwrap_or(u64::MAX));
+    publications.retain(|p| {
+        if excess > 0 && p.status != PublicationStatus::Pending {
+            excess -= 1;
+            return false;
+        }
+        true
+    });
+}
+
+fn publishing() -> std::sync::MutexGuard<'static, Vec<String>> {
+    PUBLISHING.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The publication has started publishing and can no longer be cancelled
+pub fn is_publishing(id: &str) -> bool {
+    publishing().iter().any(|running| running == id)
+}
+
+/// Marks a publication as being published until dropped
+struct Publishing(String);
+
+impl Drop for Publishing {
+    fn drop(&mut self) {
+        publishing().retain(|id| *id != self.0);
+    }
+}
+
+/// Run a pending publication and record its outcome.
+async fn execute(state: &RwLock<Vec<Publication>>, id: &str) {
+    // Checked and marked under the write lock, so a cancel either lands
+    // before this or sees the publication running and leaves it alone
+    let (publication, _publishing) = {
+        let publications = state.write().await;
+        let Some(publication) = publications.iter()
+            .find(|p| p.id == id && p.status == PublicationStatus::Pending)
+            .cloned()
+        else {
+            return;
+        };
+        publishing().push(id.to_string());
+        (publication, Publishing(id.to_string()))
+    };
+
+    info!("Publishing '{}'", publication.name);
+    let outcome = publish(&publication).await;
+    match &outcome {
+        Ok(message) => info!("Published '{}': {}", publication.name, message),
+        Err(message) => error!("Failed to publish '{}': {}", publication.name, message),
+    }
+
+    {
+        let mut publications = state.write().await;
+        if let Some(stored) = publications.iter_mut().find(|p| p.id == id && p.status == PublicationStatus::Pending) {
+            let (status, message) = match &outcome {
+                Ok(message) => (PublicationStatus::Published, message),
+                Err(message) => (PublicationStatus::Failed, message),
+            };
+            stored.status = status;
+            stored.message = Some(message.clone());
+            stored.finished_at = Some(now_secs());
+        }
+        prune(&mut publications);
+        if let Err(e) = save_state(&publications).await {
+            error!("Failed to persist publications: {}", e);
+        }
+    }
+
+    if outcome.is_ok() {
+        discard_staged(id).await;
+    }
+}
+
+pub struct Publisher {
+    scheduler: JobScheduler,
+    state: Arc<RwLock<Vec<Publication>>>,
+    /// Publication id -> id of its one-shot scheduler job
+    registered: Arc<RwLock<HashMap<String, uuid::Uuid>>>,
+}
+
+impl Publisher {
+    pub async fn new(scheduler: JobScheduler) -> Result<Self, Box<dyn std::error::Error>> {
+        let publications = storage::load_json::<Vec<Publication>>(Path::new(STATE_PATH)).await?
+            .unwrap_or_default();
+        let publisher = Publisher {
+            scheduler,
+            state: Arc::new(RwLock::new(publications)),
+            registered: Arc::new(RwLock::new(HashMap::new())),
+        };
+
+        // Publications that came due while the server was down go out right away
+        let pending: Vec<Publication> = publisher.state.read().await.iter()
+            .filter(|p| p.status == PublicationStatus::Pending)
+            .cloned()
+            .collect();
+        for publication in &pending {
+            if let Err(e) = publisher.register(publication).await {
+                error!("Failed to schedule publication '{}': {}", publication.name, e);
+            }
+        }
+
+        Ok(publisher)
+    }
+
+    async fn register(&self, publication: &Publication) -> Result<(), Box<dyn std::error::Error>> {
+        let delay = Duration::from_secs(publication.publish_at.saturating_sub(now_secs()).max(1));
+        let state = self.state.clone();
+        let registered = self.registered.clone();
+        let id = publication.id.clone();
+        let job = Job::new_one_shot_async(delay, move |_uuid, _l| {
+            let state = state.clone();
+            let registered = registered.clone();
+            let id = id.clone();
+            Box::pin(async move {
+                registered.write().await.remove(&id);
+                execute(&state, &id).await;
+            })
+        })?;
+
+        let scheduler_id = self.scheduler.add(job).await?;
+        self.registered.write().await.insert(publication.id.clone(), scheduler_id);
+        info!("Scheduled publication '{}' in {}s", publication.name, delay.as_secs());
+        Ok(())
+    }
+
+    /// All publications, soonest first.
+    pub async fn list(&self) -> Vec<Publication> {
+        let mut publications = self.state.read().await.clone();
+        publications.sort_by_key(|p| p.publish_at);
+        publications
+    }
+
+    pub async fn get(&self, id: &str) -> Option<Publication> {
+        self.state.read().await.iter().find(|p| p.id == id).cloned()
+    }
+
+    /// Persist and schedule a new publication. Staged files must already be in place.
+    pub async fn add(&self, publication: Publication) -> Result<Publication, Box<dyn std::error::Error>> {
+        {
+            let mut publications = self.state.write().await;
+            publications.push(publication.clone());
+            save_state(&publications).await?;
+        }
+        self.register(&publication).await?;
+        Ok(publication)
+    }
+
+    /// Cancel a pending publication and drop its staged files. One that has
+    /// started publishing can no longer be cancelled.
+    /// Returns `None` if it does not exist.
+    pub async fn cancel(&self, id: &str) -> Result<Option<Publication>, String> {
+        let cancelled = {
+            let mut publications = self.state.write().await;
+            let Some(publication) = publications.iter_mut().find(|p| p.id == id) else {
+                return Ok(None);
+            };
+            if publication.status != PublicationStatus::Pending {
+                return Err(format!("Publication '{}' is no longer pending", publication.name));
+            }
+            if is_publishing(id) {
+                return Err(format!("Publication '{}' is being published", publication.name));
+            }
+            publication.status = PublicationStatus::Cancelled;
+            publication.message = Some("Cancelled".to_string());
+            publication.finished_at = Some(now_secs());
+            let cancelled = publication.clone();
+            prune(&mut publications);
+            save_state(&publications).await
+                .map_err(|e| format!("Failed to persist publications: {}", e))?;
+            cancelled
+        };
+
+        let scheduler_id = self.registered.write().await.remove(id);
+        if let Some(scheduler_id) = scheduler_id {
+            self.scheduler.remove(&scheduler_id).await
+                .unwrap_or_else(|e| warn!("Failed to remove scheduled publication {}: {}", id, e));
+        }
+        discard_staged(id).await;
+        info!("Cancelled publication '{}'", cancelled.name);
+        Ok(Some(cancelled))
+    }
+}
+
+// Global publisher instance
+static PUBLISHER: tokio::sync::OnceCell<Publisher> = tokio::sync::OnceCell::const_new();
+
+/// Set up the publisher on the git scheduler's job scheduler. Call after `init_scheduler`.
+pub async fn init_publisher() -> Result<(), Box<dyn std::error::Error>> {
+    let scheduler = crate::scheduler::get_scheduler().await.job_scheduler();
+    let publisher = Publisher::new(scheduler).await?;
+    PUBLISHER.set(publisher).map_err(|_| "Failed to initialize publisher")?;
+    info!("Publisher initialized");
+    Ok(())
+}
+
+pub async fn get_publisher() -> &'static Publisher {
+    PUBLISHER.get().expect("Publisher not initialized")
+}
@@ -1,15 +1,35 @@
 use rocket::tokio::sync::RwLock;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::future::Future;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use rocket::tokio::fs;
 use serde::{Deserialize, Serialize};
 
-use crate::api::git::pull_repo_internal;
+use crate::api::git::{commit_all_internal, head_commit_internal, pull_repo_internal, push_repo_internal, GitStatus, PullPolicy};
+use crate::job_history::{JobHistory, JobRun, RunSummary};
+use crate::storage::now_secs;
+use crate::{backup, link_check, storage};
+
+const STATE_PATH: &str = "/app/data/scheduler.json";
+// Where auto-pull settings lived before they were moved to the data dir
+const LEGACY_CONFIG_PATH: &str = "/tmp/auto_pull_config.json";
+const STATE_VERSION: u32 = 2;
+
+/// Job backing the legacy `/api/git/auto-pull` endpoints
+const AUTO_PULL_JOB_ID: &str = "auto-pull";
+/// Longest allowed delay between retries (one day)
+const MAX_RETRY_DELAY_SECS: u64 = 86_400;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct AutoPullConfig {
     pub enabled: bool,
     pub interval_minutes: u32,
+    #[serde(default)]
+    pub policy: PullPolicy,
 }
 
 impl Default for AutoPullConfig {
@@ -17,134 +37,595 @@ impl Default for AutoPullConfig {
         Self {
             enabled: false,
             interval_minutes: 30,
+            policy: PullPolicy::default(),
+        }
+    }
+}
+
+/// What a scheduled job does when it fires.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum JobAction {
+    Pull,
+    CommitAll,
+    Push,
+    Backup,
+    LinkCheck,
+}
+
+impl JobAction {
+    /// Whether a failure may be transient. A link check reports broken content,
+    /// which retrying will not fix.
+    fn retryable(self) -> bool {
+        !matches!(self, JobAction::LinkCheck)
+    }
+}
+
+/// When a scheduled job fires.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JobSchedule {
+    /// Every N minutes, counted from when the job was registered
+    Interval { minutes: u32 },
+    /// Cron expression with 5 (`min hour dom mon dow`) or 6 (leading seconds) fields
+    Cron { expression: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ScheduledJob {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub schedule: JobSchedule,
+    /// IANA timezone name used to evaluate cron expressions
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    pub action: JobAction,
+    /// How a `Pull` deals with local edits and diverged history
+    #[serde(default)]
+    pub pull_policy: PullPolicy,
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// Pause the job after this many failed runs in a row (0 = never)
+    #[serde(default = "default_pause_after_failures")]
+    pub pause_after_failures: u32,
+    /// Unix timestamp (seconds) of the last run
+    #[serde(default)]
+    pub last_run: Option<u64>,
+    /// Failed runs since the last success or resume
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    /// Set by the circuit breaker; the job does not run until an admin resumes it
+    #[serde(default)]
+    pub paused: bool,
+}
+
+pub fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+pub fn default_pause_after_failures() -> u32 {
+    5
+}
+
+/// How a failed scheduled run is retried before it counts as a failure.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RetryPolicy {
+    /// Attempts per run, including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further one
+    pub initial_delay_secs: u64,
+    pub max_delay_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay_secs: 30,
+            max_delay_secs: 600,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before retry number `retry` (starting at 1): exponential backoff capped
+    /// at `max_delay_secs`, of which a random half is jitter so jobs do not retry in lockstep.
+    fn delay(&self, retry: u32) -> Duration {
+        let exp = self.initial_delay_secs.saturating_mul(1u64 << (retry - 1).min(32));
+        let delay_ms = exp.min(self.max_delay_secs).saturating_mul(1000);
+        Duration::from_millis(delay_ms / 2 + rand::random_range(0..=delay_ms / 2))
+    }
+}
+
+impl ScheduledJob {
+    /// Check name, timezone and schedule without registering anything.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("Job name cannot be empty".into());
+        }
+        if !(1..=10).contains(&self.retry.max_attempts) {
+            return Err("Attempts per run must be between 1 and 10".into());
+        }
+        if self.retry.initial_delay_secs == 0 || self.retry.max_delay_secs < self.retry.initial_delay_secs {
+            return Err("Retry delays must be positive, with the maximum at least the initial delay".into());
+        }
+        if self.retry.max_delay_secs > MAX_RETRY_DELAY_SECS {
+            return Err(format!("Retry delays cannot exceed {} seconds", MAX_RETRY_DELAY_SECS));
+        }
+        build_job(self, |_uuid, _l| Box::pin(async {})).map(|_| ())
+    }
+}
+
+/// Everything the scheduler persists between restarts.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct SchedulerState {
+    version: u32,
+    jobs: Vec<ScheduledJob>,
+}
+
+impl Default for SchedulerState {
+    fn default() -> Self {
+        Self {
+            version: STATE_VERSION,
+            jobs: Vec::new(),
+        }
+    }
+}
+
+/// Version 1: a single auto-pull job with its last run time.
+#[derive(Deserialize)]
+struct SchedulerStateV1 {
+    auto_pull: AutoPullConfig,
+    last_run: Option<u64>,
+}
+
+impl From<SchedulerStateV1> for SchedulerState {
+    fn from(v1: SchedulerStateV1) -> Self {
+        let mut job = auto_pull_job(&v1.auto_pull);
+        job.last_run = v1.last_run;
+        Self { version: STATE_VERSION, jobs: vec![job] }
+    }
+}
+
+fn auto_pull_job(config: &AutoPullConfig) -> ScheduledJob {
+    ScheduledJob {
+        id: AUTO_PULL_JOB_ID.to_string(),
+        name: "Auto-pull".to_string(),
+        enabled: config.enabled,
+        schedule: JobSchedule::Interval { minutes: config.interval_minutes },
+        timezone: default_timezone(),
+        action: JobAction::Pull,
+        pull_policy: config.policy,
+        retry: RetryPolicy::default(),
+        pause_after_failures: default_pause_after_failures(),
+        last_run: None,
+        consecutive_failures: 0,
+        paused: false,
+    }
+}
+
+/// Upgrade a state file of any known version to the current schema.
+fn migrate_state(value: serde_json::Value) -> Result<SchedulerState, Box<dyn std::error::Error>> {
+    let version = value.get("version").and_then(|v| v.as_u64()).unwrap_or(0);
+    match version {
+        1 => Ok(serde_json::from_value::<SchedulerStateV1>(value)?.into()),
+        2 => Ok(serde_json::from_value(value)?),
+        v => Err(format!("Unsupported scheduler state version {}", v).into()),
+    }
+}
+
+async fn save_state(state: &SchedulerState) -> Result<(), Box<dyn std::error::Error>> {
+    storage::save_memory_state(Path::new(STATE_PATH), state).await?;
+    Ok(())
+}
+
+type JobFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Build a `tokio_cron_scheduler` job for the given schedule and timezone.
+fn build_job<F>(job: &ScheduledJob, run: F) -> Result<Job, String>
+where
+    F: FnMut(uuid::Uuid, JobScheduler) -> JobFuture + Send + Sync + 'static,
+{
+    match &job.schedule {
+        JobSchedule::Interval { minutes } => {
+            if *minutes == 0 {
+                return Err("Interval must be at least 1 minute".into());
+            }
+            Job::new_repeated_async(Duration::from_secs(u64::from(*minutes) * 60), run)
+                .map_err(|e| format!("Invalid interval: {:?}", e))
+        }
+        JobSchedule::Cron { expression } => {
+            let tz: chrono_tz::Tz = job.timezone.parse()
+                .map_err(|_| format!("Unknown timezone '{}'", job.timezone))?;
+            // The scheduler expects a leading seconds field
+            let expression = expression.trim();
+            let expression = if expression.split_whitespace().count() == 5 {
+                format!("0 {}", expression)
+            } else {
+                expression.to_string()
+            };
+            Job::new_async_tz(expression.as_str(), tz, run)
+                .map_err(|e| format!("Invalid cron expression '{}': {:?}", expression, e))
+        }
+    }
+}
+
+/// Flatten a git operation's result into a success or failure message.
+pub fn git_outcome(result: Result<GitStatus, String>) -> Result<String, String> {
+    match result {
+        Ok(status) if status.success => Ok(status.message),
+        Ok(status) => Err(status.message),
+        Err(e) => Err(e),
+    }
+}
+
+/// Perform a job's action. Returns a human readable outcome message.
+async fn run_action(job: &ScheduledJob) -> Result<String, String> {
+    match job.action {
+        JobAction::Pull => git_outcome(pull_repo_internal(job.pull_policy).await),
+        JobAction::CommitAll => git_outcome(commit_all_internal("Scheduled commit via simple_web").await),
+        JobAction::Push => git_outcome(push_repo_internal().await),
+        JobAction::Backup => backup::create_snapshot().await
+            .map(|path| format!("Backup written to {}", path.display()))
+            .map_err(|e| format!("Backup failed: {}", e)),
+        JobAction::LinkCheck => {
+            let broken = link_check::check_site().await
+                .map_err(|e| format!("Link check failed: {}", e))?;
+            if broken.is_empty() {
+                Ok("No broken links found".to_string())
+            } else {
+                let sample: Vec<String> = broken.iter().take(5)
+                    .map(|link| format!("{} -> {}", link.file, link.target))
+                    .collect();
+                Err(format!("Found {} broken links: {}", broken.len(), sample.join(", ")))
+            }
         }
     }
 }
 
+/// Ids of the jobs with a run in progress
+static RUNNING: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Marks a job as running until dropped
+struct RunningJob(String);
+
+impl RunningJob {
+    /// `None` if the job is already running
+    fn claim(job_id: &str) -> Option<Self> {
+        let mut running = RUNNING.lock().unwrap_or_else(|e| e.into_inner());
+        if running.iter().any(|id| id == job_id) {
+            return None;
+        }
+        running.push(job_id.to_string());
+        Some(RunningJob(job_id.to_string()))
+    }
+}
+
+impl Drop for RunningJob {
+    fn drop(&mut self) {
+        RUNNING.lock().unwrap_or_else(|e| e.into_inner()).retain(|id| *id != self.0);
+    }
+}
+
+/// Run a job by id, record the run in the history and update its run state.
+/// Scheduled runs retry according to the job's policy and are skipped while the
+/// job is paused; manual runs make a single attempt. A job never runs twice at
+/// once: a run started while one is in progress, e.g. waiting for a retry, is skipped.
+/// Returns `None` if the job does not exist.
+async fn execute_job(
+    state: &RwLock<SchedulerState>,
+    history: &RwLock<JobHistory>,
+    job_id: &str,
+    scheduled: bool,
+) -> Option<Result<String, String>> {
+    let job = state.read().await.jobs.iter().find(|j| j.id == job_id).cloned()?;
+    if scheduled && job.paused {
+        info!("Skipping paused job '{}'", job.name);
+        return Some(Err("Job is paused".to_string()));
+    }
+    let Some(_running) = RunningJob::claim(&job.id) else {
+        info!("Skipping job '{}': its previous run is still in progress", job.name);
+        return Some(Err("The previous run is still in progress".to_string()));
+    };
+
+    info!("Running scheduled job '{}' ({:?})", job.name, job.action);
+    let started_at = now_secs();
+    let commit_before = head_commit_internal();
+    let max_attempts = if scheduled && job.action.retryable() { job.retry.max_attempts.max(1) } else { 1 };
+    let mut attempts = 1;
+    let outcome = loop {
+        let outcome = run_action(&job).await;
+        match &outcome {
+            Err(message) if attempts < max_attempts => {
+                let delay = job.retry.delay(attempts);
+                warn!("Scheduled job '{}' failed (attempt {}/{}), retrying in {}s: {}",
+                      job.name, attempts, max_attempts, delay.as_secs(), message);
+                rocket::tokio::time::sleep(delay).await;
+                attempts += 1;
+            }
+            _ => break outcome,
+        }
+    };
+    let finished_at = now_secs();
+    match &outcome {
+        Ok(message) => info!("Scheduled job '{}' succeeded: {}", job.name, message),
+        Err(message) => warn!("Scheduled job '{}' failed: {}", job.name, message),
+    }
+
+    let (success, message) = match &outcome {
+        Ok(message) => (true, message.clone()),
+        Err(message) => (false, message.clone()),
+    };
+    history.write().await.record(JobRun {
+        job_id: job.id.clone(),
+        job_name: job.name.clone(),
+        action: job.action,
+        started_at,
+        finished_at,
+        success,
+        message,
+        attempts,
+        commit_before,
+        commit_after: head_commit_internal(),
+    }).await;
+
+    let mut state = state.write().await;
+    if let Some(stored) = state.jobs.iter_mut().find(|j| j.id == job_id) {
+        stored.last_run = Some(finished_at);
+        if success {
+            stored.consecutive_failures = 0;
+        } else {
+            stored.consecutive_failures += 1;
+            let limit = stored.pause_after_failures;
+            if limit > 0 && stored.consecutive_failures >= limit && !stored.paused {
+                stored.paused = true;
+                error!("Pausing job '{}' after {} consecutive failures", stored.name, stored.consecutive_failures);
+            }
+        }
+    }
+    if let Err(e) = save_state(&state).await {
+        error!("Failed to persist scheduler state: {}", e);
+    }
+
+    Some(outcome)
+}
+
+/// A job together with its live scheduling and run status.
+pub struct JobStatus {
+    pub job: ScheduledJob,
+    /// Unix timestamp (seconds) of the next scheduled run
+    pub next_run: Option<u64>,
+    pub runs: RunSummary,
+}
+
 pub struct GitScheduler {
     scheduler: JobScheduler,
-    config: Arc<RwLock<AutoPullConfig>>,
-    current_job_id: Arc<RwLock<Option<uuid::Uuid>>>,
+    state: Arc<RwLock<SchedulerState>>,
+    history: Arc<RwLock<JobHistory>>,
+    /// Our job id -> id of the registered `tokio_cron_scheduler` job
+    registered: Arc<RwLock<HashMap<String, uuid::Uuid>>>,
 }
 
 impl GitScheduler {
     pub async fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let scheduler = JobScheduler::new().await?;
-        let config = Arc::new(RwLock::new(Self::load_config().await.unwrap_or_default()));
-        let current_job_id = Arc::new(RwLock::new(None));
+        let loaded = Self::load_state().await.map_err(|e| e.to_string());
+        let state = match loaded {
+            Ok(state) => state,
+            Err(e) => {
+                // Keep the unreadable file: the next save would otherwise erase every job
+                let aside = storage::set_aside(Path::new(STATE_PATH)).await
+                    .map_err(|io| format!("Failed to load scheduler state ({}) or move it aside: {}", e, io))?;
+                match aside {
+                    Some(aside) => error!("Failed to load scheduler state ({}); moved it to {} and starting with defaults", e, aside.display()),
+                    None => error!("Failed to load scheduler state, using defaults: {}", e),
+                }
+                SchedulerState::default()
+            }
+        };
+        let state = Arc::new(RwLock::new(state));
+        let history = Arc::new(RwLock::new(JobHistory::load().await));
+        let registered = Arc::new(RwLock::new(HashMap::new()));
 
         let git_scheduler = GitScheduler {
             scheduler,
-            config,
-            current_job_id,
+            state,
+            history,
+            registered,
         };
 
         // Start scheduler
         git_scheduler.scheduler.start().await?;
 
-        // Load existing config and setup job if enabled
-        {
-            let config_read = git_scheduler.config.read().await;
-            if config_read.enabled {
-                drop(config_read);
-                git_scheduler.setup_auto_pull_job().await?;
+        // Register every enabled job; a broken one should not keep the others from running
+        let jobs = git_scheduler.state.read().await.jobs.clone();
+        for job in jobs.iter().filter(|j| j.enabled) {
+            if let Err(e) = git_scheduler.register(job).await {
+                error!("Failed to schedule job '{}': {}", job.name, e);
             }
         }
 
         Ok(git_scheduler)
     }
 
-    async fn load_config() -> Result<AutoPullConfig, Box<dyn std::error::Error>> {
-        let config_path = "/tmp/auto_pull_config.json";
-        if !std::path::Path::new(config_path).exists() {
-            return Ok(AutoPullConfig::default());
+    async fn load_state() -> Result<SchedulerState, Box<dyn std::error::Error>> {
+        if let Some(value) = storage::load_json::<serde_json::Value>(Path::new(STATE_PATH)).await? {
+            let state = migrate_state(value)?;
+            return Ok(state);
         }
-        
-        let content = fs::read_to_string(config_path).await?;
-        let config: AutoPullConfig = serde_json::from_str(&content)?;
-        Ok(config)
+
+        // First start with a persistent data dir: pick up settings from the old /tmp location
+        let Some(legacy) = storage::load_json::<AutoPullConfig>(Path::new(LEGACY_CONFIG_PATH)).await? else {
+            return Ok(SchedulerState::default());
+        };
+        info!("Migrating auto-pull settings from {} to {}", LEGACY_CONFIG_PATH, STATE_PATH);
+        let state: SchedulerState = SchedulerStateV1 { auto_pull: legacy, last_run: None }.into();
+        save_state(&state).await?;
+        if let Err(e) = fs::remove_file(LEGACY_CONFIG_PATH).await {
+            warn!("Failed to remove legacy auto-pull config: {}", e);
+        }
+        Ok(state)
     }
 
-    async fn save_config(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config = self.config.read().await;
-        let content = serde_json::to_string_pretty(&*config)?;
-        fs::write("/tmp/auto_pull_config.json", content).await?;
+    async fn register(&self, job: &ScheduledJob) -> Result<(), Box<dyn std::error::Error>> {
+        let state = self.state.clone();
+        let history = self.history.clone();
+        let job_id = job.id.clone();
+        let cron_job = build_job(job, move |_uuid, _l| {
+            let state = state.clone();
+            let history = history.clone();
+            let job_id = job_id.clone();
+            Box::pin(async move {
+                execute_job(&state, &history, &job_id, true).await;
+            })
+        })?;
+
+        let scheduler_id = self.scheduler.add(cron_job).await?;
+        self.registered.write().await.insert(job.id.clone(), scheduler_id);
+        info!("Scheduled job '{}' ({:?})", job.name, job.schedule);
         Ok(())
     }
 
-    pub async fn update_config(&self, new_config: AutoPullConfig) -> Result<(), Box<dyn std::error::Error>> {
-        info!("Updating auto-pull configuration: enabled={}, interval={}min", 
-              new_config.enabled, new_config.interval_minutes);
-
-        // Remove existing job if any
-        self.remove_current_job().await?;
+    async fn unregister(&self, job_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(scheduler_id) = self.registered.write().await.remove(job_id) {
+            self.scheduler.remove(&scheduler_id).await?;
+            info!("Removed scheduled job {}", job_id);
+        }
+        Ok(())
+    }
 
-        // Update config
-        {
-            let mut config = self.config.write().await;
-            *config = new_config.clone();
+    /// Stop running scheduled jobs and publications until the server restarts
+    pub async fn stop(&self) {
+        match self.scheduler.clone().shutdown().await {
+            Ok(()) => warn!("Scheduler stopped until the server restarts"),
+            Err(e) => error!("Failed to stop the scheduler: {}", e),
         }
+    }
 
-        // Save to disk
-        self.save_config().await?;
+    /// The underlying scheduler, for one-off jobs owned by other modules
+    pub fn job_scheduler(&self) -> JobScheduler {
+        self.scheduler.clone()
+    }
 
-        // Setup new job if enabled
-        if new_config.enabled {
-            self.setup_auto_pull_job().await?;
+    pub async fn list_jobs(&self) -> Vec<JobStatus> {
+        let jobs = self.state.read().await.jobs.clone();
+        let mut result = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            result.push(self.job_status(job).await);
         }
+        result
+    }
 
-        Ok(())
+    pub async fn get_job(&self, job_id: &str) -> Option<JobStatus> {
+        let job = self.state.read().await.jobs.iter().find(|j| j.id == job_id).cloned()?;
+        Some(self.job_status(job).await)
     }
 
-    async fn remove_current_job(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let mut job_id_guard = self.current_job_id.write().await;
-        if let Some(job_id) = *job_id_guard {
-            self.scheduler.remove(&job_id).await?;
-            info!("Removed existing auto-pull job");
-        }
-        *job_id_guard = None;
-        Ok(())
+    async fn job_status(&self, job: ScheduledJob) -> JobStatus {
+        let next_run = if job.paused { None } else { self.next_run(&job.id).await };
+        let runs = self.history.read().await.summary(&job.id);
+        JobStatus { job, next_run, runs }
     }
 
-    async fn setup_auto_pull_job(&self) -> Result<(), Box<dyn std::error::Error>> {
-        let config = self.config.read().await;
-        let interval = config.interval_minutes;
-        drop(config);
+    /// Recorded runs, most recent first.
+    pub async fn history(&self, job_id: Option<&str>, limit: usize) -> Vec<JobRun> {
+        self.history.read().await.recent(job_id, limit)
+    }
 
-        // Create cron expression for every N minutes
-        let cron_expr = format!("0 */{} * * * *", interval);
-        
-        let job = Job::new_async(cron_expr.as_str(), |_uuid, _l| {
-            Box::pin(async move {
-                info!("Running scheduled git pull...");
-                match pull_repo_internal().await {
-                    Ok(status) => {
-                        if status.success {
-                            info!("Scheduled git pull successful: {}", status.message);
-                        } else {
-                            warn!("Scheduled git pull failed: {}", status.message);
-                        }
-                    }
-                    Err(e) => {
-                        error!("Scheduled git pull error: {}", e);
-                    }
-                }
-            })
-        })?;
+    async fn next_run(&self, job_id: &str) -> Option<u64> {
+        let scheduler_id = *self.registered.read().await.get(job_id)?;
+        let mut scheduler = self.scheduler.clone();
+        scheduler.next_tick_for_job(scheduler_id).await.ok().flatten()
+            .map(|t| t.timestamp().max(0) as u64)
+    }
+
+    /// Insert or replace a job (matched by id), persist it and (re)schedule it.
+    /// The stored run state (last run, failures, paused) is kept when replacing.
+    pub async fn upsert_job(&self, mut job: ScheduledJob) -> Result<ScheduledJob, Box<dyn std::error::Error>> {
+        job.validate()?;
+        self.unregister(&job.id).await?;
 
-        let job_id = self.scheduler.add(job).await?;
-        
         {
-            let mut job_id_guard = self.current_job_id.write().await;
-            *job_id_guard = Some(job_id);
+            let mut state = self.state.write().await;
+            match state.jobs.iter_mut().find(|j| j.id == job.id) {
+                Some(existing) => {
+                    job.last_run = existing.last_run;
+                    job.consecutive_failures = existing.consecutive_failures;
+                    job.paused = existing.paused;
+                    *existing = job.clone();
+                }
+                None => state.jobs.push(job.clone()),
+            }
+            save_state(&state).await?;
         }
 
-        info!("Auto-pull job scheduled every {} minutes", interval);
+        if job.enabled {
+            self.register(&job).await?;
+        }
+        Ok(job)
+    }
+
+    /// Remove a job. Returns `false` if it did not exist.
+    pub async fn delete_job(&self, job_id: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        self.unregister(job_id).await?;
+
+        let mut state = self.state.write().await;
+        let before = state.jobs.len();
+        state.jobs.retain(|j| j.id != job_id);
+        if state.jobs.len() == before {
+            return Ok(false);
+        }
+        save_state(&state).await?;
+        Ok(true)
+    }
+
+    /// Clear a job's circuit breaker so it runs on schedule again.
+    /// Returns `false` if the job does not exist.
+    pub async fn resume_job(&self, job_id: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut state = self.state.write().await;
+        let Some(job) = state.jobs.iter_mut().find(|j| j.id == job_id) else {
+            return Ok(false);
+        };
+        job.paused = false;
+        job.consecutive_failures = 0;
+        info!("Resumed job '{}'", job.name);
+        save_state(&state).await?;
+        Ok(true)
+    }
+
+    /// Run a job immediately, regardless of its schedule, enabled flag or pause.
+    pub async fn run_job_now(&self, job_id: &str) -> Option<Result<String, String>> {
+        execute_job(&self.state, &self.history, job_id, false).await
+    }
+
+    pub async fn update_config(&self, new_config: AutoPullConfig) -> Result<(), Box<dyn std::error::Error>> {
+        info!("Updating auto-pull configuration: enabled={}, interval={}min, policy={:?}",
+              new_config.enabled, new_config.interval_minutes, new_config.policy);
+        // Only what the config covers changes; retry and pause settings stay
+        let existing = self.state.read().await.jobs.iter().find(|j| j.id == AUTO_PULL_JOB_ID).cloned();
+        let job = match existing {
+            Some(mut job) => {
+                job.enabled = new_config.enabled;
+                job.schedule = JobSchedule::Interval { minutes: new_config.interval_minutes };
+                job.pull_policy = new_config.policy;
+                job
+            }
+            None => auto_pull_job(&new_config),
+        };
+        self.upsert_job(job).await?;
         Ok(())
     }
 
     pub async fn get_config(&self) -> AutoPullConfig {
-        self.config.read().await.clone()
+        let state = self.state.read().await;
+        match state.jobs.iter().find(|j| j.id == AUTO_PULL_JOB_ID) {
+            Some(ScheduledJob { enabled, schedule: JobSchedule::Interval { minutes }, pull_policy, .. }) => {
+                AutoPullConfig { enabled: *enabled, interval_minutes: *minutes, policy: *pull_policy }
+            }
+            Some(job) => AutoPullConfig { enabled: job.enabled, policy: job.pull_policy, ..AutoPullConfig::default() },
+            None => AutoPullConfig::default(),
+        }
     }
 }
 
@@ -160,4 +641,4 @@ pub async fn init_scheduler() -> Result<(), Box<dyn std::error::Error>> {
 
 pub async fn get_scheduler() -> &'static GitScheduler {
     GIT_SCHEDULER.get().expect("Scheduler not initialized")
-}
\ No newline at end of file
+}
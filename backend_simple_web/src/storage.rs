@@ -0,0 +1,89 @@
+// backend_simple_web/src/storage.rs
+//
+// Helpers for JSON state kept in the persistent data directory.
+
+use rocket::tokio::{fs, io::AsyncWriteExt};
+use serde::{de::DeserializeOwned, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Set once a restore has replaced files in the data directory
+static DATA_RESTORED: AtomicBool = AtomicBool::new(false);
+
+/// Current Unix time in seconds
+pub fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Write `contents` to `path` without ever leaving a half-written file behind:
+/// the data goes to a uniquely named sibling temp file first, so concurrent writes
+/// of the same file don't share it, is synced, then renamed over the target.
+pub async fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+
+    let file_name = path.file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path has no file name"))?;
+    let tmp_path = path.with_file_name(format!(
+        ".{}.{}.tmp", file_name.to_string_lossy(), uuid::Uuid::new_v4()
+    ));
+
+    let mut file = fs::File::create(&tmp_path).await?;
+    file.write_all(contents).await?;
+    file.sync_all().await?;
+    drop(file);
+
+    fs::rename(&tmp_path, path).await
+}
+
+/// Read and parse a JSON file. Returns `Ok(None)` if the file does not exist.
+pub async fn load_json<T: DeserializeOwned>(path: &Path) -> io::Result<Option<T>> {
+    let content = match fs::read_to_string(path).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Rename a state file that could not be read to `<name>.corrupt-<unix time>`,
+/// so state saved afterwards does not overwrite it. Returns the new path, or
+/// `None` if there was no file.
+pub async fn set_aside(path: &Path) -> io::Result<Option<PathBuf>> {
+    let file_name = path.file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Path has no file name"))?;
+    let aside = path.with_file_name(format!("{}.corrupt-{}", file_name.to_string_lossy(), now_secs()));
+    match fs::rename(path, &aside).await {
+        Ok(()) => Ok(Some(aside)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Serialize `value` as pretty JSON and write it atomically.
+pub async fn save_json<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    let content = serde_json::to_string_pretty(value)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    write_atomic(path, content.as_bytes()).await
+}
+
+/// Record that a restore replaced files in the data directory. The state the
+/// scheduler, job history and publisher hold in memory is stale from then on,
+/// and `save_memory_state` refuses to write it until the server restarts.
+pub fn mark_data_restored() {
+    DATA_RESTORED.store(true, Ordering::Relaxed);
+}
+
+/// `save_json` for state that is also held in memory, so that it doesn't
+/// overwrite files restored from a backup.
+pub async fn save_memory_state<T: Serialize>(path: &Path, value: &T) -> io::Result<()> {
+    if DATA_RESTORED.load(Ordering::Relaxed) {
+        return Err(io::Error::other("the data directory was restored from a backup; restart the server to load it"));
+    }
+    save_json(path, value).await
+}
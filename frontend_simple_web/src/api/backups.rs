@@ -0,0 +1,115 @@
+// frontend_simple_web/src/api/backups.rs
+use gloo::net::http::Request;
+use serde::Deserialize;
+use wasm_bindgen_futures::spawn_local;
+
+use super::auth::{get_token, handle_auth_error};
+use super::client::{self, Method};
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct BackupInfo {
+    pub name: String,
+    pub created_at: u64,
+    pub size: u64,
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+pub struct RestorePlan {
+    pub added: Vec<String>,
+    pub modified: Vec<String>,
+    pub deleted: Vec<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct BackupListResponse {
+    pub directory: String,
+    pub backups: Vec<BackupInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct BackupResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RestoreResponse {
+    pub success: bool,
+    pub message: String,
+    #[serde(default)]
+    pub plan: RestorePlan,
+}
+
+pub fn api_list_backups<F: Fn(Result<BackupListResponse, String>) + 'static>(callback: Option<F>) {
+    client::spawn_request(Method::Get, "/api/backups".into(), None, callback);
+}
+
+pub fn api_create_backup<F: Fn(Result<String, String>) + 'static>(callback: Option<F>) {
+    client::spawn_request::<BackupResponse, _>(
+        Method::Post,
+        "/api/backups".into(),
+        None,
+        callback.map(|cb| {
+            move |result: Result<BackupResponse, String>| {
+                match result {
+                    Ok(r) if r.success => cb(Ok(r.message)),
+                    Ok(r) => cb(Err(r.message)),
+                    Err(e) => cb(Err(e)),
+                }
+            }
+        }),
+    );
+}
+
+/// Dry-run or real restore. On success the callback gets the message and the plan.
+pub fn api_restore_backup<F: Fn(Result<(String, RestorePlan), String>) + 'static>(
+    name: String,
+    include_data: bool,
+    dry_run: bool,
+    callback: Option<F>,
+) {
+    let url = format!("/api/backups/{}/restore?dry_run={}&data={}", name, dry_run, include_data);
+    client::spawn_request::<RestoreResponse, _>(
+        Method::Post,
+        url,
+        None,
+        callback.map(|cb| {
+            move |result: Result<RestoreResponse, String>| {
+                match result {
+                    Ok(r) if r.success => cb(Ok((r.message, r.plan))),
+                    Ok(r) => cb(Err(r.message)),
+                    Err(e) => cb(Err(e)),
+                }
+            }
+        }),
+    );
+}
+
+/// Fetch a backup with the auth header and hand it to the browser as a download.
+pub fn download_backup(name: String) {
+    let auth = get_token();
+    spawn_local(async move {
+        let url = format!("/api/backups/{}", name);
+        let Ok(response) = Request::get(&url).header("Authorization", &auth).send().await else {
+            gloo::console::error!("Failed to download backup");
+            return;
+        };
+        if handle_auth_error(response.status()) || !response.ok() {
+            gloo::console::error!(format!("Failed to download backup: HTTP {}", response.status()));
+            return;
+        }
+        let Ok(bytes) = response.binary().await else { return };
+
+        let blob = gloo::file::Blob::new_with_options(bytes.as_slice(), Some("application/gzip"));
+        let object_url = gloo::file::ObjectUrl::from(blob);
+        let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+        if let Ok(anchor) = document.create_element("a") {
+            let anchor: web_sys::HtmlAnchorElement = wasm_bindgen::JsCast::unchecked_into(anchor);
+            anchor.set_href(&object_url);
+            anchor.set_download(&name);
+            anchor.click();
+        }
+        // Revoking the URL right away can cancel the download in some browsers
+        gloo::timers::callback::Timeout::new(10_000, move || drop(object_url)).forget();
+    });
+}
@@ -1,7 +1,9 @@
 // frontend_simple_web/src/api/git.rs
 use serde::{Deserialize, Serialize};
+use urlencoding::encode;
 
 use super::client::{self, Method};
+use super::jobs::PullPolicy;
 
 #[derive(Serialize, Clone)]
 pub struct GitRepoConfig {
@@ -9,6 +11,8 @@ pub struct GitRepoConfig {
     pub branch: Option<String>,
     pub username: Option<String>,
     pub token: Option<String>,
+    pub depth: Option<u32>,
+    pub sparse_paths: Vec<String>,
 }
 
 #[derive(Deserialize)]
@@ -38,30 +42,42 @@ pub struct GitRepoStatus {
     pub has_unstaged_changes: bool,
     pub changed_files: Vec<GitFileStatus>,
     pub untracked_files: Vec<String>,
+    #[serde(default)]
+    pub merge_in_progress: bool,
+    #[serde(default)]
+    pub submodules: Vec<SubmoduleInfo>,
+    #[serde(default)]
+    pub shallow: bool,
+    #[serde(default)]
+    pub sparse_paths: Vec<String>,
+    #[serde(default)]
+    pub lock_holders: Vec<LockHolder>,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct AutoPullConfig {
-    pub enabled: bool,
-    pub interval_minutes: u32,
+/// Operation holding the repository lock
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct LockHolder {
+    pub operation: String,
+    /// Other git operations and file writes wait for it
+    pub exclusive: bool,
+    pub since: u64,
 }
 
-#[derive(Serialize)]
-struct CommitRequest {
-    message: String,
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct SubmoduleInfo {
+    pub name: String,
+    pub path: String,
+    pub url: Option<String>,
+    pub recorded_commit: Option<String>,
+    pub checked_out_commit: Option<String>,
+    /// `uninitialized`, `new_commits`, `modified` or `clean`
+    pub state: String,
 }
 
 fn serialize_body(value: &impl Serialize) -> Result<String, String> {
     serde_json::to_string(value).map_err(|e| format!("Failed to serialize: {}", e))
 }
 
-pub fn api_git_setup(config: GitRepoConfig, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    match serialize_body(&config) {
-        Ok(body) => client::spawn_request(Method::Post, "/api/git/setup".into(), Some(body), callback),
-        Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
-    }
-}
-
 pub fn api_git_test(config: GitRepoConfig, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
     match serialize_body(&config) {
         Ok(body) => client::spawn_request(Method::Post, "/api/git/test".into(), Some(body), callback),
@@ -69,36 +85,1015 @@ pub fn api_git_test(config: GitRepoConfig, callback: Option<impl Fn(Result<GitSt
     }
 }
 
-pub fn api_git_pull(callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    client::spawn_request(Method::Post, "/api/git/pull".into(), None, callback);
+/// How a pull brings a diverged branch up to date
+#[derive(Serialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PullMode {
+    #[default]
+    FastForward,
+    Merge,
+    Rebase,
+}
+
+#[derive(Serialize)]
+struct PullRequest {
+    mode: PullMode,
+    stash: bool,
+    remote: Option<String>,
+}
+
+/// Pull from origin; with `stash` uncommitted changes are stashed first and
+/// restored afterwards.
+/// Fast-forward pull from a named remote
+pub fn api_pull_remote(remote: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/pull", &PullRequest { mode: PullMode::FastForward, stash: false, remote: Some(remote) }, callback);
 }
 
 pub fn api_get_git_status(callback: Option<impl Fn(Result<GitRepoStatus, String>) + 'static>) {
     client::spawn_request(Method::Get, "/api/git/status".into(), None, callback);
 }
 
-pub fn api_commit_changes(message: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    match serialize_body(&CommitRequest { message }) {
+#[derive(Serialize)]
+pub struct CommitRequest {
+    pub message: String,
+    /// Stage and commit everything instead of only what is staged
+    pub all: bool,
+    /// Author profile id; the repository's identity when unset
+    pub author: Option<String>,
+    /// `Name <email>` of co-authors
+    pub co_authors: Vec<String>,
+}
+
+pub fn api_commit_changes(request: CommitRequest, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    match serialize_body(&request) {
         Ok(body) => client::spawn_request(Method::Post, "/api/git/commit".into(), Some(body), callback),
         Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
     }
 }
 
-pub fn api_push_changes(callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    client::spawn_request(Method::Post, "/api/git/push".into(), None, callback);
+// --- Branches ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct BranchInfo {
+    pub name: String,
+    pub is_head: bool,
+    pub upstream: Option<String>,
+    pub ahead: usize,
+    pub behind: usize,
+    pub commit: Option<String>,
+    pub summary: Option<String>,
 }
 
-pub fn api_force_pull(callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    client::spawn_request(Method::Post, "/api/git/force-pull".into(), None, callback);
+#[derive(Deserialize, Clone)]
+pub struct BranchListResponse {
+    pub success: bool,
+    pub message: String,
+    pub current: Option<String>,
+    pub local: Vec<BranchInfo>,
+    pub remote: Vec<BranchInfo>,
 }
 
-pub fn api_get_auto_pull_config(callback: Option<impl Fn(Result<AutoPullConfig, String>) + 'static>) {
-    client::spawn_request(Method::Get, "/api/git/auto-pull".into(), None, callback);
+#[derive(Serialize)]
+struct CreateBranchRequest {
+    name: String,
+    from: Option<String>,
+    checkout: bool,
 }
 
-pub fn api_set_auto_pull_config(config: AutoPullConfig, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
-    match serialize_body(&config) {
-        Ok(body) => client::spawn_request(Method::Post, "/api/git/auto-pull".into(), Some(body), callback),
+#[derive(Serialize)]
+struct CheckoutRequest {
+    name: String,
+    carry_changes: bool,
+    stash: bool,
+}
+
+#[derive(Serialize)]
+struct RenameBranchRequest {
+    name: String,
+    new_name: String,
+}
+
+#[derive(Serialize)]
+struct UpstreamRequest {
+    name: String,
+    upstream: Option<String>,
+}
+
+fn post_git<B: Serialize>(url: &str, body: &B, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    match serialize_body(body) {
+        Ok(body) => client::spawn_request(Method::Post, url.into(), Some(body), callback),
         Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
     }
 }
+
+/// List branches; with `fetch` origin is fetched first so remote branches are current.
+pub fn api_list_branches(fetch: bool, callback: Option<impl Fn(Result<BranchListResponse, String>) + 'static>) {
+    let url = format!("/api/git/branches?fetch={}", fetch);
+    client::spawn_request(Method::Get, url, None, callback);
+}
+
+pub fn api_create_branch(name: String, from: Option<String>, checkout: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/branches", &CreateBranchRequest { name, from, checkout }, callback);
+}
+
+/// Switch branches, carrying uncommitted changes along or stashing them first.
+pub fn api_checkout_branch(name: String, carry_changes: bool, stash: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/branches/checkout", &CheckoutRequest { name, carry_changes, stash }, callback);
+}
+
+pub fn api_rename_branch(name: String, new_name: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/branches/rename", &RenameBranchRequest { name, new_name }, callback);
+}
+
+pub fn api_set_branch_upstream(name: String, upstream: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/branches/upstream", &UpstreamRequest { name, upstream }, callback);
+}
+
+pub fn api_delete_branch(name: String, force: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    // Branch names may contain `/`, which the route takes as path segments
+    let path: Vec<String> = name.split('/').map(|segment| encode(segment).into_owned()).collect();
+    let url = format!("/api/git/branches/{}?force={}", path.join("/"), force);
+    client::spawn_request(Method::Delete, url, None, callback);
+}
+
+// --- History ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct LogAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct LogFile {
+    pub path: String,
+    pub status: String,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct LogStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct LogEntry {
+    pub hash: String,
+    pub short_hash: String,
+    pub author: LogAuthor,
+    pub date: i64,
+    pub message: String,
+    pub parents: Vec<String>,
+    pub stats: LogStats,
+    pub files: Vec<LogFile>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl LogEntry {
+    /// First line of the message
+    pub fn summary(&self) -> &str {
+        self.message.lines().next().unwrap_or("")
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct LogResponse {
+    pub success: bool,
+    pub message: String,
+    pub entries: Vec<LogEntry>,
+    pub has_more: bool,
+}
+
+/// Filters for `GET /api/git/log`; empty fields are left out.
+#[derive(Clone, Default, PartialEq)]
+pub struct LogQuery {
+    pub path: Option<String>,
+    pub author: String,
+    pub search: String,
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+}
+
+impl LogQuery {
+    fn to_query_string(&self, skip: usize, limit: usize) -> String {
+        let mut params = vec![format!("skip={}", skip), format!("limit={}", limit)];
+        if let Some(path) = &self.path {
+            params.push(format!("path={}", encode(path)));
+        }
+        if !self.author.trim().is_empty() {
+            params.push(format!("author={}", encode(self.author.trim())));
+        }
+        if !self.search.trim().is_empty() {
+            params.push(format!("search={}", encode(self.search.trim())));
+        }
+        if let Some(since) = self.since {
+            params.push(format!("since={}", since));
+        }
+        if let Some(until) = self.until {
+            params.push(format!("until={}", until));
+        }
+        params.join("&")
+    }
+}
+
+pub fn api_git_log(query: &LogQuery, skip: usize, limit: usize, callback: Option<impl Fn(Result<LogResponse, String>) + 'static>) {
+    let url = format!("/api/git/log?{}", query.to_query_string(skip, limit));
+    client::spawn_request(Method::Get, url, None, callback);
+}
+
+// --- Blame ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct BlameHunk {
+    /// First line, counting from 1
+    pub start_line: usize,
+    pub lines: usize,
+    /// `None` for lines not committed yet
+    pub commit: Option<String>,
+    pub short_hash: Option<String>,
+    pub author: Option<String>,
+    pub email: Option<String>,
+    pub date: Option<i64>,
+    pub summary: Option<String>,
+    pub orig_path: Option<String>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct BlameResponse {
+    pub success: bool,
+    pub message: String,
+    pub hunks: Vec<BlameHunk>,
+}
+
+/// Blame the working copy of `path`
+pub fn api_git_blame(path: &str, callback: Option<impl Fn(Result<BlameResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, format!("/api/git/blame?path={}", encode(path)), None, callback);
+}
+
+// --- Diff ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DiffLine {
+    /// context, addition or deletion
+    pub kind: String,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+    pub content: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DiffHunk {
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DiffFile {
+    pub old_path: Option<String>,
+    pub new_path: Option<String>,
+    pub status: String,
+    pub binary: bool,
+    pub insertions: usize,
+    pub deletions: usize,
+    pub hunks: Vec<DiffHunk>,
+}
+
+impl DiffFile {
+    /// Path shown for the file, `old → new` for renames
+    pub fn display_path(&self) -> String {
+        match (&self.old_path, &self.new_path) {
+            (Some(old), Some(new)) if old != new => format!("{} → {}", old, new),
+            (_, Some(path)) | (Some(path), None) => path.clone(),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DiffResponse {
+    pub success: bool,
+    pub message: String,
+    pub files: Vec<DiffFile>,
+    pub raw: Option<String>,
+    pub truncated: bool,
+}
+
+/// What `GET /api/git/diff` compares.
+#[derive(Clone, PartialEq)]
+pub enum DiffTarget {
+    /// Unstaged changes
+    WorkingTree,
+    /// Staged changes
+    Staged,
+    /// A commit against its parent
+    Commit(String),
+    Range { from: String, to: String },
+    /// A stash, by its index in the stash list
+    Stash(usize),
+}
+
+pub fn api_git_diff(target: &DiffTarget, path: Option<&str>, callback: Option<impl Fn(Result<DiffResponse, String>) + 'static>) {
+    let mut params = match target {
+        DiffTarget::WorkingTree => vec![],
+        DiffTarget::Staged => vec!["staged=true".to_string()],
+        DiffTarget::Commit(rev) => vec![format!("commit={}", encode(rev))],
+        DiffTarget::Range { from, to } => vec![format!("from={}", encode(from)), format!("to={}", encode(to))],
+        // Stashes have their own endpoint, which does not filter by path
+        DiffTarget::Stash(index) => {
+            client::spawn_request(Method::Get, format!("/api/git/stash/{}/diff", index), None, callback);
+            return;
+        }
+    };
+    if let Some(path) = path {
+        params.push(format!("path={}", encode(path)));
+    }
+    let url = format!("/api/git/diff?{}", params.join("&"));
+    client::spawn_request(Method::Get, url, None, callback);
+}
+
+// --- Staging ---
+
+#[derive(Serialize)]
+struct PathsRequest {
+    paths: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct HunkRequest {
+    path: String,
+    hunk: usize,
+    header: String,
+}
+
+pub fn api_stage_paths(paths: Vec<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/stage", &PathsRequest { paths }, callback);
+}
+
+pub fn api_unstage_paths(paths: Vec<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/unstage", &PathsRequest { paths }, callback);
+}
+
+/// Stage (or with `unstage`, unstage) hunk number `hunk` of a file's diff.
+/// `header` guards against the file having changed since the diff was loaded.
+pub fn api_stage_hunk(path: String, hunk: usize, header: String, unstage: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    let url = if unstage { "/api/git/unstage/hunk" } else { "/api/git/stage/hunk" };
+    post_git(url, &HunkRequest { path, hunk, header }, callback);
+}
+
+// --- Commit identity ---
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct Identity {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct AuthorProfile {
+    pub id: String,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct IdentityResponse {
+    pub committer: Option<Identity>,
+    pub authors: Vec<AuthorProfile>,
+    pub repo_default: Option<Identity>,
+}
+
+#[derive(Serialize)]
+struct CommitterRequest {
+    committer: Option<Identity>,
+}
+
+#[derive(Serialize)]
+struct AuthorRequest {
+    id: Option<String>,
+    name: String,
+    email: String,
+}
+
+pub fn api_get_identity(callback: Option<impl Fn(Result<IdentityResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/identity".into(), None, callback);
+}
+
+/// Set the instance-wide committer, or with `None` let authors commit themselves.
+pub fn api_set_committer(committer: Option<Identity>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/identity/committer", &CommitterRequest { committer }, callback);
+}
+
+/// Create an author profile, or update the one with `id`.
+pub fn api_save_author(id: Option<String>, name: String, email: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/identity/authors", &AuthorRequest { id, name, email }, callback);
+}
+
+pub fn api_delete_author(id: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    let url = format!("/api/git/identity/authors/{}", encode(id));
+    client::spawn_request(Method::Delete, url, None, callback);
+}
+
+// --- Merge conflicts ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct ConflictFile {
+    pub path: String,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+    pub working: Option<String>,
+    pub binary: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct MergeStatusResponse {
+    pub success: bool,
+    pub message: String,
+    pub in_progress: bool,
+    pub merge_heads: Vec<String>,
+    pub conflicts: Vec<ConflictFile>,
+}
+
+/// How to resolve a conflicted file
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    Ours,
+    Theirs,
+    Content,
+}
+
+#[derive(Serialize)]
+struct ResolveRequest {
+    path: String,
+    resolution: Resolution,
+    content: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ContinueRequest {
+    message: Option<String>,
+}
+
+pub fn api_merge_status(callback: Option<impl Fn(Result<MergeStatusResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/merge".into(), None, callback);
+}
+
+/// Resolve a conflicted file; `content` is only used with `Resolution::Content`.
+pub fn api_resolve_conflict(path: String, resolution: Resolution, content: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/merge/resolve", &ResolveRequest { path, resolution, content }, callback);
+}
+
+/// Commit the merge; without a message the one prepared by the merge is used.
+pub fn api_continue_merge(message: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/merge/continue", &ContinueRequest { message }, callback);
+}
+
+pub fn api_abort_merge(callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Post, "/api/git/merge/abort".into(), None, callback);
+}
+
+// --- Stash ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct StashEntry {
+    pub index: usize,
+    pub message: String,
+    pub commit: String,
+    pub date: i64,
+    pub includes_untracked: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct StashListResponse {
+    pub success: bool,
+    pub message: String,
+    pub stashes: Vec<StashEntry>,
+}
+
+#[derive(Serialize)]
+struct StashRequest {
+    message: Option<String>,
+    include_untracked: bool,
+}
+
+pub fn api_list_stashes(callback: Option<impl Fn(Result<StashListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/stash".into(), None, callback);
+}
+
+pub fn api_save_stash(message: Option<String>, include_untracked: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/stash", &StashRequest { message, include_untracked }, callback);
+}
+
+/// Re-apply stash `index`; with `pop` it is dropped afterwards.
+pub fn api_apply_stash(index: usize, pop: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    let url = format!("/api/git/stash/{}/{}", index, if pop { "pop" } else { "apply" });
+    client::spawn_request(Method::Post, url, None, callback);
+}
+
+pub fn api_drop_stash(index: usize, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, format!("/api/git/stash/{}", index), None, callback);
+}
+
+// --- Revert, reset and restore ---
+
+#[derive(Serialize)]
+struct RevertRequest {
+    commit: String,
+    mainline: Option<u32>,
+    author: Option<String>,
+}
+
+/// How far a reset goes
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetMode {
+    Soft,
+    Mixed,
+    Hard,
+}
+
+#[derive(Serialize)]
+struct ResetRequest {
+    commit: String,
+    mode: ResetMode,
+    confirm: bool,
+}
+
+#[derive(Serialize)]
+struct RestoreRequest {
+    commit: String,
+    path: String,
+}
+
+/// Commit the inverse of `commit`; merges need the `mainline` parent to keep.
+pub fn api_revert_commit(commit: String, mainline: Option<u32>, author: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/revert", &RevertRequest { commit, mainline, author }, callback);
+}
+
+/// Move the current branch to `commit`. Callers must have asked the user first.
+pub fn api_reset_branch(commit: String, mode: ResetMode, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/reset", &ResetRequest { commit, mode, confirm: true }, callback);
+}
+
+/// Restore a file or directory from `commit` into the working tree.
+pub fn api_restore_path(commit: String, path: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/restore", &RestoreRequest { commit, path }, callback);
+}
+
+// --- SSH deploy key ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct KnownHost {
+    pub hosts: String,
+    pub key_type: String,
+    pub key: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct SshConfigResponse {
+    pub success: bool,
+    pub message: String,
+    pub public_key: Option<String>,
+    pub known_hosts: Vec<KnownHost>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct HostScanResponse {
+    pub success: bool,
+    pub message: String,
+    pub host: String,
+    pub key_type: Option<String>,
+    pub fingerprint: Option<String>,
+    pub line: Option<String>,
+}
+
+#[derive(Serialize)]
+struct GenerateKeyRequest {
+    comment: Option<String>,
+    overwrite: bool,
+}
+
+#[derive(Serialize)]
+struct ImportKeyRequest {
+    private_key: String,
+    overwrite: bool,
+}
+
+#[derive(Serialize)]
+struct ScanRequest {
+    host: String,
+    port: Option<u16>,
+}
+
+#[derive(Serialize)]
+struct KnownHostRequest {
+    line: String,
+}
+
+pub fn api_get_ssh_config(callback: Option<impl Fn(Result<SshConfigResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/ssh".into(), None, callback);
+}
+
+/// Generate a new deploy key; `overwrite` replaces an existing one.
+pub fn api_generate_ssh_key(comment: Option<String>, overwrite: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/ssh/generate", &GenerateKeyRequest { comment, overwrite }, callback);
+}
+
+pub fn api_import_ssh_key(private_key: String, overwrite: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/ssh/import", &ImportKeyRequest { private_key, overwrite }, callback);
+}
+
+pub fn api_delete_ssh_key(callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, "/api/git/ssh".into(), None, callback);
+}
+
+/// Read a host's key and fingerprint without trusting it.
+pub fn api_scan_known_host(host: String, port: Option<u16>, callback: Option<impl Fn(Result<HostScanResponse, String>) + 'static>) {
+    match serialize_body(&ScanRequest { host, port }) {
+        Ok(body) => client::spawn_request(Method::Post, "/api/git/ssh/known-hosts/scan".into(), Some(body), callback),
+        Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
+    }
+}
+
+pub fn api_add_known_host(line: String, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/ssh/known-hosts", &KnownHostRequest { line }, callback);
+}
+
+pub fn api_remove_known_host(hosts: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, format!("/api/git/ssh/known-hosts/{}", encode(hosts)), None, callback);
+}
+
+// --- Credential profiles ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct CredentialProfile {
+    pub id: String,
+    pub name: String,
+    /// Empty when the profile applies to every host
+    pub host: String,
+    pub username: String,
+    pub token_set: bool,
+    pub token_hint: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct CredentialListResponse {
+    pub success: bool,
+    pub message: String,
+    pub profiles: Vec<CredentialProfile>,
+}
+
+#[derive(Serialize)]
+struct CredentialRequest {
+    id: Option<String>,
+    name: String,
+    host: String,
+    username: String,
+    token: Option<String>,
+}
+
+pub fn api_list_credentials(callback: Option<impl Fn(Result<CredentialListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/credentials".into(), None, callback);
+}
+
+/// Create a profile, or update `id`. Without a token an existing profile keeps its own.
+pub fn api_save_credentials(id: Option<String>, name: String, host: String, username: String, token: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/credentials", &CredentialRequest { id, name, host, username, token }, callback);
+}
+
+pub fn api_delete_credentials(id: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, format!("/api/git/credentials/{}", encode(id)), None, callback);
+}
+
+// --- Remotes ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct RemoteInfo {
+    pub name: String,
+    pub url: String,
+    pub push_url: Option<String>,
+    pub mirror: bool,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct RemoteListResponse {
+    pub success: bool,
+    pub message: String,
+    pub remotes: Vec<RemoteInfo>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct RemotePushResult {
+    pub remote: String,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct PushResponse {
+    pub success: bool,
+    pub message: String,
+    pub commit_hash: Option<String>,
+    #[serde(default)]
+    pub remotes: Vec<RemotePushResult>,
+}
+
+#[derive(Serialize)]
+struct AddRemoteRequest {
+    name: String,
+    url: String,
+    push_url: Option<String>,
+    mirror: bool,
+}
+
+#[derive(Serialize)]
+struct UpdateRemoteRequest {
+    rename: Option<String>,
+    url: Option<String>,
+    push_url: Option<String>,
+    mirror: Option<bool>,
+}
+
+#[derive(Serialize)]
+struct PushRequest {
+    remote: Option<String>,
+}
+
+pub fn api_list_remotes(callback: Option<impl Fn(Result<RemoteListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/remotes".into(), None, callback);
+}
+
+pub fn api_add_remote(name: String, url: String, push_url: Option<String>, mirror: bool, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/remotes", &AddRemoteRequest { name, url, push_url, mirror }, callback);
+}
+
+/// Change a remote; `None` fields are left as they are and an empty `push_url` removes it.
+pub fn api_update_remote(name: &str, rename: Option<String>, url: Option<String>, push_url: Option<String>, mirror: Option<bool>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    let url_path = format!("/api/git/remotes/{}", encode(name));
+    post_git(&url_path, &UpdateRemoteRequest { rename, url, push_url, mirror }, callback);
+}
+
+pub fn api_delete_remote(name: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, format!("/api/git/remotes/{}", encode(name)), None, callback);
+}
+
+/// Push to one remote, or to origin and every mirror when `remote` is `None`
+pub fn api_push(remote: Option<String>, callback: Option<impl Fn(Result<PushResponse, String>) + 'static>) {
+    match serialize_body(&PushRequest { remote }) {
+        Ok(body) => client::spawn_request(Method::Post, "/api/git/push".into(), Some(body), callback),
+        Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
+    }
+}
+
+/// Fetch a remote; answers with the refreshed branch list
+pub fn api_fetch_remote(remote: &str, callback: Option<impl Fn(Result<BranchListResponse, String>) + 'static>) {
+    let url = format!("/api/git/branches?fetch=true&remote={}", encode(remote));
+    client::spawn_request(Method::Get, url, None, callback);
+}
+
+// --- Tags ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct TagInfo {
+    pub name: String,
+    pub target: String,
+    pub annotated: bool,
+    pub message: Option<String>,
+    pub tagger: Option<String>,
+    pub date: i64,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct TagListResponse {
+    pub success: bool,
+    pub message: String,
+    pub tags: Vec<TagInfo>,
+}
+
+#[derive(Serialize)]
+struct CreateTagRequest {
+    name: String,
+    commit: Option<String>,
+    message: Option<String>,
+    author: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PushTagsRequest {
+    name: Option<String>,
+    remote: Option<String>,
+}
+
+pub fn api_list_tags(callback: Option<impl Fn(Result<TagListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/tags".into(), None, callback);
+}
+
+/// Create an annotated tag on `commit`, or on HEAD when `None`
+pub fn api_create_tag(name: String, commit: Option<String>, message: Option<String>, author: Option<String>, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    post_git("/api/git/tags", &CreateTagRequest { name, commit, message, author }, callback);
+}
+
+pub fn api_delete_tag(name: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    // Tag names may contain `/`, which the route takes as path segments
+    let path: Vec<String> = name.split('/').map(|segment| encode(segment).into_owned()).collect();
+    client::spawn_request(Method::Delete, format!("/api/git/tags/{}", path.join("/")), None, callback);
+}
+
+/// Push one tag, or every tag when `name` is `None`, to origin and every mirror
+pub fn api_push_tags(name: Option<String>, callback: Option<impl Fn(Result<PushResponse, String>) + 'static>) {
+    match serialize_body(&PushTagsRequest { name, remote: None }) {
+        Ok(body) => client::spawn_request(Method::Post, "/api/git/tags/push".into(), Some(body), callback),
+        Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
+    }
+}
+
+// --- Webhooks ---
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct WebhookInfo {
+    pub id: String,
+    pub name: String,
+    /// Masked; the full secret is only returned when it is set
+    pub secret: String,
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub policy: PullPolicy,
+    pub enabled: bool,
+    /// Receiving path, relative to the API URL
+    pub path: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct WebhookListResponse {
+    pub success: bool,
+    pub message: String,
+    pub hooks: Vec<WebhookInfo>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: String,
+    pub hook_id: String,
+    pub provider: Option<String>,
+    pub event: Option<String>,
+    pub branch: Option<String>,
+    pub received_at: u64,
+    /// rejected, ignored, queued, succeeded or failed
+    pub status: String,
+    pub message: String,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct DeliveryListResponse {
+    pub success: bool,
+    pub message: String,
+    pub deliveries: Vec<WebhookDelivery>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct SaveWebhookResponse {
+    pub success: bool,
+    pub message: String,
+    /// Id of the saved hook
+    pub commit_hash: Option<String>,
+    /// The new secret, when the hook was created or its secret replaced
+    #[serde(default)]
+    pub secret: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SaveWebhookRequest {
+    id: Option<String>,
+    name: String,
+    branch: Option<String>,
+    policy: PullPolicy,
+    enabled: bool,
+    regenerate_secret: bool,
+}
+
+pub fn api_list_webhooks(callback: Option<impl Fn(Result<WebhookListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/webhooks".into(), None, callback);
+}
+
+/// Create a webhook, or update the one with `id`; a new hook's id comes back in
+/// `commit_hash`, and a new secret in `secret`.
+pub fn api_save_webhook(id: Option<String>, name: String, branch: Option<String>, policy: PullPolicy, enabled: bool, regenerate_secret: bool, callback: Option<impl Fn(Result<SaveWebhookResponse, String>) + 'static>) {
+    match serialize_body(&SaveWebhookRequest { id, name, branch, policy, enabled, regenerate_secret }) {
+        Ok(body) => client::spawn_request(Method::Post, "/api/git/webhooks".into(), Some(body), callback),
+        Err(e) => { if let Some(cb) = callback { cb(Err(e)); } }
+    }
+}
+
+pub fn api_delete_webhook(id: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Delete, format!("/api/git/webhooks/{}", encode(id)), None, callback);
+}
+
+pub fn api_list_deliveries(limit: usize, callback: Option<impl Fn(Result<DeliveryListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, format!("/api/git/webhooks/deliveries?limit={}", limit), None, callback);
+}
+
+// --- Background tasks ---
+
+/// Operation to run in the background
+#[derive(Serialize, Clone)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum TaskRequest {
+    Clone(GitRepoConfig),
+    Pull { mode: PullMode, stash: bool, remote: Option<String> },
+    ForcePull,
+    Push { remote: Option<String> },
+}
+
+#[derive(Deserialize, Clone, PartialEq, Default)]
+pub struct TaskProgress {
+    /// receiving, resolving, packing or uploading
+    pub stage: Option<String>,
+    pub current: usize,
+    pub total: usize,
+    pub bytes: usize,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct GitTask {
+    pub id: String,
+    pub operation: String,
+    /// queued, running, succeeded, failed or cancelled
+    pub state: String,
+    pub progress: TaskProgress,
+    pub message: Option<String>,
+    pub commit_hash: Option<String>,
+    pub created_at: u64,
+    pub finished_at: Option<u64>,
+}
+
+impl GitTask {
+    pub fn is_finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct TaskResponse {
+    pub success: bool,
+    pub message: String,
+    pub task: Option<GitTask>,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct TaskListResponse {
+    pub success: bool,
+    pub message: String,
+    pub tasks: Vec<GitTask>,
+}
+
+/// How often a started task is checked on
+const TASK_POLL_MS: u32 = 1000;
+
+pub fn api_list_git_tasks(callback: Option<impl Fn(Result<TaskListResponse, String>) + 'static>) {
+    client::spawn_request(Method::Get, "/api/git/tasks".into(), None, callback);
+}
+
+pub fn api_cancel_git_task(id: &str, callback: Option<impl Fn(Result<GitStatus, String>) + 'static>) {
+    client::spawn_request(Method::Post, format!("/api/git/tasks/{}/cancel", encode(id)), None, callback);
+}
+
+/// Run `request` as a background task and call back with its outcome once
+/// it has finished. Progress shows in the `GitTasks` list meanwhile.
+pub fn run_git_task(request: TaskRequest, callback: impl Fn(Result<GitStatus, String>) + 'static) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let body = match serialize_body(&request) {
+            Ok(body) => body,
+            Err(e) => return callback(Err(e)),
+        };
+        let started: TaskResponse = match client::request(Method::Post, "/api/git/tasks", Some(body)).await {
+            Ok(response) => response,
+            Err(e) => return callback(Err(e)),
+        };
+        let Some(mut task) = started.task else {
+            return callback(Ok(GitStatus { success: false, message: started.message, commit_hash: None }));
+        };
+        while !task.is_finished() {
+            gloo::timers::future::TimeoutFuture::new(TASK_POLL_MS).await;
+            let url = format!("/api/git/tasks/{}", encode(&task.id));
+            match client::request::<TaskResponse>(Method::Get, &url, None).await {
+                Ok(TaskResponse { task: Some(current), .. }) => task = current,
+                Ok(response) => return callback(Ok(GitStatus { success: false, message: response.message, commit_hash: None })),
+                Err(e) => return callback(Err(e)),
+            }
+        }
+        callback(Ok(GitStatus {
+            success: task.state == "succeeded",
+            message: task.message.unwrap_or_default(),
+            commit_hash: task.commit_hash,
+        }));
+    });
+}
@@ -0,0 +1,288 @@
+// frontend_simple_web/src/api/jobs.rs
+use serde::{Deserialize, Serialize};
+
+use super::client::{self, Method};
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum JobAction {
+    Pull,
+    CommitAll,
+    Push,
+    Backup,
+    LinkCheck,
+}
+
+impl JobAction {
+    pub const ALL: [JobAction; 5] = [
+        JobAction::Pull,
+        JobAction::CommitAll,
+        JobAction::Push,
+        JobAction::Backup,
+        JobAction::LinkCheck,
+    ];
+
+    /// Stable identifier, used as `<select>` option value
+    pub fn key(&self) -> &'static str {
+        match self {
+            JobAction::Pull => "pull",
+            JobAction::CommitAll => "commit_all",
+            JobAction::Push => "push",
+            JobAction::Backup => "backup",
+            JobAction::LinkCheck => "link_check",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.key() == key)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            JobAction::Pull => "Pull",
+            JobAction::CommitAll => "Commit all changes",
+            JobAction::Push => "Push",
+            JobAction::Backup => "Backup",
+            JobAction::LinkCheck => "Link check",
+        }
+    }
+}
+
+/// What a scheduled pull does with uncommitted edits
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DirtyTreePolicy {
+    #[default]
+    Skip,
+    Stash,
+    Commit,
+    Discard,
+}
+
+impl DirtyTreePolicy {
+    pub const ALL: [DirtyTreePolicy; 4] = [
+        DirtyTreePolicy::Skip,
+        DirtyTreePolicy::Stash,
+        DirtyTreePolicy::Commit,
+        DirtyTreePolicy::Discard,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            DirtyTreePolicy::Skip => "skip",
+            DirtyTreePolicy::Stash => "stash",
+            DirtyTreePolicy::Commit => "commit",
+            DirtyTreePolicy::Discard => "discard",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.key() == key)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DirtyTreePolicy::Skip => "Skip the pull and report it",
+            DirtyTreePolicy::Stash => "Stash, pull, then re-apply",
+            DirtyTreePolicy::Commit => "Commit them before pulling",
+            DirtyTreePolicy::Discard => "Discard them (cannot be undone)",
+        }
+    }
+}
+
+/// What a scheduled pull does when local and remote history have diverged
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergedPolicy {
+    #[default]
+    Skip,
+    Merge,
+    Rebase,
+    Reset,
+}
+
+impl DivergedPolicy {
+    pub const ALL: [DivergedPolicy; 4] = [
+        DivergedPolicy::Skip,
+        DivergedPolicy::Merge,
+        DivergedPolicy::Rebase,
+        DivergedPolicy::Reset,
+    ];
+
+    pub fn key(&self) -> &'static str {
+        match self {
+            DivergedPolicy::Skip => "skip",
+            DivergedPolicy::Merge => "merge",
+            DivergedPolicy::Rebase => "rebase",
+            DivergedPolicy::Reset => "reset",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.key() == key)
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DivergedPolicy::Skip => "Skip the pull and report it",
+            DivergedPolicy::Merge => "Merge",
+            DivergedPolicy::Rebase => "Rebase local commits",
+            DivergedPolicy::Reset => "Reset to remote (drops local commits)",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug, Default)]
+pub struct PullPolicy {
+    pub dirty: DirtyTreePolicy,
+    pub diverged: DivergedPolicy,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JobSchedule {
+    Interval { minutes: u32 },
+    Cron { expression: String },
+}
+
+impl JobSchedule {
+    pub fn describe(&self) -> String {
+        match self {
+            JobSchedule::Interval { minutes } => format!("Every {} minutes", minutes),
+            JobSchedule::Cron { expression } => format!("Cron: {}", expression),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_delay_secs: u64,
+    pub max_delay_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_delay_secs: 30,
+            max_delay_secs: 600,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct JobInfo {
+    pub id: String,
+    pub name: String,
+    pub enabled: bool,
+    pub schedule: JobSchedule,
+    pub timezone: String,
+    pub action: JobAction,
+    #[serde(default)]
+    pub pull_policy: PullPolicy,
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    pub pause_after_failures: u32,
+    pub last_run: Option<u64>,
+    pub next_run: Option<u64>,
+    pub last_success: Option<bool>,
+    pub last_message: Option<String>,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub paused: bool,
+}
+
+#[derive(Serialize, Clone)]
+pub struct JobSpec {
+    pub name: String,
+    pub enabled: bool,
+    pub schedule: JobSchedule,
+    pub timezone: String,
+    pub action: JobAction,
+    pub pull_policy: PullPolicy,
+    pub retry: RetryPolicy,
+    pub pause_after_failures: u32,
+}
+
+impl From<&JobInfo> for JobSpec {
+    fn from(job: &JobInfo) -> Self {
+        Self {
+            name: job.name.clone(),
+            enabled: job.enabled,
+            schedule: job.schedule.clone(),
+            timezone: job.timezone.clone(),
+            action: job.action,
+            pull_policy: job.pull_policy,
+            retry: job.retry.clone(),
+            pause_after_failures: job.pause_after_failures,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct JobListResponse {
+    pub jobs: Vec<JobInfo>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct JobResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+fn message_callback<F: Fn(Result<String, String>) + 'static>(cb: F) -> impl Fn(Result<JobResponse, String>) {
+    move |result: Result<JobResponse, String>| {
+        match result {
+            Ok(r) if r.success => cb(Ok(r.message)),
+            Ok(r) => cb(Err(r.message)),
+            Err(e) => cb(Err(e)),
+        }
+    }
+}
+
+pub fn api_list_jobs<F: Fn(Result<Vec<JobInfo>, String>) + 'static>(callback: Option<F>) {
+    client::spawn_request::<JobListResponse, _>(
+        Method::Get,
+        "/api/jobs".into(),
+        None,
+        callback.map(|cb| {
+            move |result: Result<JobListResponse, String>| {
+                cb(result.map(|r| r.jobs));
+            }
+        }),
+    );
+}
+
+/// Create a job, or update it when `id` is given.
+pub fn api_save_job<F: Fn(Result<String, String>) + 'static>(id: Option<String>, spec: JobSpec, callback: Option<F>) {
+    let body = match serde_json::to_string(&spec) {
+        Ok(b) => b,
+        Err(e) => {
+            if let Some(cb) = callback { cb(Err(format!("Failed to serialize job: {}", e))); }
+            return;
+        }
+    };
+    let url = match id {
+        Some(id) => format!("/api/jobs/{}", id),
+        None => "/api/jobs".to_string(),
+    };
+
+    client::spawn_request::<JobResponse, _>(Method::Post, url, Some(body), callback.map(message_callback));
+}
+
+pub fn api_delete_job<F: Fn(Result<String, String>) + 'static>(id: String, callback: Option<F>) {
+    let url = format!("/api/jobs/{}", id);
+    client::spawn_request::<JobResponse, _>(Method::Delete, url, None, callback.map(message_callback));
+}
+
+pub fn api_run_job<F: Fn(Result<String, String>) + 'static>(id: String, callback: Option<F>) {
+    let url = format!("/api/jobs/{}/run", id);
+    client::spawn_request::<JobResponse, _>(Method::Post, url, None, callback.map(message_callback));
+}
+
+pub fn api_resume_job<F: Fn(Result<String, String>) + 'static>(id: String, callback: Option<F>) {
+    let url = format!("/api/jobs/{}/resume", id);
+    client::spawn_request::<JobResponse, _>(Method::Post, url, None, callback.map(message_callback));
+}
@@ -1,6 +1,9 @@
 // frontend_simple_web/src/api.rs
 pub mod auth;
+pub mod backups;
 pub mod client;
 pub mod file;
 pub mod git;
+pub mod jobs;
+pub mod publications;
 pub mod themes;
@@ -0,0 +1,118 @@
+// frontend_simple_web/src/api/publications.rs
+use serde::{Deserialize, Serialize};
+
+use super::client::{self, Method};
+
+#[derive(Deserialize, Clone, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PublishSource {
+    Files { paths: Vec<String> },
+    GitRef { reference: String },
+}
+
+impl PublishSource {
+    pub fn describe(&self) -> String {
+        match self {
+            PublishSource::Files { paths } => paths.join(", "),
+            PublishSource::GitRef { reference } => format!("git ref {}", reference),
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicationStatus {
+    Pending,
+    Published,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Deserialize, Clone, PartialEq)]
+pub struct Publication {
+    pub id: String,
+    pub name: String,
+    pub publish_at: u64,
+    pub source: PublishSource,
+    pub commit: bool,
+    pub push: bool,
+    pub status: PublicationStatus,
+    pub message: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+pub struct StagedFile {
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Serialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PublishRequestSource {
+    Files { files: Vec<StagedFile> },
+    GitRef { reference: String },
+}
+
+#[derive(Serialize, Clone)]
+pub struct PublishRequest {
+    pub name: String,
+    pub publish_at: u64,
+    pub source: PublishRequestSource,
+    pub commit: bool,
+    pub push: bool,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PublicationListResponse {
+    pub publications: Vec<Publication>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PublicationResponse {
+    pub success: bool,
+    pub message: String,
+}
+
+fn message_callback<F: Fn(Result<String, String>) + 'static>(cb: F) -> impl Fn(Result<PublicationResponse, String>) {
+    move |result: Result<PublicationResponse, String>| {
+        match result {
+            Ok(r) if r.success => cb(Ok(r.message)),
+            Ok(r) => cb(Err(r.message)),
+            Err(e) => cb(Err(e)),
+        }
+    }
+}
+
+pub fn api_list_publications<F: Fn(Result<Vec<Publication>, String>) + 'static>(callback: Option<F>) {
+    client::spawn_request::<PublicationListResponse, _>(
+        Method::Get,
+        "/api/publications".into(),
+        None,
+        callback.map(|cb| {
+            move |result: Result<PublicationListResponse, String>| {
+                cb(result.map(|r| r.publications));
+            }
+        }),
+    );
+}
+
+pub fn api_schedule_publication<F: Fn(Result<String, String>) + 'static>(request: PublishRequest, callback: Option<F>) {
+    let body = match serde_json::to_string(&request) {
+        Ok(b) => b,
+        Err(e) => {
+            if let Some(cb) = callback { cb(Err(format!("Failed to serialize publication: {}", e))); }
+            return;
+        }
+    };
+    client::spawn_request::<PublicationResponse, _>(
+        Method::Post,
+        "/api/publications".into(),
+        Some(body),
+        callback.map(message_callback),
+    );
+}
+
+pub fn api_cancel_publication<F: Fn(Result<String, String>) + 'static>(id: String, callback: Option<F>) {
+    let url = format!("/api/publications/{}", id);
+    client::spawn_request::<PublicationResponse, _>(Method::Delete, url, None, callback.map(message_callback));
+}
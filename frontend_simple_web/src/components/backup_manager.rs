@@ -0,0 +1,245 @@
+// frontend_simple_web/src/components/backup_manager.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::backups::{api_create_backup, api_list_backups, api_restore_backup, download_backup, BackupInfo, BackupListResponse, RestorePlan};
+use crate::components::job_manager::format_timestamp;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+/// Paths listed per change type in a restore preview
+const PREVIEW_LIMIT: usize = 20;
+
+fn format_size(bytes: u64) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+fn refresh_backups(
+    backups: &UseStateHandle<Vec<BackupInfo>>,
+    directory: &UseStateHandle<String>,
+    status_message: &StatusMsg,
+) {
+    let backups = backups.clone();
+    let directory = directory.clone();
+    let status_message = status_message.clone();
+    api_list_backups(Some(move |result: Result<BackupListResponse, String>| {
+        match result {
+            Ok(list) => {
+                directory.set(list.directory);
+                backups.set(list.backups);
+            }
+            Err(e) => status_message.set(Some((false, format!("Failed to load backups: {}", e)))),
+        }
+    }));
+}
+
+fn plan_section(title: &str, paths: &[String]) -> Html {
+    if paths.is_empty() {
+        return html! {};
+    }
+    let more = paths.len().saturating_sub(PREVIEW_LIMIT);
+    html! {
+        <div class="text-sm">
+            <div class="font-medium">{ format!("{} ({})", title, paths.len()) }</div>
+            { for paths.iter().take(PREVIEW_LIMIT).map(|p| html! { <div><code>{ p }</code></div> }) }
+            { if more > 0 { html! { <div>{ format!("…and {} more", more) }</div> } } else { html! {} } }
+        </div>
+    }
+}
+
+#[function_component(BackupManager)]
+pub fn backup_manager() -> Html {
+    let backups = use_state(Vec::<BackupInfo>::new);
+    let directory = use_state(String::new);
+    let status_message: StatusMsg = use_state(|| None);
+    let include_data = use_state(|| false);
+    // Backup name and its dry-run result
+    let preview = use_state(|| None::<(String, RestorePlan)>);
+    let busy = use_state(|| false);
+
+    {
+        let backups = backups.clone();
+        let directory = directory.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_backups(&backups, &directory, &status_message);
+            || ()
+        });
+    }
+
+    let on_create = {
+        let backups = backups.clone();
+        let directory = directory.clone();
+        let status_message = status_message.clone();
+        let busy = busy.clone();
+        Callback::from(move |_| {
+            busy.set(true);
+            let backups = backups.clone();
+            let directory = directory.clone();
+            let status_message = status_message.clone();
+            let busy = busy.clone();
+            api_create_backup(Some(move |result: Result<String, String>| {
+                busy.set(false);
+                match result {
+                    Ok(msg) => status_message.set(Some((true, msg))),
+                    Err(e) => status_message.set(Some((false, e))),
+                }
+                refresh_backups(&backups, &directory, &status_message);
+            }));
+        })
+    };
+
+    let on_include_data = {
+        let include_data = include_data.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                include_data.set(input.checked());
+                // A preview made with the other setting no longer applies
+                preview.set(None);
+            }
+        })
+    };
+
+    let rows = backups.iter().map(|backup| {
+        let on_download = {
+            let name = backup.name.clone();
+            Callback::from(move |_| download_backup(name.clone()))
+        };
+
+        let on_preview = {
+            let name = backup.name.clone();
+            let include_data = include_data.clone();
+            let preview = preview.clone();
+            let status_message = status_message.clone();
+            Callback::from(move |_| {
+                let preview = preview.clone();
+                let status_message = status_message.clone();
+                let name_for_cb = name.clone();
+                api_restore_backup(name.clone(), *include_data, true, Some(move |result: Result<(String, RestorePlan), String>| {
+                    match result {
+                        Ok((msg, plan)) => {
+                            status_message.set(Some((true, msg)));
+                            preview.set(Some((name_for_cb.clone(), plan)));
+                        }
+                        Err(e) => status_message.set(Some((false, e))),
+                    }
+                }));
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <div class="text-sm">
+                    <span class="font-medium">{ format_timestamp(Some(backup.created_at)) }</span>
+                    { format!(" · {} · {}", backup.name, format_size(backup.size)) }
+                </div>
+                <div class="flex gap-2">
+                    <button class="btn btn-secondary text-sm" onclick={on_download}>{"Download"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_preview}>{"Preview restore"}</button>
+                </div>
+            </div>
+        }
+    }).collect::<Html>();
+
+    let preview_html = if let Some((ref name, ref plan)) = *preview {
+        let on_restore = {
+            let name = name.clone();
+            let include_data = include_data.clone();
+            let preview = preview.clone();
+            let status_message = status_message.clone();
+            let backups = backups.clone();
+            let directory = directory.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!(
+                        "Restore {}? The listed files will be overwritten or deleted. A backup of the current state is taken first.", name
+                    )).ok())
+                    .unwrap_or(false);
+                if !confirmed {
+                    return;
+                }
+                let preview = preview.clone();
+                let status_message = status_message.clone();
+                let backups = backups.clone();
+                let directory = directory.clone();
+                api_restore_backup(name.clone(), *include_data, false, Some(move |result: Result<(String, RestorePlan), String>| {
+                    match result {
+                        Ok((msg, _)) => status_message.set(Some((true, msg))),
+                        Err(e) => status_message.set(Some((false, e))),
+                    }
+                    preview.set(None);
+                    refresh_backups(&backups, &directory, &status_message);
+                }));
+            })
+        };
+        let on_close = {
+            let preview = preview.clone();
+            Callback::from(move |_| preview.set(None))
+        };
+        let has_changes = !(plan.added.is_empty() && plan.modified.is_empty() && plan.deleted.is_empty());
+
+        html! {
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{ format!("Restore preview: {}", name) }</h3>
+                { plan_section("Added", &plan.added) }
+                { plan_section("Modified", &plan.modified) }
+                { plan_section("Deleted", &plan.deleted) }
+                <div class="flex gap-2">
+                    { if has_changes {
+                        html! { <button class="btn btn-danger" onclick={on_restore}>{"Restore"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                    <button class="btn btn-secondary" onclick={on_close}>{"Close"}</button>
+                </div>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <div class="space-y-4">
+            <div class="flex items-center justify-between">
+                <p class="text-sm">{ format!("Snapshots of the site and settings in {}. Add a Backup job above to take them on a schedule.", *directory) }</p>
+                <button class="btn btn-primary text-sm" onclick={on_create} disabled={*busy}>
+                    { if *busy { "Backing up..." } else { "Back up now" } }
+                </button>
+            </div>
+
+            { if let Some((success, ref msg)) = *status_message {
+                html! {
+                    <div class="p-2 rounded text-sm" style={
+                        if success {
+                            "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                        } else {
+                            "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                        }
+                    }>
+                        { msg }
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            <div class="flex items-center gap-3">
+                <input type="checkbox" id="restore-data" checked={*include_data} onchange={on_include_data} />
+                <label for="restore-data" class="text-sm font-medium">{"Also restore settings (themes, jobs; applied after a restart)"}</label>
+            </div>
+
+            { preview_html }
+
+            { if backups.is_empty() {
+                html! { <p class="text-sm">{"No backups yet."}</p> }
+            } else {
+                rows
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,131 @@
+// frontend_simple_web/src/components/blame_view.rs
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+use crate::api::file::get_api_file;
+use crate::api::git::{api_git_blame, BlameHunk, BlameResponse, DiffTarget};
+use crate::components::diff_view::DiffView;
+use crate::components::job_manager::format_timestamp;
+
+#[derive(Properties, PartialEq)]
+pub struct BlameViewProps {
+    /// File to annotate, relative to the site root
+    pub path: String,
+    /// Bumped when git rewrote the file, to fetch the blame again
+    #[prop_or_default]
+    pub revision: u32,
+}
+
+/// Gutter text of the first line of a hunk
+fn hunk_label(hunk: &BlameHunk) -> String {
+    match (&hunk.short_hash, &hunk.author) {
+        (Some(_), Some(author)) => format!("{} · {}", author, format_timestamp(hunk.date.map(|d| d.max(0) as u64))),
+        _ => "Not committed".to_string(),
+    }
+}
+
+/// Read-only copy of the saved file with who last changed each line. Clicking
+/// a commit shows its diff of this file. Unsaved edits are not included.
+#[function_component(BlameView)]
+pub fn blame_view(props: &BlameViewProps) -> Html {
+    let blame = use_state(|| None::<BlameResponse>);
+    let lines = use_state(Vec::<String>::new);
+    let error = use_state(|| None::<String>);
+    let selected = use_state(|| None::<String>);
+
+    {
+        let blame = blame.clone();
+        let lines = lines.clone();
+        let error = error.clone();
+        use_effect_with((props.path.clone(), props.revision), move |(path, _)| {
+            blame.set(None);
+            error.set(None);
+            {
+                let path = path.clone();
+                let error = error.clone();
+                spawn_local(async move {
+                    match get_api_file(&path).await {
+                        Ok(resp) => match resp.text().await {
+                            Ok(body) => lines.set(body.lines().map(str::to_string).collect()),
+                            Err(e) => error.set(Some(format!("Failed to read file: {:?}", e))),
+                        },
+                        Err(e) => error.set(Some(format!("Failed to read file: {:?}", e))),
+                    }
+                });
+            }
+            api_git_blame(path, Some(move |result: Result<BlameResponse, String>| {
+                match result {
+                    Ok(response) if response.success => blame.set(Some(response)),
+                    Ok(response) => error.set(Some(response.message)),
+                    Err(e) => error.set(Some(format!("Failed to load blame: {}", e))),
+                }
+            }));
+            || ()
+        });
+    }
+
+    if let Some(ref e) = *error {
+        return html! { <p class="text-sm text-red-600">{ e }</p> };
+    }
+    let Some(ref response) = *blame else {
+        return html! { <p class="text-sm">{"Loading blame..."}</p> };
+    };
+
+    let row = |hunk: &BlameHunk, offset: usize| {
+        let number = hunk.start_line + offset;
+        let text = lines.get(number - 1).cloned().unwrap_or_default();
+        let mut class = classes!();
+        if offset == 0 {
+            class.push("blame-start");
+        }
+        if hunk.commit.is_none() {
+            class.push("blame-uncommitted");
+        }
+        let info = if offset > 0 {
+            html! {}
+        } else if let (Some(commit), Some(short_hash)) = (&hunk.commit, &hunk.short_hash) {
+            let on_select = {
+                let selected = selected.clone();
+                let commit = commit.clone();
+                Callback::from(move |_| {
+                    let next = (selected.as_deref() != Some(commit.as_str())).then(|| commit.clone());
+                    selected.set(next);
+                })
+            };
+            html! {
+                <>
+                    <button class="blame-commit" title={hunk.summary.clone().unwrap_or_default()} onclick={on_select}>
+                        <code>{ short_hash }</code>
+                    </button>
+                    { format!(" {}", hunk_label(hunk)) }
+                </>
+            }
+        } else {
+            html! { { hunk_label(hunk) } }
+        };
+        html! {
+            <tr class={class}>
+                <td class="blame-info">{ info }</td>
+                <td class="diff-lineno">{ number }</td>
+                <td>{ text }</td>
+            </tr>
+        }
+    };
+
+    html! {
+        <div class="space-y-2">
+            <p class="text-xs">{"Blame of the saved file; unsaved edits are not shown."}</p>
+            { match *selected {
+                Some(ref commit) => html! {
+                    <div class="p-2 border rounded">
+                        <DiffView target={DiffTarget::Commit(commit.clone())} path={Some(props.path.clone())} />
+                    </div>
+                },
+                None => html! {},
+            }}
+            <table class="diff-table blame-table">
+                { for response.hunks.iter().flat_map(|hunk| (0..hunk.lines).map(move |offset| (hunk, offset))).map(|(hunk, offset)| row(hunk, offset)) }
+            </table>
+        </div>
+    }
+}
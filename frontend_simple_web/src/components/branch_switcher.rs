@@ -0,0 +1,326 @@
+// frontend_simple_web/src/components/branch_switcher.rs
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_checkout_branch, api_create_branch, api_delete_branch, api_list_branches,
+    api_rename_branch, api_set_branch_upstream, BranchInfo, BranchListResponse, DiffTarget, GitStatus,
+};
+use crate::components::diff_view::DiffView;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_branches(list: &UseStateHandle<Option<BranchListResponse>>, status_message: &StatusMsg, fetch: bool) {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    api_list_branches(fetch, Some(move |result: Result<BranchListResponse, String>| {
+        match result {
+            Ok(branches) if branches.success => list.set(Some(branches)),
+            Ok(branches) => status_message.set(Some((false, branches.message))),
+            Err(e) => status_message.set(Some((false, format!("Failed to load branches: {}", e)))),
+        }
+    }));
+}
+
+/// Callback that reports a git operation's outcome and reloads the branch list.
+fn reload_after(list: &UseStateHandle<Option<BranchListResponse>>, status_message: &StatusMsg) -> impl Fn(Result<GitStatus, String>) + Clone + 'static {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    move |result: Result<GitStatus, String>| {
+        match result {
+            Ok(status) => status_message.set(Some((status.success, status.message))),
+            Err(e) => status_message.set(Some((false, e))),
+        }
+        refresh_branches(&list, &status_message, false);
+    }
+}
+
+fn prompt(message: &str, default: &str) -> Option<String> {
+    web_sys::window()
+        .and_then(|w| w.prompt_with_message_and_default(message, default).ok())
+        .flatten()
+}
+
+fn confirm(message: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.confirm_with_message(message).ok())
+        .unwrap_or(false)
+}
+
+fn tracking(branch: &BranchInfo) -> String {
+    match &branch.upstream {
+        Some(upstream) if branch.ahead == 0 && branch.behind == 0 => format!("tracks {} · up to date", upstream),
+        Some(upstream) => format!("tracks {} · {} ahead, {} behind", upstream, branch.ahead, branch.behind),
+        None => "no upstream".to_string(),
+    }
+}
+
+/// Current branch with a switcher, plus compare, create, rename, delete and
+/// upstream controls for local branches.
+#[function_component(BranchSwitcher)]
+pub fn branch_switcher() -> Html {
+    let list = use_state(|| None::<BranchListResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    let target = use_state(String::new);
+    let carry_changes = use_state(|| false);
+    let new_name = use_state(String::new);
+    let new_from = use_state(String::new);
+    let compare = use_state(|| None::<String>);
+
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_branches(&list, &status_message, false);
+            || ()
+        });
+    }
+
+    let on_fetch = {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        Callback::from(move |_| refresh_branches(&list, &status_message, true))
+    };
+
+    let on_target = {
+        let target = target.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                target.set(select.value());
+            }
+        })
+    };
+
+    let on_carry = {
+        let carry_changes = carry_changes.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                carry_changes.set(input.checked());
+            }
+        })
+    };
+
+    let on_switch = {
+        let target = target.clone();
+        let carry_changes = carry_changes.clone();
+        let reload = reload_after(&list, &status_message);
+        Callback::from(move |_| {
+            if target.is_empty() {
+                return;
+            }
+            let name = (*target).clone();
+            target.set(String::new());
+            if *carry_changes {
+                api_checkout_branch(name, true, false, Some(reload.clone()));
+                return;
+            }
+            let name_for_retry = name.clone();
+            let reload = reload.clone();
+            api_checkout_branch(name, false, false, Some(move |result: Result<GitStatus, String>| {
+                match result {
+                    // Uncommitted changes: offer to stash them and switch
+                    Ok(ref status) if !status.success && status.message.contains("uncommitted changes")
+                        && confirm(&format!("{}\n\nStash your changes and switch to {}?", status.message, name_for_retry)) =>
+                    {
+                        api_checkout_branch(name_for_retry.clone(), false, true, Some(reload.clone()));
+                    }
+                    result => reload(result),
+                }
+            }));
+        })
+    };
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let on_create = {
+        let new_name = new_name.clone();
+        let new_from = new_from.clone();
+        let reload = reload_after(&list, &status_message);
+        Callback::from(move |_| {
+            let from = Some(new_from.trim().to_string()).filter(|f| !f.is_empty());
+            let new_name = new_name.clone();
+            let reload = reload.clone();
+            api_create_branch(new_name.trim().to_string(), from, true, Some(move |result: Result<GitStatus, String>| {
+                if matches!(result, Ok(ref status) if status.success) {
+                    new_name.set(String::new());
+                }
+                reload(result);
+            }));
+        })
+    };
+
+    let Some(ref branches) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading branches..."}</p>
+            </div>
+        };
+    };
+
+    // Remote branches that already have a local branch of the same name are
+    // reached through that branch
+    let remote_only: Vec<&BranchInfo> = branches.remote.iter()
+        .filter(|remote| {
+            let short = remote.name.split_once('/').map(|(_, rest)| rest).unwrap_or(&remote.name);
+            !branches.local.iter().any(|local| local.name == short)
+        })
+        .collect();
+
+    let rows = branches.local.iter().map(|branch| {
+        let on_rename = {
+            let name = branch.name.clone();
+            let reload = reload_after(&list, &status_message);
+            Callback::from(move |_| {
+                let new_name = prompt(&format!("Rename branch {} to:", name), &name)
+                    .map(|n| n.trim().to_string())
+                    .filter(|n| !n.is_empty() && *n != name);
+                if let Some(new_name) = new_name {
+                    api_rename_branch(name.clone(), new_name, Some(reload.clone()));
+                }
+            })
+        };
+
+        let on_upstream = {
+            let name = branch.name.clone();
+            let current = branch.upstream.clone().unwrap_or_else(|| format!("origin/{}", branch.name));
+            let reload = reload_after(&list, &status_message);
+            Callback::from(move |_| {
+                if let Some(upstream) = prompt(&format!("Upstream for {} (leave empty to remove):", name), &current) {
+                    let upstream = Some(upstream.trim().to_string()).filter(|u| !u.is_empty());
+                    api_set_branch_upstream(name.clone(), upstream, Some(reload.clone()));
+                }
+            })
+        };
+
+        let on_delete = {
+            let name = branch.name.clone();
+            let reload = reload_after(&list, &status_message);
+            Callback::from(move |_| {
+                if !confirm(&format!("Delete branch {}?", name)) {
+                    return;
+                }
+                let name_for_retry = name.clone();
+                let reload = reload.clone();
+                api_delete_branch(name.clone(), false, Some(move |result: Result<GitStatus, String>| {
+                    match result {
+                        // Unmerged commits: offer to delete anyway
+                        Ok(ref status) if !status.success
+                            && confirm(&format!("{}\n\nDelete {} anyway?", status.message, name_for_retry)) =>
+                        {
+                            api_delete_branch(name_for_retry.clone(), true, Some(reload.clone()));
+                        }
+                        result => reload(result),
+                    }
+                }));
+            })
+        };
+
+        let on_compare = {
+            let compare = compare.clone();
+            let name = branch.name.clone();
+            Callback::from(move |_| {
+                compare.set(if compare.as_deref() == Some(name.as_str()) { None } else { Some(name.clone()) });
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <div class="text-sm">
+                    <code class={if branch.is_head { "font-medium text-green-600" } else { "font-medium" }}>{ &branch.name }</code>
+                    { format!(" · {}", tracking(branch)) }
+                    { if let Some(ref summary) = branch.summary {
+                        html! { <div>{ summary }</div> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+                <div class="flex gap-2">
+                    <button class="btn btn-secondary text-sm" onclick={on_compare} disabled={branch.is_head}>{"Compare"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_rename}>{"Rename"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_upstream}>{"Upstream"}</button>
+                    <button class="btn btn-danger text-sm" onclick={on_delete} disabled={branch.is_head}>{"Delete"}</button>
+                </div>
+            </div>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="space-y-4">
+            <div class="flex items-center justify-between">
+                <div class="flex items-center gap-2">
+                    <span class="text-sm font-medium">{"Current Branch:"}</span>
+                    <code class="bg-surface px-2 py-1 rounded text-sm">
+                        { branches.current.clone().unwrap_or_else(|| "detached HEAD".to_string()) }
+                    </code>
+                </div>
+                <button class="btn btn-secondary text-sm" onclick={on_fetch}>{"Fetch from origin"}</button>
+            </div>
+
+            { status_banner(&status_message) }
+
+            <div class="flex items-center gap-3 flex-wrap">
+                <select class="input" onchange={on_target}>
+                    <option value="" selected={target.is_empty()}>{"Switch to…"}</option>
+                    { for branches.local.iter().filter(|b| !b.is_head).map(|b| html! {
+                        <option value={b.name.clone()} selected={*target == b.name}>{ &b.name }</option>
+                    }) }
+                    { for remote_only.iter().map(|b| html! {
+                        <option value={b.name.clone()} selected={*target == b.name}>{ format!("{} (remote)", b.name) }</option>
+                    }) }
+                </select>
+                <input type="checkbox" id="branch-carry" checked={*carry_changes} onchange={on_carry} />
+                <label for="branch-carry" class="text-sm font-medium">{"Carry uncommitted changes"}</label>
+                <button class="btn btn-primary" onclick={on_switch} disabled={target.is_empty()}>{"Switch"}</button>
+            </div>
+
+            { rows }
+
+            { match (&branches.current, compare.as_ref()) {
+                (Some(current), Some(other)) => html! {
+                    <div class="space-y-2 p-3 border rounded">
+                        <h3 class="font-medium">{ format!("{} → {}", current, other) }</h3>
+                        <DiffView target={DiffTarget::Range { from: current.clone(), to: other.clone() }} />
+                    </div>
+                },
+                _ => html! {},
+            }}
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{"New branch"}</h3>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="redesign"
+                        value={(*new_name).clone()} onchange={text_field(&new_name)} />
+                    <input type="text" class="input" placeholder="from HEAD"
+                        value={(*new_from).clone()} onchange={text_field(&new_from)} />
+                    <button class="btn btn-primary" onclick={on_create}>{"Create & switch"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -5,7 +5,11 @@ use web_sys::{Event, HtmlInputElement};
 use yew::prelude::*;
 
 use crate::api::file::{api_delete, api_move, api_upload, get_api_file, post_api_file};
+use crate::components::blame_view::BlameView;
 use crate::components::code_editor_textarea::CodeEditorTextarea;
+use crate::components::conflict_resolver::ConflictResolver;
+use crate::components::history::HistoryView;
+use crate::components::publications::PublishForm;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
@@ -18,13 +22,18 @@ pub fn code_editor(props: &Props) -> Html {
     let text     = use_state(|| String::new());
     let sel_path = props.path.clone();
     let file_input_ref = use_node_ref();
+    let show_publish = use_state(|| false);
+    let show_history = use_state(|| false);
+    let show_blame = use_state(|| false);
+    // Bumped to reload the file after git changed it
+    let reload = use_state(|| 0u32);
 
     /* -- load file when path changes ------------------------------------ */
     {
         let text = text.clone();
-        use_effect_with(sel_path.clone(), {
+        use_effect_with((sel_path.clone(), *reload), {
             let text = text.clone();
-            move |maybe_path| {
+            move |(maybe_path, _)| {
                 if let Some(path) = maybe_path.clone() {
                     let text = text.clone();
                     spawn_local(async move {
@@ -43,6 +52,12 @@ pub fn code_editor(props: &Props) -> Html {
         });
     }
 
+    // Reload after a conflict resolution or restore rewrote the file
+    let reload_file = {
+        let reload = reload.clone();
+        Callback::from(move |_| reload.set(*reload + 1))
+    };
+
     /* -- textarea on-input ---------------------------------------------- */
     let oninput = {
         let text = text.clone();
@@ -69,6 +84,28 @@ pub fn code_editor(props: &Props) -> Html {
         })
     };
 
+    /* -- Publish at… button ---------------------------------------------- */
+    let on_toggle_publish = {
+        let show_publish = show_publish.clone();
+        Callback::from(move |_| show_publish.set(!*show_publish))
+    };
+    let on_close_publish = {
+        let show_publish = show_publish.clone();
+        Callback::from(move |_| show_publish.set(false))
+    };
+
+    /* -- History button ------------------------------------------------- */
+    let on_toggle_history = {
+        let show_history = show_history.clone();
+        Callback::from(move |_| show_history.set(!*show_history))
+    };
+
+    /* -- Blame button --------------------------------------------------- */
+    let on_toggle_blame = {
+        let show_blame = show_blame.clone();
+        Callback::from(move |_| show_blame.set(!*show_blame))
+    };
+
     /* -- Delete button -------------------------------------------------- */
     let ondelete = {
         let api_delete = api_delete.clone();
@@ -165,6 +202,9 @@ pub fn code_editor(props: &Props) -> Html {
                             html! {
                                 <>
                                     <button class="btn btn-primary" onclick={onsave.clone()}>{ "Save" }</button>
+                                    <button class="btn btn-secondary" onclick={on_toggle_publish.clone()}>{ "Publish at…" }</button>
+                                    <button class="btn btn-secondary" onclick={on_toggle_history.clone()}>{ "History" }</button>
+                                    <button class="btn btn-secondary" onclick={on_toggle_blame.clone()}>{ if *show_blame { "Edit" } else { "Blame" } }</button>
                                     <button class="btn btn-secondary" onclick={onmove.clone()}>{ "Move" }</button>
                                     <button class="btn btn-danger"  onclick={ondelete.clone()}>{ "Delete" }</button>
                                 </>
@@ -194,9 +234,44 @@ pub fn code_editor(props: &Props) -> Html {
                 }
             }
 
+            /* scheduled publish form */
+            {
+                match (&sel_path, *show_publish) {
+                    (Some(p), true) => html! {
+                        <PublishForm path={p.clone()} content={(*text).clone()} on_close={on_close_publish.clone()} />
+                    },
+                    _ => html!{}
+                }
+            }
+
+            /* history of the open file */
+            {
+                match (&sel_path, *show_history) {
+                    (Some(p), true) => html! {
+                        <div class="p-3 mb-2 border rounded">
+                            <HistoryView path={p.clone()} on_changed={reload_file.clone()} />
+                        </div>
+                    },
+                    _ => html!{}
+                }
+            }
+
+            /* merge conflict of the open file */
+            {
+                if let Some(ref p) = sel_path {
+                    html! {
+                        <ConflictResolver path={p.clone()} content={(*text).clone()} on_resolved={reload_file.clone()} />
+                    }
+                } else {
+                    html!{}
+                }
+            }
+
             /* editor pane */
             {
-                if sel_path.is_some() {
+                if let (Some(p), true) = (&sel_path, *show_blame) {
+                    html! { <BlameView path={p.clone()} revision={*reload} /> }
+                } else if sel_path.is_some() {
                     html! {
                         <CodeEditorTextarea value={(*text).clone()} oninput={oninput.clone()} />
                     }
@@ -0,0 +1,200 @@
+// frontend_simple_web/src/components/conflict_resolver.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_abort_merge, api_continue_merge, api_merge_status, api_resolve_conflict, ConflictFile,
+    GitStatus, MergeStatusResponse, Resolution,
+};
+
+/// Whether text still contains `<<<<<<<` / `>>>>>>>` conflict markers
+fn has_markers(text: &str) -> bool {
+    text.lines().any(|line| line.starts_with("<<<<<<<") || line.starts_with(">>>>>>>"))
+}
+
+fn version(label: &'static str, content: &Option<String>) -> Html {
+    html! {
+        <div class="space-y-1" style="flex: 1; min-width: 0;">
+            <div class="text-xs font-medium">{ label }</div>
+            { match content {
+                Some(text) => html! { <pre class="bg-surface p-2 rounded text-xs" style="max-height: 16rem; overflow: auto;">{ text }</pre> },
+                None => html! { <p class="text-xs">{"(deleted)"}</p> },
+            }}
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct ConflictResolverProps {
+    /// Only show this file's conflict, without the complete/abort controls
+    #[prop_or_default]
+    pub path: Option<String>,
+    /// Resolved text from the editor, offered as the merged content
+    #[prop_or_default]
+    pub content: Option<String>,
+    /// Called after a file was resolved or the merge completed or aborted
+    #[prop_or_default]
+    pub on_resolved: Callback<()>,
+}
+
+/// Conflicts of an in-progress merge with base, ours and theirs of each file,
+/// and buttons to resolve them and to complete or abort the merge. Renders
+/// nothing when no merge is in progress.
+#[function_component(ConflictResolver)]
+pub fn conflict_resolver(props: &ConflictResolverProps) -> Html {
+    let merge = use_state(|| None::<MergeStatusResponse>);
+    let status_message = use_state(|| None::<(bool, String)>);
+    let commit_message = use_state(String::new);
+    // Bumped to reload the merge status after an action
+    let reload = use_state(|| 0u32);
+
+    {
+        let merge = merge.clone();
+        let status_message = status_message.clone();
+        use_effect_with((props.path.clone(), *reload), move |_| {
+            api_merge_status(Some(move |result: Result<MergeStatusResponse, String>| {
+                match result {
+                    Ok(response) if response.success => merge.set(Some(response)),
+                    Ok(response) => status_message.set(Some((false, response.message))),
+                    Err(e) => status_message.set(Some((false, format!("Failed to load merge status: {}", e)))),
+                }
+            }));
+            || ()
+        });
+    }
+
+    let after_action = {
+        let reload = reload.clone();
+        let status_message = status_message.clone();
+        let on_resolved = props.on_resolved.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => {
+                    if status.success {
+                        on_resolved.emit(());
+                    }
+                    status_message.set(Some((status.success, status.message)));
+                }
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            reload.set(*reload + 1);
+        }
+    };
+
+    let Some(ref merge) = *merge else { return html! {} };
+    if !merge.in_progress {
+        return html! {};
+    }
+
+    let conflicts: Vec<&ConflictFile> = merge.conflicts.iter()
+        .filter(|file| props.path.as_ref().is_none_or(|path| *path == file.path))
+        .collect();
+    if props.path.is_some() && conflicts.is_empty() {
+        return html! {};
+    }
+
+    let conflict_view = |file: &ConflictFile| {
+        let resolve = |resolution: Resolution, content: Option<String>| {
+            let path = file.path.clone();
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                api_resolve_conflict(path.clone(), resolution, content.clone(), Some(after_action.clone()));
+            })
+        };
+        let use_content = match props.content {
+            Some(ref text) => {
+                let markers = has_markers(text);
+                html! {
+                    <button class="btn btn-primary text-sm" onclick={resolve(Resolution::Content, Some(text.clone()))}
+                        disabled={markers} title={if markers { "Remove the conflict markers first" } else { "" }}>
+                        {"Use editor content"}
+                    </button>
+                }
+            }
+            None => html! {},
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-2">
+                <div class="flex items-center justify-between">
+                    <code class="text-sm">{ &file.path }</code>
+                    <div class="flex gap-2">
+                        <button class="btn btn-secondary text-sm" onclick={resolve(Resolution::Ours, None)}>{"Take ours"}</button>
+                        <button class="btn btn-secondary text-sm" onclick={resolve(Resolution::Theirs, None)}>{"Take theirs"}</button>
+                        { use_content }
+                    </div>
+                </div>
+                { if file.binary {
+                    html! { <p class="text-sm">{"Binary file"}</p> }
+                } else {
+                    html! {
+                        <div class="flex gap-2">
+                            { version("Base", &file.base) }
+                            { version("Ours", &file.ours) }
+                            { version("Theirs", &file.theirs) }
+                        </div>
+                    }
+                }}
+            </div>
+        }
+    };
+
+    let controls = if props.path.is_none() {
+        let on_message_change = {
+            let commit_message = commit_message.clone();
+            Callback::from(move |e: Event| {
+                if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                    commit_message.set(input.value());
+                }
+            })
+        };
+        let on_continue = {
+            let commit_message = commit_message.clone();
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                let message = Some(commit_message.trim().to_string()).filter(|m| !m.is_empty());
+                api_continue_merge(message, Some(after_action.clone()));
+            })
+        };
+        let on_abort = {
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("Abort the merge and discard its changes?").ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_abort_merge(Some(after_action.clone()));
+                }
+            })
+        };
+        html! {
+            <div class="flex gap-2">
+                <input type="text" class="input w-full" placeholder="Merge commit message (optional)"
+                    value={(*commit_message).clone()} onchange={on_message_change} />
+                <button class="btn btn-primary" onclick={on_continue} disabled={!merge.conflicts.is_empty()}>{"Complete Merge"}</button>
+                <button class="btn btn-danger" onclick={on_abort}>{"Abort Merge"}</button>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <div class="space-y-2">
+            { if let Some((success, ref msg)) = *status_message {
+                html! { <p class={if success { "text-sm text-green-600" } else { "text-sm text-red-600" }}>{ msg }</p> }
+            } else {
+                html! {}
+            }}
+            <p class="text-sm text-orange-600">{ if props.path.is_some() {
+                "This file has merge conflicts.".to_string()
+            } else if merge.conflicts.is_empty() {
+                "All conflicts are resolved; complete the merge to commit it.".to_string()
+            } else {
+                merge.message.clone()
+            }}</p>
+            { for conflicts.into_iter().map(conflict_view) }
+            { controls }
+        </div>
+    }
+}
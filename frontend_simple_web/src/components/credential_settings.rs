@@ -0,0 +1,215 @@
+// frontend_simple_web/src/components/credential_settings.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_delete_credentials, api_list_credentials, api_save_credentials, CredentialListResponse,
+    CredentialProfile, GitStatus,
+};
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_profiles(list: &UseStateHandle<Option<CredentialListResponse>>, status_message: &StatusMsg) {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    api_list_credentials(Some(move |result: Result<CredentialListResponse, String>| {
+        match result {
+            Ok(response) if response.success => list.set(Some(response)),
+            Ok(response) => status_message.set(Some((false, response.message))),
+            Err(e) => status_message.set(Some((false, format!("Failed to load credentials: {}", e)))),
+        }
+    }));
+}
+
+fn reload_after(list: &UseStateHandle<Option<CredentialListResponse>>, status_message: &StatusMsg) -> impl Fn(Result<GitStatus, String>) + Clone + 'static {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    move |result: Result<GitStatus, String>| {
+        match result {
+            Ok(status) => status_message.set(Some((status.success, status.message))),
+            Err(e) => status_message.set(Some((false, e))),
+        }
+        refresh_profiles(&list, &status_message);
+    }
+}
+
+/// Named username/token profiles for HTTPS remotes, each used for the remotes
+/// on its host. Tokens are write-only; only a masked hint is shown.
+#[function_component(CredentialSettings)]
+pub fn credential_settings() -> Html {
+    let list = use_state(|| None::<CredentialListResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    // Profile being edited, `None` for a new one
+    let editing = use_state(|| None::<String>);
+    let name = use_state(String::new);
+    let host = use_state(String::new);
+    let username = use_state(String::new);
+    let token = use_state(String::new);
+
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_profiles(&list, &status_message);
+            || ()
+        });
+    }
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let clear_form = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let host = host.clone();
+        let username = username.clone();
+        let token = token.clone();
+        move || {
+            editing.set(None);
+            name.set(String::new());
+            host.set(String::new());
+            username.set(String::new());
+            token.set(String::new());
+        }
+    };
+
+    let on_save = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let host = host.clone();
+        let username = username.clone();
+        let token = token.clone();
+        let clear_form = clear_form.clone();
+        let reload = reload_after(&list, &status_message);
+        Callback::from(move |_| {
+            let new_token = Some(token.trim().to_string()).filter(|t| !t.is_empty());
+            let clear_form = clear_form.clone();
+            let reload = reload.clone();
+            api_save_credentials((*editing).clone(), name.trim().to_string(), host.trim().to_string(),
+                username.trim().to_string(), new_token, Some(move |result: Result<GitStatus, String>| {
+                    if matches!(result, Ok(ref status) if status.success) {
+                        clear_form();
+                    }
+                    reload(result);
+                }));
+        })
+    };
+
+    let on_cancel_edit = {
+        let clear_form = clear_form.clone();
+        Callback::from(move |_| clear_form())
+    };
+
+    let Some(ref profiles) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading credentials..."}</p>
+            </div>
+        };
+    };
+
+    let row = |profile: &CredentialProfile| {
+        let on_edit = {
+            let editing = editing.clone();
+            let name = name.clone();
+            let host = host.clone();
+            let username = username.clone();
+            let token = token.clone();
+            let profile = profile.clone();
+            Callback::from(move |_| {
+                editing.set(Some(profile.id.clone()));
+                name.set(profile.name.clone());
+                host.set(profile.host.clone());
+                username.set(profile.username.clone());
+                token.set(String::new());
+            })
+        };
+        let on_delete = {
+            let id = profile.id.clone();
+            let label = profile.name.clone();
+            let reload = reload_after(&list, &status_message);
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Delete credentials \"{}\"?", label)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_delete_credentials(&id, Some(reload.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <span class="text-sm">
+                    { &profile.name }
+                    { format!(" · {} · {}", if profile.host.is_empty() { "any host" } else { &profile.host }, profile.username) }
+                    <code class="text-xs">{ if profile.token_set { format!(" {}", profile.token_hint) } else { " no token".to_string() } }</code>
+                </span>
+                <div class="flex gap-2">
+                    <button class="btn btn-secondary text-sm" onclick={on_edit}>{"Edit"}</button>
+                    <button class="btn btn-danger text-sm" onclick={on_delete}>{"Delete"}</button>
+                </div>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+
+            { if profiles.profiles.is_empty() {
+                html! { <p class="text-sm">{"No saved credentials."}</p> }
+            } else {
+                html! { for profiles.profiles.iter().map(row) }
+            }}
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{ if editing.is_some() { "Edit credentials" } else { "New credentials" } }</h3>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Name"
+                        value={(*name).clone()} onchange={text_field(&name)} />
+                    <input type="text" class="input" placeholder="Host, e.g. github.com (empty for any)"
+                        value={(*host).clone()} onchange={text_field(&host)} />
+                </div>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Username"
+                        value={(*username).clone()} onchange={text_field(&username)} />
+                    <input type="password" class="input"
+                        placeholder={if editing.is_some() { "New token (empty keeps the current one)" } else { "Token" }}
+                        value={(*token).clone()} onchange={text_field(&token)} />
+                    <button class="btn btn-primary" onclick={on_save}>{"Save"}</button>
+                    { if editing.is_some() {
+                        html! { <button class="btn btn-secondary" onclick={on_cancel_edit}>{"Cancel"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,301 @@
+// frontend_simple_web/src/components/diff_view.rs
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_git_diff, api_stage_hunk, api_stage_paths, api_unstage_paths, DiffFile, DiffHunk, DiffLine,
+    DiffResponse, DiffTarget, GitStatus,
+};
+
+fn line_class(kind: &str) -> &'static str {
+    match kind {
+        "addition" => "diff-add",
+        "deletion" => "diff-del",
+        _ => "",
+    }
+}
+
+fn line_sign(kind: &str) -> &'static str {
+    match kind {
+        "addition" => "+",
+        "deletion" => "-",
+        _ => " ",
+    }
+}
+
+fn lineno(number: Option<u32>) -> String {
+    number.map(|n| n.to_string()).unwrap_or_default()
+}
+
+fn status_class(status: &str) -> &'static str {
+    match status {
+        "added" | "untracked" => "text-green-600",
+        "deleted" => "text-red-600",
+        _ => "text-orange-600",
+    }
+}
+
+fn hunk_header(hunk: &DiffHunk, columns: &'static str, action: Html) -> Html {
+    html! {
+        <tr class="diff-hunk">
+            <td colspan={columns}>
+                <div class="flex items-center justify-between">
+                    <span>{ &hunk.header }</span>
+                    { action }
+                </div>
+            </td>
+        </tr>
+    }
+}
+
+fn inline_hunk(hunk: &DiffHunk, action: Html) -> Html {
+    html! {
+        <>
+            { hunk_header(hunk, "3", action) }
+            { for hunk.lines.iter().map(|line| html! {
+                <tr class={line_class(&line.kind)}>
+                    <td class="diff-lineno">{ lineno(line.old_lineno) }</td>
+                    <td class="diff-lineno">{ lineno(line.new_lineno) }</td>
+                    <td>{ format!("{}{}", line_sign(&line.kind), line.content) }</td>
+                </tr>
+            }) }
+        </>
+    }
+}
+
+/// Rows of a side-by-side hunk: runs of deletions are paired with the
+/// additions that follow them, context lines appear on both sides.
+fn side_by_side_rows(lines: &[DiffLine]) -> Vec<(Option<&DiffLine>, Option<&DiffLine>)> {
+    let mut rows = Vec::new();
+    let mut deletions: Vec<&DiffLine> = Vec::new();
+    let mut additions: Vec<&DiffLine> = Vec::new();
+
+    fn flush<'a>(
+        rows: &mut Vec<(Option<&'a DiffLine>, Option<&'a DiffLine>)>,
+        deletions: &mut Vec<&'a DiffLine>,
+        additions: &mut Vec<&'a DiffLine>,
+    ) {
+        for i in 0..deletions.len().max(additions.len()) {
+            rows.push((deletions.get(i).copied(), additions.get(i).copied()));
+        }
+        deletions.clear();
+        additions.clear();
+    }
+
+    for line in lines {
+        match line.kind.as_str() {
+            "deletion" => {
+                // A deletion after additions starts a new change block
+                if !additions.is_empty() {
+                    flush(&mut rows, &mut deletions, &mut additions);
+                }
+                deletions.push(line);
+            }
+            "addition" => additions.push(line),
+            _ => {
+                flush(&mut rows, &mut deletions, &mut additions);
+                rows.push((Some(line), Some(line)));
+            }
+        }
+    }
+    flush(&mut rows, &mut deletions, &mut additions);
+    rows
+}
+
+fn side_by_side_hunk(hunk: &DiffHunk, action: Html) -> Html {
+    let cells = |line: Option<&DiffLine>, old: bool| match line {
+        Some(line) => {
+            let number = if old { line.old_lineno } else { line.new_lineno };
+            html! {
+                <>
+                    <td class={classes!("diff-lineno", line_class(&line.kind))}>{ lineno(number) }</td>
+                    <td class={line_class(&line.kind)}>{ &line.content }</td>
+                </>
+            }
+        }
+        None => html! { <><td class="diff-lineno"></td><td></td></> },
+    };
+
+    html! {
+        <>
+            { hunk_header(hunk, "4", action) }
+            { for side_by_side_rows(&hunk.lines).into_iter().map(|(old, new)| html! {
+                <tr>{ cells(old, true) }{ cells(new, false) }</tr>
+            }) }
+        </>
+    }
+}
+
+/// One file of the diff. `file_action` and `hunk_action` render the staging
+/// buttons, if any.
+fn file_view(file: &DiffFile, side_by_side: bool, file_action: Html, hunk_action: impl Fn(usize, &DiffHunk) -> Html) -> Html {
+    let body = if file.binary {
+        html! { <p class="text-sm">{"Binary file"}</p> }
+    } else if file.hunks.is_empty() {
+        html! { <p class="text-sm">{"No content changes"}</p> }
+    } else {
+        html! {
+            <table class="diff-table">
+                { for file.hunks.iter().enumerate().map(|(idx, hunk)| {
+                    let action = hunk_action(idx, hunk);
+                    if side_by_side { side_by_side_hunk(hunk, action) } else { inline_hunk(hunk, action) }
+                }) }
+            </table>
+        }
+    };
+
+    html! {
+        <div class="bg-surface p-2 rounded space-y-1">
+            <div class="flex items-center gap-2 text-sm">
+                <span class={status_class(&file.status)}>{ &file.status }</span>
+                <code>{ file.display_path() }</code>
+                <span class="text-green-600">{ format!("+{}", file.insertions) }</span>
+                <span class="text-red-600">{ format!("−{}", file.deletions) }</span>
+                { file_action }
+            </div>
+            { body }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct DiffViewProps {
+    pub target: DiffTarget,
+    /// Only show changes to this file or directory
+    #[prop_or_default]
+    pub path: Option<String>,
+    /// Offer stage/unstage buttons for files and hunks (working tree and
+    /// staged targets only)
+    #[prop_or_default]
+    pub staging: bool,
+    /// Called after the index changed
+    #[prop_or_default]
+    pub on_staged: Callback<()>,
+}
+
+/// Diff of the working tree, index, a commit or two refs, shown inline or
+/// side by side, optionally with buttons to stage or unstage files and hunks.
+#[function_component(DiffView)]
+pub fn diff_view(props: &DiffViewProps) -> Html {
+    let diff = use_state(|| None::<DiffResponse>);
+    let error = use_state(|| None::<String>);
+    let staging_error = use_state(|| None::<String>);
+    let side_by_side = use_state(|| false);
+    // Bumped to reload the diff after staging
+    let reload = use_state(|| 0u32);
+
+    {
+        let diff = diff.clone();
+        let error = error.clone();
+        use_effect_with((props.target.clone(), props.path.clone(), *reload), move |(target, path, _)| {
+            api_git_diff(target, path.as_deref(), Some(move |result: Result<DiffResponse, String>| {
+                match result {
+                    Ok(response) if response.success => {
+                        diff.set(Some(response));
+                        error.set(None);
+                    }
+                    Ok(response) => error.set(Some(response.message)),
+                    Err(e) => error.set(Some(format!("Failed to load diff: {}", e))),
+                }
+            }));
+            || ()
+        });
+    }
+
+    let on_toggle = {
+        let side_by_side = side_by_side.clone();
+        Callback::from(move |_| side_by_side.set(!*side_by_side))
+    };
+
+    // Stage from the working tree, unstage from the index
+    let unstage = match props.target {
+        DiffTarget::WorkingTree if props.staging => Some(false),
+        DiffTarget::Staged if props.staging => Some(true),
+        _ => None,
+    };
+    let after_staging = {
+        let reload = reload.clone();
+        let staging_error = staging_error.clone();
+        let on_staged = props.on_staged.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) if status.success => {
+                    staging_error.set(None);
+                    reload.set(*reload + 1);
+                    on_staged.emit(());
+                }
+                Ok(status) => staging_error.set(Some(status.message)),
+                Err(e) => staging_error.set(Some(e)),
+            }
+        }
+    };
+    let file_action = |file: &DiffFile| -> Html {
+        let Some(unstage) = unstage else { return html! {} };
+        // Both sides of a rename change in the index
+        let paths: Vec<String> = file.old_path.iter().chain(file.new_path.iter()).cloned().collect();
+        let after_staging = after_staging.clone();
+        let onclick = Callback::from(move |_| {
+            if unstage {
+                api_unstage_paths(paths.clone(), Some(after_staging.clone()));
+            } else {
+                api_stage_paths(paths.clone(), Some(after_staging.clone()));
+            }
+        });
+        html! {
+            <button class="btn btn-secondary text-sm" {onclick}>{ if unstage { "Unstage file" } else { "Stage file" } }</button>
+        }
+    };
+    let hunk_action = |file: &DiffFile| {
+        let path = file.new_path.clone().or_else(|| file.old_path.clone()).unwrap_or_default();
+        let after_staging = after_staging.clone();
+        move |idx: usize, hunk: &DiffHunk| -> Html {
+            let Some(unstage) = unstage else { return html! {} };
+            let path = path.clone();
+            let header = hunk.header.clone();
+            let after_staging = after_staging.clone();
+            let onclick = Callback::from(move |_| {
+                api_stage_hunk(path.clone(), idx, header.clone(), unstage, Some(after_staging.clone()));
+            });
+            html! {
+                <button class="btn btn-secondary text-sm" {onclick}>{ if unstage { "Unstage hunk" } else { "Stage hunk" } }</button>
+            }
+        }
+    };
+
+    let content = if let Some(ref message) = *error {
+        html! { <p class="text-sm text-red-600">{ message }</p> }
+    } else if let Some(ref diff) = *diff {
+        if diff.files.is_empty() {
+            html! { <p class="text-sm">{"No changes."}</p> }
+        } else {
+            html! {
+                <>
+                    { for diff.files.iter().map(|file| file_view(file, *side_by_side, file_action(file), hunk_action(file))) }
+                    { if diff.truncated {
+                        html! { <p class="text-sm">{"The diff is too large; some changes are not shown."}</p> }
+                    } else {
+                        html! {}
+                    }}
+                </>
+            }
+        }
+    } else {
+        html! { <p class="text-sm">{"Loading diff..."}</p> }
+    };
+
+    html! {
+        <div class="space-y-2">
+            <div class="flex items-center justify-between">
+                <span class="text-sm">{ diff.as_ref().map(|d| d.message.clone()).unwrap_or_default() }</span>
+                <button class="btn btn-secondary text-sm" onclick={on_toggle}>
+                    { if *side_by_side { "Inline" } else { "Side by side" } }
+                </button>
+            </div>
+            { if let Some(ref message) = *staging_error {
+                html! { <p class="text-sm text-red-600">{ message }</p> }
+            } else {
+                html! {}
+            }}
+            { content }
+        </div>
+    }
+}
@@ -14,6 +14,8 @@ use crate::api::file::{api_delete, get_api_files, api_move};
 pub struct FileEntry {
     pub path: String,
     pub is_dir: bool,
+    #[serde(default)]
+    pub is_submodule: bool,
 }
 
 #[derive(Properties, PartialEq)]
@@ -415,6 +417,11 @@ pub fn file_browser(props: &Props) -> Html {
                             <div class="flex items-center gap-2">
                                 <input type="checkbox" checked={is_selected} onchange={on_select} onclick={Callback::from(|e: MouseEvent| e.stop_propagation())} />
                                 <span>{ icon_html }{ get_file_name(&entry) }</span>
+                                { if entry.is_submodule {
+                                    html! { <span class="text-xs text-gray-500" title="Git submodule, updated on pull">{"submodule"}</span> }
+                                } else {
+                                    html! {}
+                                }}
                             </div>
                             { del_btn }
                         </li>
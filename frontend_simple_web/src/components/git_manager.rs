@@ -1,8 +1,8 @@
 // frontend_simple_web/src/components/git_manager.rs
 use yew::prelude::*;
 
-use crate::api::git::{api_git_setup, api_git_pull, GitStatus};
-use crate::hooks::{use_git_settings, use_async_action, input_callback};
+use crate::api::git::{run_git_task, GitStatus, PullMode, TaskRequest};
+use crate::hooks::{use_git_settings, use_async_action, input_callback, secret_input_callback};
 
 #[function_component(GitManager)]
 pub fn git_manager() -> Html {
@@ -25,19 +25,19 @@ pub fn git_manager() -> Html {
                 }
                 cb(result);
             };
-            api_git_setup(config, Some(inner_cb));
+            run_git_task(TaskRequest::Clone(config), inner_cb);
         })
     };
 
     let pull_action = use_async_action(status_message.clone(), |cb| {
-        api_git_pull(Some(move |result: Result<GitStatus, String>| {
+        run_git_task(TaskRequest::Pull { mode: PullMode::FastForward, stash: false, remote: None }, move |result: Result<GitStatus, String>| {
             if let Ok(ref s) = result {
                 if s.success {
                     let _ = web_sys::window().map(|w| w.location().reload());
                 }
             }
             cb(result);
-        }));
+        });
     });
 
     let toggle_form = {
@@ -50,7 +50,7 @@ pub fn git_manager() -> Html {
     let on_url_change = input_callback(git.repo_url.clone(), "git_repo_url");
     let on_branch_change = input_callback(git.branch.clone(), "git_branch");
     let on_username_change = input_callback(git.username.clone(), "git_username");
-    let on_token_change = input_callback(git.token.clone(), "git_token");
+    let on_token_change = secret_input_callback(git.token.clone());
 
     let is_loading = *setup_action.is_loading || *pull_action.is_loading;
 
@@ -133,7 +133,7 @@ pub fn git_manager() -> Html {
                             <input
                                 type="password"
                                 class="input w-full text-sm"
-                                placeholder="ghp_..."
+                                placeholder="ghp_... (leave empty to use the saved credentials)"
                                 value={(*git.token).clone()}
                                 onchange={on_token_change}
                             />
@@ -0,0 +1,122 @@
+// frontend_simple_web/src/components/git_tasks.rs
+use gloo::timers::callback::Interval;
+use yew::prelude::*;
+
+use crate::api::git::{api_cancel_git_task, api_list_git_tasks, GitStatus, GitTask, TaskListResponse};
+
+/// How many finished tasks stay listed
+const SHOWN_FINISHED: usize = 3;
+
+fn refresh_tasks(tasks: &UseStateHandle<Vec<GitTask>>) {
+    let tasks = tasks.clone();
+    api_list_git_tasks(Some(move |result: Result<TaskListResponse, String>| {
+        if let Ok(response) = result.map(|r| r.tasks) {
+            tasks.set(response);
+        }
+    }));
+}
+
+fn format_bytes(bytes: usize) -> String {
+    match bytes {
+        b if b >= 1 << 20 => format!("{:.1} MiB", b as f64 / (1 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.1} KiB", b as f64 / (1 << 10) as f64),
+        b => format!("{} B", b),
+    }
+}
+
+fn operation_label(operation: &str) -> &str {
+    match operation {
+        "clone" => "Clone",
+        "pull" => "Pull",
+        "force_pull" => "Force pull",
+        "push" => "Push",
+        other => other,
+    }
+}
+
+fn progress_bar(task: &GitTask) -> Html {
+    let progress = &task.progress;
+    let (width, label) = match (&progress.stage, progress.total) {
+        (Some(stage), total) if total > 0 => (
+            progress.current * 100 / total,
+            format!("{} {}/{} objects · {}", stage, progress.current, total, format_bytes(progress.bytes)),
+        ),
+        (Some(stage), _) => (100, stage.clone()),
+        (None, _) if task.state == "queued" => (0, "Waiting for the running task".to_string()),
+        (None, _) => (100, "Connecting...".to_string()),
+    };
+    html! {
+        <div class="space-y-1">
+            <div class={classes!("task-progress", (progress.total == 0).then_some("task-progress-unknown"))}>
+                <div class="task-progress-bar" style={format!("width: {}%", width.min(100))}></div>
+            </div>
+            <div class="text-xs">{ label }</div>
+        </div>
+    }
+}
+
+/// Background clones, pulls and pushes: progress of the running and queued
+/// ones with a way to cancel them, and the outcome of the last few.
+#[function_component(GitTasks)]
+pub fn git_tasks() -> Html {
+    let tasks = use_state(Vec::<GitTask>::new);
+
+    {
+        let tasks = tasks.clone();
+        use_effect_with((), move |_| {
+            refresh_tasks(&tasks);
+            let timer = Interval::new(1000, move || refresh_tasks(&tasks));
+            move || drop(timer)
+        });
+    }
+
+    if tasks.is_empty() {
+        return html! {};
+    }
+
+    let row = |task: &GitTask| {
+        let on_cancel = {
+            let id = task.id.clone();
+            let tasks = tasks.clone();
+            Callback::from(move |_| {
+                let tasks = tasks.clone();
+                api_cancel_git_task(&id, Some(move |_: Result<GitStatus, String>| refresh_tasks(&tasks)));
+            })
+        };
+        let outcome = match task.state.as_str() {
+            "succeeded" => "text-green-600",
+            "failed" => "text-red-600",
+            _ => "text-gray-500",
+        };
+        html! {
+            <div class="bg-surface p-2 rounded space-y-1">
+                <div class="flex items-center justify-between text-sm">
+                    <span>
+                        <span class="font-medium">{ operation_label(&task.operation) }</span>
+                        <span class={outcome}>{ format!(" · {}", task.state) }</span>
+                    </span>
+                    { if task.is_finished() {
+                        html! {}
+                    } else {
+                        html! { <button class="btn btn-danger text-sm" onclick={on_cancel}>{"Cancel"}</button> }
+                    }}
+                </div>
+                { if task.is_finished() {
+                    html! { <div class="text-xs">{ task.message.clone().unwrap_or_default() }</div> }
+                } else {
+                    progress_bar(task)
+                }}
+            </div>
+        }
+    };
+
+    let active = tasks.iter().filter(|task| !task.is_finished());
+    let finished = tasks.iter().filter(|task| task.is_finished()).take(SHOWN_FINISHED);
+
+    html! {
+        <div class="space-y-2 mt-4">
+            <h3 class="font-medium">{"Background tasks"}</h3>
+            { for active.chain(finished).map(row) }
+        </div>
+    }
+}
@@ -0,0 +1,345 @@
+// frontend_simple_web/src/components/history.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_create_tag, api_git_log, api_reset_branch, api_restore_path, api_revert_commit, DiffTarget,
+    GitStatus, LogEntry, LogQuery, LogResponse, ResetMode,
+};
+use crate::components::diff_view::DiffView;
+use crate::components::identity_settings::selected_author;
+use crate::components::job_manager::format_timestamp;
+
+/// Commits fetched per page
+const PAGE_SIZE: usize = 30;
+
+/// Parse a `date` input value (browser local midnight) into a unix timestamp.
+fn parse_date(value: &str) -> Option<i64> {
+    if value.is_empty() {
+        return None;
+    }
+    let date = web_sys::js_sys::Date::new(&wasm_bindgen::JsValue::from_str(&format!("{}T00:00", value)));
+    let millis = date.get_time();
+    (!millis.is_nan()).then(|| (millis / 1000.0) as i64)
+}
+
+/// Load a page of history. The first page replaces the list, later ones append.
+fn load_page(
+    query: &LogQuery,
+    entries: &UseStateHandle<Vec<LogEntry>>,
+    has_more: &UseStateHandle<bool>,
+    error: &UseStateHandle<Option<String>>,
+    skip: usize,
+) {
+    let entries = entries.clone();
+    let has_more = has_more.clone();
+    let error = error.clone();
+    api_git_log(query, skip, PAGE_SIZE, Some(move |result: Result<LogResponse, String>| {
+        match result {
+            Ok(log) if log.success => {
+                let mut list = if skip == 0 { Vec::new() } else { (*entries).clone() };
+                list.extend(log.entries);
+                entries.set(list);
+                has_more.set(log.has_more);
+                error.set(None);
+            }
+            Ok(log) => error.set(Some(log.message)),
+            Err(e) => error.set(Some(format!("Failed to load history: {}", e))),
+        }
+    }));
+}
+
+fn confirm(message: &str) -> bool {
+    web_sys::window()
+        .and_then(|w| w.confirm_with_message(message).ok())
+        .unwrap_or(false)
+}
+
+/// Ask how to reset the branch to `short_hash`; `None` when cancelled.
+fn ask_reset_mode(short_hash: &str) -> Option<ResetMode> {
+    let answer = web_sys::window()?
+        .prompt_with_message_and_default(&format!(
+            "Reset the branch to {}?\n\nsoft: keep changes staged\nmixed: keep changes unstaged\nhard: discard all changes\n\nMode:",
+            short_hash
+        ), "mixed")
+        .ok()??;
+    let mode = match answer.trim().to_lowercase().as_str() {
+        "soft" => ResetMode::Soft,
+        "mixed" => ResetMode::Mixed,
+        "hard" => ResetMode::Hard,
+        _ => return None,
+    };
+    let warning = match mode {
+        ResetMode::Hard => format!("Hard reset to {} discards every uncommitted change and drops later commits from the branch. Continue?", short_hash),
+        _ => format!("Commits after {} will be dropped from the branch. Continue?", short_hash),
+    };
+    confirm(&warning).then_some(mode)
+}
+
+fn file_status_class(status: &str) -> &'static str {
+    match status {
+        "added" => "text-green-600",
+        "deleted" => "text-red-600",
+        _ => "text-orange-600",
+    }
+}
+
+#[derive(Properties, PartialEq)]
+pub struct HistoryViewProps {
+    /// Only show commits that changed this file or directory
+    #[prop_or_default]
+    pub path: Option<String>,
+    /// Called after a revert, reset or restore changed the working tree
+    #[prop_or_default]
+    pub on_changed: Callback<()>,
+}
+
+/// Commit history with author, date and message filters. Commits expand to
+/// show the files they changed and their diff.
+#[function_component(HistoryView)]
+pub fn history_view(props: &HistoryViewProps) -> Html {
+    let query = use_state(|| LogQuery { path: props.path.clone(), ..LogQuery::default() });
+    let entries = use_state(Vec::<LogEntry>::new);
+    let has_more = use_state(|| false);
+    let error = use_state(|| None::<String>);
+    let expanded = use_state(|| None::<String>);
+    let show_diff = use_state(|| false);
+    let action_message = use_state(|| None::<(bool, String)>);
+
+    // Follow the file open in the editor
+    {
+        let query = query.clone();
+        use_effect_with(props.path.clone(), move |path| {
+            if query.path != *path {
+                query.set(LogQuery { path: path.clone(), ..(*query).clone() });
+            }
+            || ()
+        });
+    }
+
+    {
+        let entries = entries.clone();
+        let has_more = has_more.clone();
+        let error = error.clone();
+        use_effect_with((*query).clone(), move |query| {
+            load_page(query, &entries, &has_more, &error, 0);
+            || ()
+        });
+    }
+
+    let filter_field = |apply: fn(&mut LogQuery, String)| {
+        let query = query.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                let mut updated = (*query).clone();
+                apply(&mut updated, input.value());
+                query.set(updated);
+            }
+        })
+    };
+
+    let on_more = {
+        let query = query.clone();
+        let entries = entries.clone();
+        let has_more = has_more.clone();
+        let error = error.clone();
+        Callback::from(move |_| load_page(&query, &entries, &has_more, &error, entries.len()))
+    };
+
+    // Report the outcome and reload, since a revert or reset changes the log
+    let after_action = {
+        let query = query.clone();
+        let entries = entries.clone();
+        let has_more = has_more.clone();
+        let error = error.clone();
+        let action_message = action_message.clone();
+        let on_changed = props.on_changed.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => {
+                    if status.success {
+                        on_changed.emit(());
+                    }
+                    action_message.set(Some((status.success, status.message)));
+                }
+                Err(e) => action_message.set(Some((false, e))),
+            }
+            load_page(&query, &entries, &has_more, &error, 0);
+        }
+    };
+
+    let rows = entries.iter().map(|entry| {
+        let is_expanded = expanded.as_deref() == Some(entry.hash.as_str());
+        let on_toggle = {
+            let expanded = expanded.clone();
+            let show_diff = show_diff.clone();
+            let hash = entry.hash.clone();
+            Callback::from(move |_| {
+                expanded.set(if is_expanded { None } else { Some(hash.clone()) });
+                show_diff.set(false);
+            })
+        };
+        let on_diff = {
+            let show_diff = show_diff.clone();
+            Callback::from(move |_| show_diff.set(!*show_diff))
+        };
+        let merge = if entry.parents.len() > 1 { " · merge" } else { "" };
+        let on_revert = {
+            let hash = entry.hash.clone();
+            let label = format!("{} \"{}\"", entry.short_hash, entry.summary());
+            // Merges are reverted against the branch they were merged into
+            let mainline = (entry.parents.len() > 1).then_some(1);
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                if confirm(&format!("Revert {}? A new commit undoing it will be created.", label)) {
+                    api_revert_commit(hash.clone(), mainline, selected_author(), Some(after_action.clone()));
+                }
+            })
+        };
+        let on_reset = {
+            let hash = entry.hash.clone();
+            let short_hash = entry.short_hash.clone();
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                if let Some(mode) = ask_reset_mode(&short_hash) {
+                    api_reset_branch(hash.clone(), mode, Some(after_action.clone()));
+                }
+            })
+        };
+        let on_tag = {
+            let hash = entry.hash.clone();
+            let short_hash = entry.short_hash.clone();
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                let name = web_sys::window()
+                    .and_then(|w| w.prompt_with_message(&format!("Tag {} as:", short_hash)).ok().flatten())
+                    .map(|name| name.trim().to_string())
+                    .filter(|name| !name.is_empty());
+                if let Some(name) = name {
+                    api_create_tag(name, Some(hash.clone()), None, selected_author(), Some(after_action.clone()));
+                }
+            })
+        };
+        // A file deleted by this commit is restored from the commit before it
+        let restore = |path: &str, status: &str| {
+            let commit = if status == "deleted" { format!("{}^", entry.hash) } else { entry.hash.clone() };
+            let label = format!("Restore {} as of {}? Local changes to it are overwritten.", path, entry.short_hash);
+            let path = path.to_string();
+            let after_action = after_action.clone();
+            Callback::from(move |_| {
+                if confirm(&label) {
+                    api_restore_path(commit.clone(), path.clone(), Some(after_action.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-1">
+                <div class="flex items-center justify-between gap-2 cursor-pointer" onclick={on_toggle}>
+                    <div class="text-sm">
+                        <code>{ &entry.short_hash }</code>
+                        { for entry.tags.iter().map(|tag| html! {
+                            <span class="text-xs font-medium ml-1 px-1 rounded border">{ tag }</span>
+                        }) }
+                        <span class="font-medium">{ format!(" {}", entry.summary()) }</span>
+                    </div>
+                    <div class="text-sm">
+                        <span class="text-green-600">{ format!("+{}", entry.stats.insertions) }</span>
+                        <span class="text-red-600">{ format!(" −{}", entry.stats.deletions) }</span>
+                    </div>
+                </div>
+                <div class="text-sm">
+                    { format!("{} <{}> · {} · {} files{}",
+                        entry.author.name, entry.author.email,
+                        format_timestamp(Some(entry.date.max(0) as u64)),
+                        entry.stats.files_changed, merge) }
+                </div>
+                { if is_expanded {
+                    html! {
+                        <div class="space-y-1">
+                            { if entry.message.trim() != entry.summary() {
+                                html! { <pre class="text-sm">{ entry.message.trim() }</pre> }
+                            } else {
+                                html! {}
+                            }}
+                            { for entry.files.iter().map(|file| html! {
+                                <div class="flex items-center gap-2 text-xs">
+                                    <span class={file_status_class(&file.status)}>{ &file.status }</span>
+                                    <code class="text-xs">{ &file.path }</code>
+                                    <span>{ format!("+{} −{}", file.insertions, file.deletions) }</span>
+                                    <button class="btn btn-secondary text-xs" onclick={restore(&file.path, &file.status)}>
+                                        { if file.status == "deleted" { "Recover" } else { "Restore" } }
+                                    </button>
+                                </div>
+                            }) }
+                            { if entry.files.len() < entry.stats.files_changed {
+                                html! { <div class="text-xs">{ format!("…and {} more files", entry.stats.files_changed - entry.files.len()) }</div> }
+                            } else {
+                                html! {}
+                            }}
+                            <div class="flex gap-2 flex-wrap">
+                                <button class="btn btn-secondary text-sm" onclick={on_diff}>
+                                    { if *show_diff { "Hide diff" } else { "Show diff" } }
+                                </button>
+                                { if let Some(ref path) = props.path {
+                                    let status = entry.files.iter().find(|file| file.path == *path).map(|file| file.status.as_str());
+                                    html! {
+                                        <button class="btn btn-secondary text-sm" onclick={restore(path, status.unwrap_or_default())}>{"Restore this version"}</button>
+                                    }
+                                } else {
+                                    html! {}
+                                }}
+                                <button class="btn btn-secondary text-sm" onclick={on_tag}>{"Tag"}</button>
+                                <button class="btn btn-secondary text-sm" onclick={on_revert}>{"Revert"}</button>
+                                <button class="btn btn-danger text-sm" onclick={on_reset}>{"Reset branch here"}</button>
+                            </div>
+                            { if *show_diff {
+                                html! { <DiffView target={DiffTarget::Commit(entry.hash.clone())} path={props.path.clone()} /> }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="space-y-3">
+            <div class="flex gap-3 flex-wrap">
+                <input type="text" class="input" placeholder="Author"
+                    value={query.author.clone()} onchange={filter_field(|q, v| q.author = v)} />
+                <input type="text" class="input" placeholder="Search messages"
+                    value={query.search.clone()} onchange={filter_field(|q, v| q.search = v)} />
+                <input type="date" class="input" title="Since"
+                    onchange={filter_field(|q, v| q.since = parse_date(&v))} />
+                <input type="date" class="input" title="Until"
+                    // Include the whole day
+                    onchange={filter_field(|q, v| q.until = parse_date(&v).map(|ts| ts + 86_399))} />
+            </div>
+
+            { if let Some((success, ref message)) = *action_message {
+                html! { <p class={if success { "text-sm text-green-600" } else { "text-sm text-red-600" }}>{ message }</p> }
+            } else {
+                html! {}
+            }}
+
+            { if let Some(ref message) = *error {
+                html! { <p class="text-sm text-red-600">{ message }</p> }
+            } else if entries.is_empty() {
+                html! { <p class="text-sm">{"No commits found."}</p> }
+            } else {
+                rows
+            }}
+
+            { if *has_more {
+                html! { <button class="btn btn-secondary text-sm" onclick={on_more}>{"Load more"}</button> }
+            } else {
+                html! {}
+            }}
+        </div>
+    }
+}
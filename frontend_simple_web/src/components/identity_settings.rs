@@ -0,0 +1,283 @@
+// frontend_simple_web/src/components/identity_settings.rs
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_delete_author, api_get_identity, api_save_author, api_set_committer, GitStatus, Identity,
+    IdentityResponse,
+};
+
+/// localStorage key of the author profile this browser commits as
+const AUTHOR_KEY: &str = "git_author_id";
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+/// Author profile id picked in this browser, if any
+pub fn selected_author() -> Option<String> {
+    LocalStorage::get::<String>(AUTHOR_KEY).ok().filter(|id| !id.is_empty())
+}
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_identity(identity: &UseStateHandle<Option<IdentityResponse>>, status_message: &StatusMsg) {
+    let identity = identity.clone();
+    let status_message = status_message.clone();
+    api_get_identity(Some(move |result: Result<IdentityResponse, String>| {
+        match result {
+            Ok(response) => identity.set(Some(response)),
+            Err(e) => status_message.set(Some((false, format!("Failed to load identities: {}", e)))),
+        }
+    }));
+}
+
+fn reload_after(identity: &UseStateHandle<Option<IdentityResponse>>, status_message: &StatusMsg) -> impl Fn(Result<GitStatus, String>) + Clone + 'static {
+    let identity = identity.clone();
+    let status_message = status_message.clone();
+    move |result: Result<GitStatus, String>| {
+        match result {
+            Ok(status) => status_message.set(Some((status.success, status.message))),
+            Err(e) => status_message.set(Some((false, e))),
+        }
+        refresh_identity(&identity, &status_message);
+    }
+}
+
+fn format_identity(identity: &Identity) -> String {
+    format!("{} <{}>", identity.name, identity.email)
+}
+
+/// Author profiles, the author this browser commits as, and the optional
+/// instance-wide committer.
+#[function_component(IdentitySettings)]
+pub fn identity_settings() -> Html {
+    let identity = use_state(|| None::<IdentityResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    let selected = use_state(|| selected_author().unwrap_or_default());
+    // Profile being edited, `None` for a new one
+    let editing = use_state(|| None::<String>);
+    let author_name = use_state(String::new);
+    let author_email = use_state(String::new);
+    let committer_name = use_state(String::new);
+    let committer_email = use_state(String::new);
+
+    {
+        let identity = identity.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_identity(&identity, &status_message);
+            || ()
+        });
+    }
+
+    // Show the saved committer in its fields once loaded
+    {
+        let committer_name = committer_name.clone();
+        let committer_email = committer_email.clone();
+        let committer = identity.as_ref().and_then(|i| i.committer.clone());
+        use_effect_with(committer, move |committer| {
+            committer_name.set(committer.as_ref().map(|c| c.name.clone()).unwrap_or_default());
+            committer_email.set(committer.as_ref().map(|c| c.email.clone()).unwrap_or_default());
+            || ()
+        });
+    }
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let on_select = {
+        let selected = selected.clone();
+        Callback::from(move |e: Event| {
+            if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+                let id = select.value();
+                if id.is_empty() {
+                    LocalStorage::delete(AUTHOR_KEY);
+                } else {
+                    let _ = LocalStorage::set(AUTHOR_KEY, &id);
+                }
+                selected.set(id);
+            }
+        })
+    };
+
+    let on_save_author = {
+        let editing = editing.clone();
+        let author_name = author_name.clone();
+        let author_email = author_email.clone();
+        let reload = reload_after(&identity, &status_message);
+        Callback::from(move |_| {
+            let editing = editing.clone();
+            let author_name = author_name.clone();
+            let author_email = author_email.clone();
+            let reload = reload.clone();
+            api_save_author((*editing).clone(), author_name.trim().to_string(), author_email.trim().to_string(),
+                Some(move |result: Result<GitStatus, String>| {
+                    if matches!(result, Ok(ref status) if status.success) {
+                        editing.set(None);
+                        author_name.set(String::new());
+                        author_email.set(String::new());
+                    }
+                    reload(result);
+                }));
+        })
+    };
+
+    let on_cancel_edit = {
+        let editing = editing.clone();
+        let author_name = author_name.clone();
+        let author_email = author_email.clone();
+        Callback::from(move |_| {
+            editing.set(None);
+            author_name.set(String::new());
+            author_email.set(String::new());
+        })
+    };
+
+    let on_save_committer = {
+        let committer_name = committer_name.clone();
+        let committer_email = committer_email.clone();
+        let reload = reload_after(&identity, &status_message);
+        Callback::from(move |_| {
+            let committer = Identity {
+                name: committer_name.trim().to_string(),
+                email: committer_email.trim().to_string(),
+            };
+            api_set_committer(Some(committer), Some(reload.clone()));
+        })
+    };
+
+    let on_clear_committer = {
+        let reload = reload_after(&identity, &status_message);
+        Callback::from(move |_| api_set_committer(None, Some(reload.clone())))
+    };
+
+    let Some(ref config) = *identity else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading identities..."}</p>
+            </div>
+        };
+    };
+
+    let default_label = match &config.repo_default {
+        Some(identity) => format!("Repository default ({})", format_identity(identity)),
+        None => "Repository default (Simple Web)".to_string(),
+    };
+
+    let rows = config.authors.iter().map(|author| {
+        let on_edit = {
+            let editing = editing.clone();
+            let author_name = author_name.clone();
+            let author_email = author_email.clone();
+            let author = author.clone();
+            Callback::from(move |_| {
+                editing.set(Some(author.id.clone()));
+                author_name.set(author.name.clone());
+                author_email.set(author.email.clone());
+            })
+        };
+        let on_delete = {
+            let id = author.id.clone();
+            let label = format!("{} <{}>", author.name, author.email);
+            let selected = selected.clone();
+            let reload = reload_after(&identity, &status_message);
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Delete author {}?", label)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    if *selected == id {
+                        LocalStorage::delete(AUTHOR_KEY);
+                        selected.set(String::new());
+                    }
+                    api_delete_author(&id, Some(reload.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <span class="text-sm">{ format!("{} <{}>", author.name, author.email) }</span>
+                <div class="flex gap-2">
+                    <button class="btn btn-secondary text-sm" onclick={on_edit}>{"Edit"}</button>
+                    <button class="btn btn-danger text-sm" onclick={on_delete}>{"Delete"}</button>
+                </div>
+            </div>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+
+            <div class="flex items-center gap-3">
+                <label class="text-sm font-medium">{"This browser commits as"}</label>
+                <select class="input" onchange={on_select}>
+                    <option value="" selected={selected.is_empty()}>{ default_label }</option>
+                    { for config.authors.iter().map(|author| html! {
+                        <option value={author.id.clone()} selected={*selected == author.id}>
+                            { format!("{} <{}>", author.name, author.email) }
+                        </option>
+                    }) }
+                </select>
+            </div>
+
+            { rows }
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{ if editing.is_some() { "Edit author" } else { "New author" } }</h3>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Name"
+                        value={(*author_name).clone()} onchange={text_field(&author_name)} />
+                    <input type="email" class="input" placeholder="name@example.com"
+                        value={(*author_email).clone()} onchange={text_field(&author_email)} />
+                    <button class="btn btn-primary" onclick={on_save_author}>{"Save"}</button>
+                    { if editing.is_some() {
+                        html! { <button class="btn btn-secondary" onclick={on_cancel_edit}>{"Cancel"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{"Committer"}</h3>
+                <p class="text-sm">{ match &config.committer {
+                    Some(committer) => format!("All commits are committed by {}.", format_identity(committer)),
+                    None => "Commits are committed by their author.".to_string(),
+                }}</p>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Name"
+                        value={(*committer_name).clone()} onchange={text_field(&committer_name)} />
+                    <input type="email" class="input" placeholder="name@example.com"
+                        value={(*committer_email).clone()} onchange={text_field(&committer_email)} />
+                    <button class="btn btn-primary" onclick={on_save_committer}>{"Save"}</button>
+                    <button class="btn btn-secondary" onclick={on_clear_committer} disabled={config.committer.is_none()}>{"Clear"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
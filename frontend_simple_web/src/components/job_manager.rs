@@ -0,0 +1,457 @@
+// frontend_simple_web/src/components/job_manager.rs
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::api::jobs::{api_delete_job, api_list_jobs, api_resume_job, api_run_job, api_save_job, DirtyTreePolicy, DivergedPolicy, JobAction, JobInfo, JobSchedule, JobSpec, PullPolicy, RetryPolicy};
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+/// Editable copy of a job; numbers stay strings until the form is submitted.
+#[derive(Clone, PartialEq)]
+struct JobForm {
+    id: Option<String>,
+    name: String,
+    action: JobAction,
+    use_cron: bool,
+    minutes: String,
+    expression: String,
+    timezone: String,
+    enabled: bool,
+    pull_policy: PullPolicy,
+    retry: RetryPolicy,
+    attempts: String,
+    pause_after: String,
+}
+
+impl Default for JobForm {
+    fn default() -> Self {
+        Self {
+            id: None,
+            name: String::new(),
+            action: JobAction::Pull,
+            use_cron: false,
+            minutes: "30".to_string(),
+            expression: "0 * * * *".to_string(),
+            timezone: "UTC".to_string(),
+            enabled: true,
+            pull_policy: PullPolicy::default(),
+            retry: RetryPolicy::default(),
+            attempts: "3".to_string(),
+            pause_after: "5".to_string(),
+        }
+    }
+}
+
+impl JobForm {
+    fn from_job(job: &JobInfo) -> Self {
+        let mut form = Self {
+            id: Some(job.id.clone()),
+            name: job.name.clone(),
+            action: job.action,
+            timezone: job.timezone.clone(),
+            enabled: job.enabled,
+            pull_policy: job.pull_policy,
+            retry: job.retry.clone(),
+            attempts: job.retry.max_attempts.to_string(),
+            pause_after: job.pause_after_failures.to_string(),
+            ..Self::default()
+        };
+        match &job.schedule {
+            JobSchedule::Interval { minutes } => form.minutes = minutes.to_string(),
+            JobSchedule::Cron { expression } => {
+                form.use_cron = true;
+                form.expression = expression.clone();
+            }
+        }
+        form
+    }
+
+    fn to_spec(&self) -> Result<JobSpec, String> {
+        let schedule = if self.use_cron {
+            JobSchedule::Cron { expression: self.expression.trim().to_string() }
+        } else {
+            let minutes = self.minutes.trim().parse::<u32>()
+                .map_err(|_| "Interval must be a whole number of minutes".to_string())?;
+            JobSchedule::Interval { minutes }
+        };
+        let max_attempts = self.attempts.trim().parse::<u32>()
+            .map_err(|_| "Attempts must be a whole number".to_string())?;
+        let pause_after_failures = self.pause_after.trim().parse::<u32>()
+            .map_err(|_| "Pause threshold must be a whole number".to_string())?;
+        Ok(JobSpec {
+            name: self.name.trim().to_string(),
+            enabled: self.enabled,
+            schedule,
+            timezone: self.timezone.trim().to_string(),
+            action: self.action,
+            pull_policy: self.pull_policy,
+            retry: RetryPolicy { max_attempts, ..self.retry.clone() },
+            pause_after_failures,
+        })
+    }
+}
+
+/// Format a unix timestamp (seconds) in the browser's locale.
+pub fn format_timestamp(ts: Option<u64>) -> String {
+    match ts {
+        Some(ts) => {
+            let date = web_sys::js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ts as f64 * 1000.0));
+            String::from(date.to_locale_string("default", &wasm_bindgen::JsValue::UNDEFINED))
+        }
+        None => "never".to_string(),
+    }
+}
+
+/// Outcome of the last run, with the failure streak when it is failing.
+fn last_status(job: &JobInfo) -> Html {
+    let title = job.last_message.clone().unwrap_or_default();
+    if job.paused {
+        let text = format!("Paused after {} failures", job.consecutive_failures);
+        return html! { <span class="text-sm text-red-600" {title}>{ text }</span> };
+    }
+    match job.last_success {
+        Some(true) => html! { <span class="text-sm text-green-600" {title}>{"Last run OK"}</span> },
+        Some(false) => {
+            let text = if job.consecutive_failures > 1 {
+                format!("Failed {} times in a row", job.consecutive_failures)
+            } else {
+                "Last run failed".to_string()
+            };
+            html! { <span class="text-sm text-red-600" {title}>{ text }</span> }
+        }
+        None => html! {},
+    }
+}
+
+fn refresh_jobs(jobs: &UseStateHandle<Vec<JobInfo>>, status_message: &StatusMsg) {
+    let jobs = jobs.clone();
+    let status_message = status_message.clone();
+    api_list_jobs(Some(move |result: Result<Vec<JobInfo>, String>| {
+        match result {
+            Ok(list) => jobs.set(list),
+            Err(e) => status_message.set(Some((false, format!("Failed to load jobs: {}", e)))),
+        }
+    }));
+}
+
+/// Callback that reports the outcome of a job request and reloads the list.
+fn reload_after(jobs: &UseStateHandle<Vec<JobInfo>>, status_message: &StatusMsg) -> impl Fn(Result<String, String>) + Clone + 'static {
+    let jobs = jobs.clone();
+    let status_message = status_message.clone();
+    move |result: Result<String, String>| {
+        match result {
+            Ok(msg) => status_message.set(Some((true, msg))),
+            Err(e) => status_message.set(Some((false, e))),
+        }
+        refresh_jobs(&jobs, &status_message);
+    }
+}
+
+/// Callback<Event> that writes an input/select value into the form being edited.
+fn form_field(form: &UseStateHandle<Option<JobForm>>, apply: fn(&mut JobForm, String)) -> Callback<Event> {
+    let form = form.clone();
+    Callback::from(move |e: Event| {
+        let value = if let Some(select) = e.target_dyn_into::<HtmlSelectElement>() {
+            select.value()
+        } else if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+            if input.type_() == "checkbox" { input.checked().to_string() } else { input.value() }
+        } else {
+            return;
+        };
+        if let Some(mut current) = (*form).clone() {
+            apply(&mut current, value);
+            form.set(Some(current));
+        }
+    })
+}
+
+#[function_component(JobManager)]
+pub fn job_manager() -> Html {
+    let jobs = use_state(Vec::<JobInfo>::new);
+    let status_message: StatusMsg = use_state(|| None);
+    let editing = use_state(|| None::<JobForm>);
+    let running = use_state(|| None::<String>);
+
+    {
+        let jobs = jobs.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_jobs(&jobs, &status_message);
+            || ()
+        });
+    }
+
+    let on_add = {
+        let editing = editing.clone();
+        Callback::from(move |_| editing.set(Some(JobForm::default())))
+    };
+
+    let on_cancel = {
+        let editing = editing.clone();
+        Callback::from(move |_| editing.set(None))
+    };
+
+    let on_save = {
+        let editing = editing.clone();
+        let jobs = jobs.clone();
+        let status_message = status_message.clone();
+        Callback::from(move |_| {
+            let Some(form) = (*editing).clone() else { return };
+            match form.to_spec() {
+                Ok(spec) => {
+                    let editing = editing.clone();
+                    let reload = reload_after(&jobs, &status_message);
+                    api_save_job(form.id.clone(), spec, Some(move |result: Result<String, String>| {
+                        if result.is_ok() {
+                            editing.set(None);
+                        }
+                        reload(result);
+                    }));
+                }
+                Err(e) => status_message.set(Some((false, e))),
+            }
+        })
+    };
+
+    let job_rows = jobs.iter().map(|job| {
+        let on_toggle = {
+            let job = job.clone();
+            let reload = reload_after(&jobs, &status_message);
+            Callback::from(move |_: Event| {
+                let mut spec = JobSpec::from(&job);
+                spec.enabled = !job.enabled;
+                api_save_job(Some(job.id.clone()), spec, Some(reload.clone()));
+            })
+        };
+
+        let on_run = {
+            let id = job.id.clone();
+            let running = running.clone();
+            let reload = reload_after(&jobs, &status_message);
+            Callback::from(move |_| {
+                running.set(Some(id.clone()));
+                let running = running.clone();
+                let reload = reload.clone();
+                api_run_job(id.clone(), Some(move |result: Result<String, String>| {
+                    running.set(None);
+                    reload(result);
+                }));
+            })
+        };
+
+        let on_resume = {
+            let id = job.id.clone();
+            let reload = reload_after(&jobs, &status_message);
+            Callback::from(move |_| api_resume_job(id.clone(), Some(reload.clone())))
+        };
+
+        let on_edit = {
+            let editing = editing.clone();
+            let form = JobForm::from_job(job);
+            Callback::from(move |_| editing.set(Some(form.clone())))
+        };
+
+        let on_delete = {
+            let id = job.id.clone();
+            let name = job.name.clone();
+            let reload = reload_after(&jobs, &status_message);
+            Callback::from(move |_| {
+                if web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Delete job \"{}\"?", name)).ok())
+                    .unwrap_or(false)
+                {
+                    api_delete_job(id.clone(), Some(reload.clone()));
+                }
+            })
+        };
+
+        let is_running = running.as_deref() == Some(job.id.as_str());
+        let timezone = if matches!(job.schedule, JobSchedule::Cron { .. }) {
+            format!(" ({})", job.timezone)
+        } else {
+            String::new()
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-2">
+                <div class="flex items-center justify-between">
+                    <div class="flex items-center gap-2">
+                        <input type="checkbox" checked={job.enabled} onchange={on_toggle} title="Enabled" />
+                        <span class="font-medium">{ &job.name }</span>
+                        <code class="text-sm">{ job.action.label() }</code>
+                        { last_status(job) }
+                    </div>
+                    <div class="flex gap-2">
+                        { if job.paused {
+                            html! { <button class="btn btn-primary text-sm" onclick={on_resume}>{ "Resume" }</button> }
+                        } else {
+                            html! {}
+                        }}
+                        <button class="btn btn-secondary text-sm" onclick={on_run} disabled={running.is_some()}>
+                            { if is_running { "Running..." } else { "Run now" } }
+                        </button>
+                        <button class="btn btn-secondary text-sm" onclick={on_edit}>{ "Edit" }</button>
+                        <button class="btn btn-danger text-sm" onclick={on_delete}>{ "Delete" }</button>
+                    </div>
+                </div>
+                <div class="text-sm">
+                    { format!("{}{} · Last run: {} · Next run: {}",
+                        job.schedule.describe(), timezone,
+                        format_timestamp(job.last_run),
+                        if job.paused {
+                            "paused".to_string()
+                        } else if job.enabled {
+                            format_timestamp(job.next_run)
+                        } else {
+                            "disabled".to_string()
+                        }) }
+                </div>
+                { match (job.last_success, &job.last_message) {
+                    (Some(false), Some(message)) => html! { <div class="text-sm text-red-600">{ message }</div> },
+                    _ => html! {},
+                }}
+            </div>
+        }
+    }).collect::<Html>();
+
+    let form_html = if let Some(ref form) = *editing {
+        html! {
+            <div class="space-y-2 p-3 border rounded">
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Name"}</label>
+                    <input type="text" class="input w-full" placeholder="Nightly backup"
+                        value={form.name.clone()} onchange={form_field(&editing, |f, v| f.name = v)} />
+                </div>
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Action"}</label>
+                    <select class="input w-full" onchange={form_field(&editing, |f, v| {
+                        if let Some(action) = JobAction::from_key(&v) { f.action = action; }
+                    })}>
+                        { for JobAction::ALL.iter().map(|action| html! {
+                            <option value={action.key()} selected={*action == form.action}>{ action.label() }</option>
+                        }) }
+                    </select>
+                </div>
+                { if form.action == JobAction::Pull {
+                    html! {
+                        <div class="flex gap-3">
+                            <div>
+                                <label class="block text-sm font-medium mb-1">{"Uncommitted changes"}</label>
+                                <select class="input" onchange={form_field(&editing, |f, v| {
+                                    if let Some(policy) = DirtyTreePolicy::from_key(&v) { f.pull_policy.dirty = policy; }
+                                })}>
+                                    { for DirtyTreePolicy::ALL.iter().map(|policy| html! {
+                                        <option value={policy.key()} selected={*policy == form.pull_policy.dirty}>{ policy.label() }</option>
+                                    }) }
+                                </select>
+                            </div>
+                            <div>
+                                <label class="block text-sm font-medium mb-1">{"Diverged history"}</label>
+                                <select class="input" onchange={form_field(&editing, |f, v| {
+                                    if let Some(policy) = DivergedPolicy::from_key(&v) { f.pull_policy.diverged = policy; }
+                                })}>
+                                    { for DivergedPolicy::ALL.iter().map(|policy| html! {
+                                        <option value={policy.key()} selected={*policy == form.pull_policy.diverged}>{ policy.label() }</option>
+                                    }) }
+                                </select>
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Schedule"}</label>
+                    <select class="input w-full" onchange={form_field(&editing, |f, v| f.use_cron = v == "cron")}>
+                        <option value="interval" selected={!form.use_cron}>{"Every N minutes"}</option>
+                        <option value="cron" selected={form.use_cron}>{"Cron expression"}</option>
+                    </select>
+                </div>
+                { if form.use_cron {
+                    html! {
+                        <>
+                            <div>
+                                <label class="block text-sm font-medium mb-1">{"Cron expression"}</label>
+                                <input type="text" class="input w-full" placeholder="0 9 * * 1-5"
+                                    value={form.expression.clone()} onchange={form_field(&editing, |f, v| f.expression = v)} />
+                                <p class="text-sm mt-1">{"Minute, hour, day of month, month, day of week (an optional leading seconds field is allowed)."}</p>
+                            </div>
+                            <div>
+                                <label class="block text-sm font-medium mb-1">{"Timezone"}</label>
+                                <input type="text" class="input w-full" placeholder="Europe/Madrid"
+                                    value={form.timezone.clone()} onchange={form_field(&editing, |f, v| f.timezone = v)} />
+                            </div>
+                        </>
+                    }
+                } else {
+                    html! {
+                        <div>
+                            <label class="block text-sm font-medium mb-1">{"Interval (minutes)"}</label>
+                            <input type="number" min="1" class="input w-32"
+                                value={form.minutes.clone()} onchange={form_field(&editing, |f, v| f.minutes = v)} />
+                        </div>
+                    }
+                }}
+                <div class="flex gap-3">
+                    <div>
+                        <label class="block text-sm font-medium mb-1">{"Attempts per run"}</label>
+                        <input type="number" min="1" max="10" class="input w-32"
+                            value={form.attempts.clone()} onchange={form_field(&editing, |f, v| f.attempts = v)} />
+                    </div>
+                    <div>
+                        <label class="block text-sm font-medium mb-1">{"Pause after failures"}</label>
+                        <input type="number" min="0" class="input w-32"
+                            value={form.pause_after.clone()} onchange={form_field(&editing, |f, v| f.pause_after = v)} />
+                    </div>
+                </div>
+                <p class="text-sm">{"Failed runs are retried with exponential backoff. After the given number of failed runs in a row the job pauses until resumed (0 never pauses)."}</p>
+                <div class="flex items-center gap-3">
+                    <input type="checkbox" id="job-enabled" checked={form.enabled}
+                        onchange={form_field(&editing, |f, v| f.enabled = v == "true")} />
+                    <label for="job-enabled" class="text-sm font-medium">{"Enabled"}</label>
+                </div>
+                <div class="flex gap-2">
+                    <button class="btn btn-primary" onclick={on_save}>
+                        { if form.id.is_some() { "Save Job" } else { "Create Job" } }
+                    </button>
+                    <button class="btn btn-secondary" onclick={on_cancel}>{"Cancel"}</button>
+                </div>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <div class="space-y-4">
+            <div class="flex items-center justify-between">
+                <p class="text-sm">{"Pull, commit, push, back up or check links on a schedule. Repository must be set up first for git actions."}</p>
+                <button class="btn btn-primary text-sm" onclick={on_add} disabled={editing.is_some()}>{"Add Job"}</button>
+            </div>
+
+            { if let Some((success, ref msg)) = *status_message {
+                html! {
+                    <div class="p-2 rounded text-sm" style={
+                        if success {
+                            "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                        } else {
+                            "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                        }
+                    }>
+                        { msg }
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+
+            { form_html }
+
+            { if jobs.is_empty() {
+                html! { <p class="text-sm">{"No scheduled jobs yet."}</p> }
+            } else {
+                job_rows
+            }}
+        </div>
+    }
+}
@@ -5,4 +5,20 @@ pub mod code_editor_textarea;
 pub mod git_manager;
 pub mod auth_guard;
 pub mod theme_selector;
-pub mod theme_editor;
\ No newline at end of file
+pub mod theme_editor;
+pub mod job_manager;
+pub mod publications;
+pub mod backup_manager;
+pub mod branch_switcher;
+pub mod history;
+pub mod diff_view;
+pub mod identity_settings;
+pub mod conflict_resolver;
+pub mod stash_manager;
+pub mod ssh_settings;
+pub mod credential_settings;
+pub mod remote_manager;
+pub mod tag_manager;
+pub mod webhook_settings;
+pub mod blame_view;
+pub mod git_tasks;
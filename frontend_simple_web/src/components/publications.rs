@@ -0,0 +1,295 @@
+// frontend_simple_web/src/components/publications.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::publications::{
+    api_cancel_publication, api_list_publications, api_schedule_publication, Publication,
+    PublicationStatus, PublishRequest, PublishRequestSource, StagedFile,
+};
+use crate::components::job_manager::format_timestamp;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+/// Parse a `datetime-local` input value (browser local time) into a unix timestamp.
+fn parse_local_datetime(value: &str) -> Result<u64, String> {
+    let date = web_sys::js_sys::Date::new(&wasm_bindgen::JsValue::from_str(value));
+    let millis = date.get_time();
+    if value.is_empty() || millis.is_nan() {
+        return Err("Choose a date and time to publish at".to_string());
+    }
+    Ok((millis / 1000.0) as u64)
+}
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn text_callback(handle: &UseStateHandle<String>) -> Callback<Event> {
+    let handle = handle.clone();
+    Callback::from(move |e: Event| {
+        if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+            handle.set(input.value());
+        }
+    })
+}
+
+fn checkbox_callback(handle: &UseStateHandle<bool>) -> Callback<Event> {
+    let handle = handle.clone();
+    Callback::from(move |e: Event| {
+        if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+            handle.set(input.checked());
+        }
+    })
+}
+
+#[derive(Properties, PartialEq)]
+pub struct PublishFormProps {
+    /// File being edited, relative to ROOT
+    pub path: String,
+    /// Content to publish, as currently in the editor
+    pub content: String,
+    pub on_close: Callback<()>,
+}
+
+/// "Publish at…" form for the file open in the editor. The content is staged
+/// now and moved into place at the chosen time.
+#[function_component(PublishForm)]
+pub fn publish_form(props: &PublishFormProps) -> Html {
+    let name = use_state(|| props.path.clone());
+    let publish_at = use_state(String::new);
+    let commit = use_state(|| true);
+    let push = use_state(|| false);
+    let status_message: StatusMsg = use_state(|| None);
+
+    let on_schedule = {
+        let name = name.clone();
+        let publish_at = publish_at.clone();
+        let commit = commit.clone();
+        let push = push.clone();
+        let status_message = status_message.clone();
+        let path = props.path.clone();
+        let content = props.content.clone();
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| {
+            let publish_at = match parse_local_datetime(&publish_at) {
+                Ok(ts) => ts,
+                Err(e) => {
+                    status_message.set(Some((false, e)));
+                    return;
+                }
+            };
+            let request = PublishRequest {
+                name: (*name).clone(),
+                publish_at,
+                source: PublishRequestSource::Files {
+                    files: vec![StagedFile { path: path.clone(), content: content.clone() }],
+                },
+                commit: *commit,
+                push: *push,
+            };
+            let status_message = status_message.clone();
+            let on_close = on_close.clone();
+            api_schedule_publication(request, Some(move |result: Result<String, String>| {
+                match result {
+                    Ok(_) => on_close.emit(()),
+                    Err(e) => status_message.set(Some((false, e))),
+                }
+            }));
+        })
+    };
+
+    let on_cancel = {
+        let on_close = props.on_close.clone();
+        Callback::from(move |_| on_close.emit(()))
+    };
+
+    html! {
+        <div class="space-y-2 p-3 mb-2 border rounded">
+            <p class="text-sm">{"The current editor content is staged now and replaces the live file at the chosen time."}</p>
+            { status_banner(&status_message) }
+            <div>
+                <label class="block text-sm font-medium mb-1">{"Name"}</label>
+                <input type="text" class="input w-full" value={(*name).clone()} onchange={text_callback(&name)} />
+            </div>
+            <div>
+                <label class="block text-sm font-medium mb-1">{"Publish at"}</label>
+                <input type="datetime-local" class="input" value={(*publish_at).clone()} onchange={text_callback(&publish_at)} />
+            </div>
+            <div class="flex items-center gap-3">
+                <input type="checkbox" id="publish-commit" checked={*commit} onchange={checkbox_callback(&commit)} />
+                <label for="publish-commit" class="text-sm font-medium">{"Commit after publishing"}</label>
+                <input type="checkbox" id="publish-push" checked={*push} onchange={checkbox_callback(&push)} />
+                <label for="publish-push" class="text-sm font-medium">{"Push"}</label>
+            </div>
+            <div class="flex gap-2">
+                <button class="btn btn-primary" onclick={on_schedule}>{"Schedule"}</button>
+                <button class="btn btn-secondary" onclick={on_cancel}>{"Cancel"}</button>
+            </div>
+        </div>
+    }
+}
+
+fn refresh_publications(publications: &UseStateHandle<Vec<Publication>>, status_message: &StatusMsg) {
+    let publications = publications.clone();
+    let status_message = status_message.clone();
+    api_list_publications(Some(move |result: Result<Vec<Publication>, String>| {
+        match result {
+            Ok(list) => publications.set(list),
+            Err(e) => status_message.set(Some((false, format!("Failed to load publications: {}", e)))),
+        }
+    }));
+}
+
+fn status_label(publication: &Publication) -> Html {
+    match publication.status {
+        PublicationStatus::Pending => html! { <span class="text-sm">{"Pending"}</span> },
+        PublicationStatus::Published => html! { <span class="text-sm text-green-600">{"Published"}</span> },
+        PublicationStatus::Failed => html! { <span class="text-sm text-red-600">{"Failed"}</span> },
+        PublicationStatus::Cancelled => html! { <span class="text-sm">{"Cancelled"}</span> },
+    }
+}
+
+/// Scheduled publications with cancel buttons, plus a form to publish a git ref.
+#[function_component(PublicationList)]
+pub fn publication_list() -> Html {
+    let publications = use_state(Vec::<Publication>::new);
+    let status_message: StatusMsg = use_state(|| None);
+    let ref_name = use_state(String::new);
+    let reference = use_state(String::new);
+    let ref_publish_at = use_state(String::new);
+    let ref_push = use_state(|| false);
+
+    {
+        let publications = publications.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_publications(&publications, &status_message);
+            || ()
+        });
+    }
+
+    let reload = {
+        let publications = publications.clone();
+        let status_message = status_message.clone();
+        move |result: Result<String, String>| {
+            match result {
+                Ok(msg) => status_message.set(Some((true, msg))),
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            refresh_publications(&publications, &status_message);
+        }
+    };
+
+    let on_schedule_ref = {
+        let ref_name = ref_name.clone();
+        let reference = reference.clone();
+        let ref_publish_at = ref_publish_at.clone();
+        let ref_push = ref_push.clone();
+        let status_message = status_message.clone();
+        let reload = reload.clone();
+        Callback::from(move |_| {
+            let publish_at = match parse_local_datetime(&ref_publish_at) {
+                Ok(ts) => ts,
+                Err(e) => {
+                    status_message.set(Some((false, e)));
+                    return;
+                }
+            };
+            let request = PublishRequest {
+                name: (*ref_name).clone(),
+                publish_at,
+                source: PublishRequestSource::GitRef { reference: (*reference).clone() },
+                commit: false,
+                push: *ref_push,
+            };
+            api_schedule_publication(request, Some(reload.clone()));
+        })
+    };
+
+    let rows = publications.iter().map(|publication| {
+        let on_cancel = {
+            let id = publication.id.clone();
+            let reload = reload.clone();
+            Callback::from(move |_| api_cancel_publication(id.clone(), Some(reload.clone())))
+        };
+        let mut after = Vec::new();
+        if publication.commit { after.push("commit"); }
+        if publication.push { after.push("push"); }
+        let after = if after.is_empty() { String::new() } else { format!(" · then {}", after.join(" & ")) };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-2">
+                <div class="flex items-center justify-between">
+                    <div class="flex items-center gap-2">
+                        <span class="font-medium">{ &publication.name }</span>
+                        { status_label(publication) }
+                    </div>
+                    { if publication.status == PublicationStatus::Pending {
+                        html! { <button class="btn btn-danger text-sm" onclick={on_cancel}>{"Cancel"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+                <div class="text-sm">
+                    { format!("{} · {}{}", format_timestamp(Some(publication.publish_at)), publication.source.describe(), after) }
+                </div>
+                { if let Some(ref message) = publication.message {
+                    html! { <div class="text-sm">{ message }</div> }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="space-y-4">
+            <p class="text-sm">{"Files are scheduled from the editor with \"Publish at…\". A git ref publication fast-forwards the current branch to that ref."}</p>
+            { status_banner(&status_message) }
+
+            { if publications.is_empty() {
+                html! { <p class="text-sm">{"No scheduled publications."}</p> }
+            } else {
+                rows
+            }}
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{"Publish a git ref"}</h3>
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Name"}</label>
+                    <input type="text" class="input w-full" placeholder="Spring launch"
+                        value={(*ref_name).clone()} onchange={text_callback(&ref_name)} />
+                </div>
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Ref"}</label>
+                    <input type="text" class="input w-full" placeholder="origin/launch"
+                        value={(*reference).clone()} onchange={text_callback(&reference)} />
+                </div>
+                <div>
+                    <label class="block text-sm font-medium mb-1">{"Publish at"}</label>
+                    <input type="datetime-local" class="input"
+                        value={(*ref_publish_at).clone()} onchange={text_callback(&ref_publish_at)} />
+                </div>
+                <div class="flex items-center gap-3">
+                    <input type="checkbox" id="publish-ref-push" checked={*ref_push} onchange={checkbox_callback(&ref_push)} />
+                    <label for="publish-ref-push" class="text-sm font-medium">{"Push after fast-forwarding"}</label>
+                </div>
+                <button class="btn btn-primary" onclick={on_schedule_ref}>{"Schedule"}</button>
+            </div>
+        </div>
+    }
+}
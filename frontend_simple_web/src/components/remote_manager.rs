@@ -0,0 +1,352 @@
+// frontend_simple_web/src/components/remote_manager.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_add_remote, api_delete_remote, api_fetch_remote, api_list_remotes, api_pull_remote,
+    api_push, api_update_remote, BranchListResponse, GitStatus, PushResponse, RemoteInfo,
+    RemoteListResponse,
+};
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_remotes(list: &UseStateHandle<Option<RemoteListResponse>>, status_message: &StatusMsg) {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    api_list_remotes(Some(move |result: Result<RemoteListResponse, String>| {
+        match result {
+            Ok(response) if response.success => list.set(Some(response)),
+            Ok(response) => status_message.set(Some((false, response.message))),
+            Err(e) => status_message.set(Some((false, format!("Failed to load remotes: {}", e)))),
+        }
+    }));
+}
+
+#[derive(Properties, PartialEq)]
+pub struct RemoteManagerProps {
+    /// Called after a pull or push changed the repository
+    #[prop_or_default]
+    pub on_changed: Callback<()>,
+}
+
+/// Remotes with their URLs and mirror setting, and fetch, pull and push per
+/// remote or to origin and all mirrors at once.
+#[function_component(RemoteManager)]
+pub fn remote_manager(props: &RemoteManagerProps) -> Html {
+    let list = use_state(|| None::<RemoteListResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    let push_results = use_state(|| None::<PushResponse>);
+    // Remote being edited, `None` when adding one
+    let editing = use_state(|| None::<String>);
+    let name = use_state(String::new);
+    let url = use_state(String::new);
+    let push_url = use_state(String::new);
+    let mirror = use_state(|| false);
+
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_remotes(&list, &status_message);
+            || ()
+        });
+    }
+
+    let reload_after = {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        let on_changed = props.on_changed.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => {
+                    if status.success {
+                        on_changed.emit(());
+                    }
+                    status_message.set(Some((status.success, status.message)));
+                }
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            refresh_remotes(&list, &status_message);
+        }
+    };
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let on_mirror_change = {
+        let mirror = mirror.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                mirror.set(input.checked());
+            }
+        })
+    };
+
+    let clear_form = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let url = url.clone();
+        let push_url = push_url.clone();
+        let mirror = mirror.clone();
+        move || {
+            editing.set(None);
+            name.set(String::new());
+            url.set(String::new());
+            push_url.set(String::new());
+            mirror.set(false);
+        }
+    };
+
+    let on_save = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let url = url.clone();
+        let push_url = push_url.clone();
+        let mirror = mirror.clone();
+        let clear_form = clear_form.clone();
+        let reload_after = reload_after.clone();
+        Callback::from(move |_| {
+            let clear_form = clear_form.clone();
+            let reload_after = reload_after.clone();
+            let done = move |result: Result<GitStatus, String>| {
+                if matches!(result, Ok(ref status) if status.success) {
+                    clear_form();
+                }
+                reload_after(result);
+            };
+            match *editing {
+                Some(ref original) => api_update_remote(
+                    original,
+                    Some(name.trim().to_string()),
+                    Some(url.trim().to_string()),
+                    Some(push_url.trim().to_string()),
+                    Some(*mirror),
+                    Some(done),
+                ),
+                None => api_add_remote(
+                    name.trim().to_string(),
+                    url.trim().to_string(),
+                    Some(push_url.trim().to_string()).filter(|u| !u.is_empty()),
+                    *mirror,
+                    Some(done),
+                ),
+            }
+        })
+    };
+
+    let on_cancel_edit = {
+        let clear_form = clear_form.clone();
+        Callback::from(move |_| clear_form())
+    };
+
+    let on_push_all = {
+        let push_results = push_results.clone();
+        let status_message = status_message.clone();
+        let on_changed = props.on_changed.clone();
+        Callback::from(move |_| {
+            let push_results = push_results.clone();
+            let status_message = status_message.clone();
+            let on_changed = on_changed.clone();
+            api_push(None, Some(move |result: Result<PushResponse, String>| {
+                match result {
+                    Ok(response) => {
+                        on_changed.emit(());
+                        status_message.set(Some((response.success, response.message.clone())));
+                        push_results.set(Some(response));
+                    }
+                    Err(e) => status_message.set(Some((false, e))),
+                }
+            }));
+        })
+    };
+
+    let Some(ref remotes) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading remotes..."}</p>
+            </div>
+        };
+    };
+
+    let row = |remote: &RemoteInfo| {
+        let remote_name = remote.name.clone();
+        let on_fetch = {
+            let remote_name = remote_name.clone();
+            let status_message = status_message.clone();
+            Callback::from(move |_| {
+                let status_message = status_message.clone();
+                let remote_name_msg = remote_name.clone();
+                api_fetch_remote(&remote_name, Some(move |result: Result<BranchListResponse, String>| {
+                    match result {
+                        Ok(response) if response.success => {
+                            status_message.set(Some((true, format!("Fetched {}", remote_name_msg))));
+                        }
+                        Ok(response) => status_message.set(Some((false, response.message))),
+                        Err(e) => status_message.set(Some((false, e))),
+                    }
+                }));
+            })
+        };
+        let on_pull = {
+            let remote_name = remote_name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| api_pull_remote(remote_name.clone(), Some(reload_after.clone())))
+        };
+        let on_push = {
+            let remote_name = remote_name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                let reload_after = reload_after.clone();
+                api_push(Some(remote_name.clone()), Some(move |result: Result<PushResponse, String>| {
+                    reload_after(result.map(|response| GitStatus {
+                        success: response.success,
+                        message: response.message,
+                        commit_hash: response.commit_hash,
+                    }));
+                }));
+            })
+        };
+        let on_toggle_mirror = {
+            let remote_name = remote_name.clone();
+            let mirror = !remote.mirror;
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                api_update_remote(&remote_name, None, None, None, Some(mirror), Some(reload_after.clone()));
+            })
+        };
+        let on_edit = {
+            let editing = editing.clone();
+            let name = name.clone();
+            let url = url.clone();
+            let push_url = push_url.clone();
+            let mirror = mirror.clone();
+            let remote = remote.clone();
+            Callback::from(move |_| {
+                editing.set(Some(remote.name.clone()));
+                name.set(remote.name.clone());
+                url.set(remote.url.clone());
+                push_url.set(remote.push_url.clone().unwrap_or_default());
+                mirror.set(remote.mirror);
+            })
+        };
+        let on_delete = {
+            let remote_name = remote_name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Remove remote '{}'?", remote_name)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_delete_remote(&remote_name, Some(reload_after.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <div class="text-sm">
+                    <code>{ &remote.name }</code>{ format!(" · {}", remote.url) }
+                    { match remote.push_url {
+                        Some(ref push_url) => html! { <div class="text-xs">{ format!("Pushes to {}", push_url) }</div> },
+                        None => html! {},
+                    }}
+                </div>
+                <div class="flex items-center gap-2">
+                    <label class="flex items-center gap-1 text-sm" title="Push here whenever origin is pushed">
+                        <input type="checkbox" checked={remote.mirror} onchange={on_toggle_mirror}
+                            disabled={remote.name == "origin"} />
+                        {"Mirror"}
+                    </label>
+                    <button class="btn btn-secondary text-sm" onclick={on_fetch}>{"Fetch"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_pull}>{"Pull"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_push}>{"Push"}</button>
+                    <button class="btn btn-secondary text-sm" onclick={on_edit}>{"Edit"}</button>
+                    <button class="btn btn-danger text-sm" onclick={on_delete}>{"Remove"}</button>
+                </div>
+            </div>
+        }
+    };
+
+    let push_report = match *push_results {
+        Some(ref response) if response.remotes.len() > 1 => html! {
+            <ul class="text-sm">
+                { for response.remotes.iter().map(|result| html! {
+                    <li class={if result.success { "text-green-600" } else { "text-red-600" }}>
+                        { format!("{}: {}", result.remote, result.message) }
+                    </li>
+                }) }
+            </ul>
+        },
+        _ => html! {},
+    };
+
+    let mirrors = remotes.remotes.iter().filter(|r| r.mirror).count();
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+
+            { if remotes.remotes.is_empty() {
+                html! { <p class="text-sm">{"No remotes configured."}</p> }
+            } else {
+                html! { for remotes.remotes.iter().map(row) }
+            }}
+
+            <div class="flex items-center gap-3">
+                <button class="btn btn-primary" onclick={on_push_all}>
+                    { if mirrors > 0 { format!("Push to origin and {} mirrors", mirrors) } else { "Push to origin".to_string() } }
+                </button>
+            </div>
+            { push_report }
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{ if editing.is_some() { "Edit remote" } else { "Add remote" } }</h3>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Name, e.g. backup"
+                        value={(*name).clone()} onchange={text_field(&name)} />
+                    <input type="text" class="input w-full" placeholder="URL"
+                        value={(*url).clone()} onchange={text_field(&url)} />
+                </div>
+                <div class="flex items-center gap-3">
+                    <input type="text" class="input w-full" placeholder="Push URL (optional)"
+                        value={(*push_url).clone()} onchange={text_field(&push_url)} />
+                    <label class="flex items-center gap-1 text-sm">
+                        <input type="checkbox" checked={*mirror} onchange={on_mirror_change} />
+                        {"Mirror"}
+                    </label>
+                    <button class="btn btn-primary" onclick={on_save}>{"Save"}</button>
+                    { if editing.is_some() {
+                        html! { <button class="btn btn-secondary" onclick={on_cancel_edit}>{"Cancel"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
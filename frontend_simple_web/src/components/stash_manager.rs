@@ -0,0 +1,211 @@
+// frontend_simple_web/src/components/stash_manager.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_apply_stash, api_drop_stash, api_list_stashes, api_save_stash, DiffTarget, GitStatus,
+    StashListResponse,
+};
+use crate::components::diff_view::DiffView;
+use crate::components::job_manager::format_timestamp;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_stashes(list: &UseStateHandle<Option<StashListResponse>>, status_message: &StatusMsg) {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    api_list_stashes(Some(move |result: Result<StashListResponse, String>| {
+        match result {
+            Ok(response) if response.success => list.set(Some(response)),
+            Ok(response) => status_message.set(Some((false, response.message))),
+            Err(e) => status_message.set(Some((false, format!("Failed to load stashes: {}", e)))),
+        }
+    }));
+}
+
+#[derive(Properties, PartialEq)]
+pub struct StashManagerProps {
+    /// Called after the working tree changed
+    #[prop_or_default]
+    pub on_changed: Callback<()>,
+}
+
+/// Stash uncommitted changes and list, inspect, apply, pop or drop stashes.
+#[function_component(StashManager)]
+pub fn stash_manager(props: &StashManagerProps) -> Html {
+    let list = use_state(|| None::<StashListResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    let message = use_state(String::new);
+    let include_untracked = use_state(|| true);
+    let shown = use_state(|| None::<usize>);
+
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_stashes(&list, &status_message);
+            || ()
+        });
+    }
+
+    let reload_after = {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        let shown = shown.clone();
+        let on_changed = props.on_changed.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => {
+                    if status.success {
+                        on_changed.emit(());
+                    }
+                    status_message.set(Some((status.success, status.message)));
+                }
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            // Indexes shift, so whatever diff was open may now be another stash
+            shown.set(None);
+            refresh_stashes(&list, &status_message);
+        }
+    };
+
+    let on_message_change = {
+        let message = message.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                message.set(input.value());
+            }
+        })
+    };
+
+    let on_untracked_change = {
+        let include_untracked = include_untracked.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                include_untracked.set(input.checked());
+            }
+        })
+    };
+
+    let on_save = {
+        let message = message.clone();
+        let include_untracked = include_untracked.clone();
+        let reload_after = reload_after.clone();
+        Callback::from(move |_| {
+            let text = Some(message.trim().to_string()).filter(|m| !m.is_empty());
+            let message = message.clone();
+            let reload_after = reload_after.clone();
+            api_save_stash(text, *include_untracked, Some(move |result: Result<GitStatus, String>| {
+                if matches!(result, Ok(ref status) if status.success) {
+                    message.set(String::new());
+                }
+                reload_after(result);
+            }));
+        })
+    };
+
+    let Some(ref stashes) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading stashes..."}</p>
+            </div>
+        };
+    };
+
+    let rows = stashes.stashes.iter().map(|entry| {
+        let index = entry.index;
+        let on_show = {
+            let shown = shown.clone();
+            Callback::from(move |_| shown.set(if *shown == Some(index) { None } else { Some(index) }))
+        };
+        let on_apply = {
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| api_apply_stash(index, false, Some(reload_after.clone())))
+        };
+        let on_pop = {
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| api_apply_stash(index, true, Some(reload_after.clone())))
+        };
+        let on_drop = {
+            let reload_after = reload_after.clone();
+            let label = entry.message.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Drop stash \"{}\"? Its changes will be lost.", label)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_drop_stash(index, Some(reload_after.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-2">
+                <div class="flex items-center justify-between">
+                    <div class="text-sm">
+                        <code>{ format!("stash@{{{}}}", index) }</code>
+                        { format!(" · {}", entry.message) }
+                        <div class="text-xs">
+                            { format_timestamp(Some(entry.date.max(0) as u64)) }
+                            { if entry.includes_untracked { " · includes untracked files" } else { "" } }
+                        </div>
+                    </div>
+                    <div class="flex gap-2">
+                        <button class="btn btn-secondary text-sm" onclick={on_show}>
+                            { if *shown == Some(index) { "Hide" } else { "Show" } }
+                        </button>
+                        <button class="btn btn-secondary text-sm" onclick={on_apply}>{"Apply"}</button>
+                        <button class="btn btn-primary text-sm" onclick={on_pop}>{"Pop"}</button>
+                        <button class="btn btn-danger text-sm" onclick={on_drop}>{"Drop"}</button>
+                    </div>
+                </div>
+                { if *shown == Some(index) {
+                    html! { <DiffView target={DiffTarget::Stash(index)} /> }
+                } else {
+                    html! {}
+                }}
+            </div>
+        }
+    }).collect::<Html>();
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+
+            <div class="flex items-center gap-3">
+                <input type="text" class="input w-full" placeholder="Stash message (optional)"
+                    value={(*message).clone()} onchange={on_message_change} />
+                <label class="flex items-center gap-1 text-sm">
+                    <input type="checkbox" checked={*include_untracked} onchange={on_untracked_change} />
+                    {"Include untracked files"}
+                </label>
+                <button class="btn btn-primary" onclick={on_save}>{"Stash Changes"}</button>
+            </div>
+
+            { if stashes.stashes.is_empty() {
+                html! { <p class="text-sm">{"No stashes."}</p> }
+            } else {
+                rows
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,196 @@
+// frontend_simple_web/src/components/tag_manager.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_create_tag, api_delete_tag, api_list_tags, api_push_tags, GitStatus, PushResponse, TagInfo,
+    TagListResponse,
+};
+use crate::components::identity_settings::selected_author;
+use crate::components::job_manager::format_timestamp;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh_tags(list: &UseStateHandle<Option<TagListResponse>>, status_message: &StatusMsg) {
+    let list = list.clone();
+    let status_message = status_message.clone();
+    api_list_tags(Some(move |result: Result<TagListResponse, String>| {
+        match result {
+            Ok(response) if response.success => list.set(Some(response)),
+            Ok(response) => status_message.set(Some((false, response.message))),
+            Err(e) => status_message.set(Some((false, format!("Failed to load tags: {}", e)))),
+        }
+    }));
+}
+
+fn push_status(result: Result<PushResponse, String>) -> Result<GitStatus, String> {
+    result.map(|response| GitStatus {
+        success: response.success,
+        message: response.message,
+        commit_hash: response.commit_hash,
+    })
+}
+
+/// Tags marking release snapshots: create one on HEAD, delete them and push
+/// them to origin and every mirror.
+#[function_component(TagManager)]
+pub fn tag_manager() -> Html {
+    let list = use_state(|| None::<TagListResponse>);
+    let status_message: StatusMsg = use_state(|| None);
+    let name = use_state(String::new);
+    let message = use_state(String::new);
+
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh_tags(&list, &status_message);
+            || ()
+        });
+    }
+
+    let reload_after = {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => status_message.set(Some((status.success, status.message))),
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            refresh_tags(&list, &status_message);
+        }
+    };
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let on_create = {
+        let name = name.clone();
+        let message = message.clone();
+        let reload_after = reload_after.clone();
+        Callback::from(move |_| {
+            let name_state = name.clone();
+            let message_state = message.clone();
+            let reload_after = reload_after.clone();
+            let tag_message = Some(message.trim().to_string()).filter(|m| !m.is_empty());
+            api_create_tag(name.trim().to_string(), None, tag_message, selected_author(), Some(move |result: Result<GitStatus, String>| {
+                if matches!(result, Ok(ref status) if status.success) {
+                    name_state.set(String::new());
+                    message_state.set(String::new());
+                }
+                reload_after(result);
+            }));
+        })
+    };
+
+    let on_push_all = {
+        let reload_after = reload_after.clone();
+        Callback::from(move |_| {
+            let reload_after = reload_after.clone();
+            api_push_tags(None, Some(move |result: Result<PushResponse, String>| reload_after(push_status(result))));
+        })
+    };
+
+    let Some(ref tags) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading tags..."}</p>
+            </div>
+        };
+    };
+
+    let row = |tag: &TagInfo| {
+        let on_push = {
+            let tag_name = tag.name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                let reload_after = reload_after.clone();
+                api_push_tags(Some(tag_name.clone()), Some(move |result: Result<PushResponse, String>| reload_after(push_status(result))));
+            })
+        };
+        let on_delete = {
+            let tag_name = tag.name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Delete tag '{}'? Copies already pushed stay on the remotes.", tag_name)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_delete_tag(&tag_name, Some(reload_after.clone()));
+                }
+            })
+        };
+        let details = match (&tag.message, &tag.tagger) {
+            (Some(message), Some(tagger)) => format!("{} · {}", message.lines().next().unwrap_or(""), tagger),
+            (Some(message), None) => message.lines().next().unwrap_or("").to_string(),
+            _ => "lightweight".to_string(),
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded flex items-center justify-between">
+                <div class="text-sm">
+                    <code>{ &tag.name }</code>
+                    { format!(" → {} · {}", &tag.target[..7.min(tag.target.len())], format_timestamp(Some(tag.date.max(0) as u64))) }
+                    <div class="text-xs">{ details }</div>
+                </div>
+                <div class="flex gap-2">
+                    <button class="btn btn-secondary text-sm" onclick={on_push}>{"Push"}</button>
+                    <button class="btn btn-danger text-sm" onclick={on_delete}>{"Delete"}</button>
+                </div>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+
+            { if tags.tags.is_empty() {
+                html! { <p class="text-sm">{"No tags yet."}</p> }
+            } else {
+                html! {
+                    <>
+                        { for tags.tags.iter().map(row) }
+                        <button class="btn btn-secondary" onclick={on_push_all}>{"Push all tags"}</button>
+                    </>
+                }
+            }}
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{"Tag the current commit"}</h3>
+                <div class="flex gap-3">
+                    <input type="text" class="input" placeholder="Name, e.g. v1.0"
+                        value={(*name).clone()} onchange={text_field(&name)} />
+                    <input type="text" class="input w-full" placeholder="Message (optional)"
+                        value={(*message).clone()} onchange={text_field(&message)} />
+                    <button class="btn btn-primary" onclick={on_create} disabled={name.trim().is_empty()}>{"Create Tag"}</button>
+                </div>
+            </div>
+        </div>
+    }
+}
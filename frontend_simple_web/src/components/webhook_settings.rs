@@ -0,0 +1,387 @@
+// frontend_simple_web/src/components/webhook_settings.rs
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::api::git::{
+    api_delete_webhook, api_list_deliveries, api_list_webhooks, api_save_webhook,
+    DeliveryListResponse, GitStatus, SaveWebhookResponse, WebhookDelivery, WebhookInfo, WebhookListResponse,
+};
+use crate::api::jobs::{DirtyTreePolicy, DivergedPolicy, PullPolicy};
+use crate::components::job_manager::format_timestamp;
+use crate::config_file::get_env_var;
+
+type StatusMsg = UseStateHandle<Option<(bool, String)>>;
+
+/// Deliveries shown in the log
+const DELIVERY_LIMIT: usize = 30;
+
+fn status_banner(status_message: &Option<(bool, String)>) -> Html {
+    if let Some((success, ref msg)) = *status_message {
+        html! {
+            <div class="p-2 rounded text-sm" style={
+                if success {
+                    "background-color: #dcfce7; color: #166534; border: 1px solid #bbf7d0;"
+                } else {
+                    "background-color: #fef2f2; color: #dc2626; border: 1px solid #fecaca;"
+                }
+            }>
+                { msg }
+            </div>
+        }
+    } else {
+        html! {}
+    }
+}
+
+fn refresh(
+    list: &UseStateHandle<Option<WebhookListResponse>>,
+    deliveries: &UseStateHandle<Vec<WebhookDelivery>>,
+    status_message: &StatusMsg,
+) {
+    {
+        let list = list.clone();
+        let status_message = status_message.clone();
+        api_list_webhooks(Some(move |result: Result<WebhookListResponse, String>| {
+            match result {
+                Ok(response) if response.success => list.set(Some(response)),
+                Ok(response) => status_message.set(Some((false, response.message))),
+                Err(e) => status_message.set(Some((false, format!("Failed to load webhooks: {}", e)))),
+            }
+        }));
+    }
+    let deliveries = deliveries.clone();
+    api_list_deliveries(DELIVERY_LIMIT, Some(move |result: Result<DeliveryListResponse, String>| {
+        if let Ok(response) = result {
+            deliveries.set(response.deliveries);
+        }
+    }));
+}
+
+fn delivery_status_class(status: &str) -> &'static str {
+    match status {
+        "succeeded" => "text-green-600",
+        "rejected" | "failed" => "text-red-600",
+        _ => "text-orange-600",
+    }
+}
+
+/// Webhooks that let GitHub, Gitea, Forgejo or GitLab trigger a pull on push,
+/// and a log of their recent deliveries.
+#[function_component(WebhookSettings)]
+pub fn webhook_settings() -> Html {
+    let list = use_state(|| None::<WebhookListResponse>);
+    let deliveries = use_state(Vec::<WebhookDelivery>::new);
+    let status_message: StatusMsg = use_state(|| None);
+    // Hook being edited, `None` for a new one
+    let editing = use_state(|| None::<String>);
+    let name = use_state(String::new);
+    let branch = use_state(String::new);
+    let policy = use_state(PullPolicy::default);
+    let enabled = use_state(|| true);
+    // Hook name and secret, shown once after the secret was set
+    let new_secret = use_state(|| None::<(String, String)>);
+
+    {
+        let list = list.clone();
+        let deliveries = deliveries.clone();
+        let status_message = status_message.clone();
+        use_effect_with((), move |_| {
+            refresh(&list, &deliveries, &status_message);
+            || ()
+        });
+    }
+
+    let reload_after = {
+        let list = list.clone();
+        let deliveries = deliveries.clone();
+        let status_message = status_message.clone();
+        move |result: Result<GitStatus, String>| {
+            match result {
+                Ok(status) => status_message.set(Some((status.success, status.message))),
+                Err(e) => status_message.set(Some((false, e))),
+            }
+            refresh(&list, &deliveries, &status_message);
+        }
+    };
+
+    let after_save = {
+        let new_secret = new_secret.clone();
+        let reload_after = reload_after.clone();
+        move |hook_name: String, result: Result<SaveWebhookResponse, String>| {
+            match result {
+                Ok(response) => {
+                    new_secret.set(response.secret.clone().map(|secret| (hook_name, secret)));
+                    reload_after(Ok(GitStatus {
+                        success: response.success, message: response.message, commit_hash: response.commit_hash,
+                    }));
+                }
+                Err(e) => reload_after(Err(e)),
+            }
+        }
+    };
+
+    let text_field = |handle: &UseStateHandle<String>| {
+        let handle = handle.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                handle.set(input.value());
+            }
+        })
+    };
+
+    let on_enabled_change = {
+        let enabled = enabled.clone();
+        Callback::from(move |e: Event| {
+            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+                enabled.set(input.checked());
+            }
+        })
+    };
+
+    let on_dirty_change = {
+        let policy = policy.clone();
+        Callback::from(move |e: Event| {
+            let selected = e.target_dyn_into::<web_sys::HtmlSelectElement>()
+                .and_then(|input| DirtyTreePolicy::from_key(&input.value()));
+            if let Some(dirty) = selected {
+                policy.set(PullPolicy { dirty, ..*policy });
+            }
+        })
+    };
+
+    let on_diverged_change = {
+        let policy = policy.clone();
+        Callback::from(move |e: Event| {
+            let selected = e.target_dyn_into::<web_sys::HtmlSelectElement>()
+                .and_then(|input| DivergedPolicy::from_key(&input.value()));
+            if let Some(diverged) = selected {
+                policy.set(PullPolicy { diverged, ..*policy });
+            }
+        })
+    };
+
+    let clear_form = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let branch = branch.clone();
+        let policy = policy.clone();
+        let enabled = enabled.clone();
+        move || {
+            editing.set(None);
+            name.set(String::new());
+            branch.set(String::new());
+            policy.set(PullPolicy::default());
+            enabled.set(true);
+        }
+    };
+
+    let on_save = {
+        let editing = editing.clone();
+        let name = name.clone();
+        let branch = branch.clone();
+        let policy = policy.clone();
+        let enabled = enabled.clone();
+        let clear_form = clear_form.clone();
+        let after_save = after_save.clone();
+        Callback::from(move |_| {
+            let clear_form = clear_form.clone();
+            let after_save = after_save.clone();
+            let hook_name = name.trim().to_string();
+            let branch = Some(branch.trim().to_string()).filter(|b| !b.is_empty());
+            api_save_webhook((*editing).clone(), hook_name.clone(), branch, *policy, *enabled, false,
+                Some(move |result: Result<SaveWebhookResponse, String>| {
+                    if matches!(result, Ok(ref response) if response.success) {
+                        clear_form();
+                    }
+                    after_save(hook_name.clone(), result);
+                }));
+        })
+    };
+
+    let on_cancel_edit = {
+        let clear_form = clear_form.clone();
+        Callback::from(move |_| clear_form())
+    };
+
+    let on_refresh = {
+        let list = list.clone();
+        let deliveries = deliveries.clone();
+        let status_message = status_message.clone();
+        Callback::from(move |_| refresh(&list, &deliveries, &status_message))
+    };
+
+    let Some(ref hooks) = *list else {
+        return html! {
+            <div class="space-y-2">
+                { status_banner(&status_message) }
+                <p class="text-sm">{"Loading webhooks..."}</p>
+            </div>
+        };
+    };
+
+    let api_url = get_env_var("API_URL");
+    let row = |hook: &WebhookInfo| {
+        let on_edit = {
+            let editing = editing.clone();
+            let name = name.clone();
+            let branch = branch.clone();
+            let policy = policy.clone();
+            let enabled = enabled.clone();
+            let hook = hook.clone();
+            Callback::from(move |_| {
+                editing.set(Some(hook.id.clone()));
+                name.set(hook.name.clone());
+                branch.set(hook.branch.clone().unwrap_or_default());
+                policy.set(hook.policy);
+                enabled.set(hook.enabled);
+            })
+        };
+        let on_regenerate = {
+            let hook = hook.clone();
+            let after_save = after_save.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message("Replace the secret? Update it on the git host afterwards.").ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    let after_save = after_save.clone();
+                    let hook_name = hook.name.clone();
+                    api_save_webhook(Some(hook.id.clone()), hook.name.clone(), hook.branch.clone(), hook.policy, hook.enabled, true,
+                        Some(move |result| after_save(hook_name.clone(), result)));
+                }
+            })
+        };
+        let on_delete = {
+            let id = hook.id.clone();
+            let label = hook.name.clone();
+            let reload_after = reload_after.clone();
+            Callback::from(move |_| {
+                let confirmed = web_sys::window()
+                    .and_then(|w| w.confirm_with_message(&format!("Delete webhook \"{}\"?", label)).ok())
+                    .unwrap_or(false);
+                if confirmed {
+                    api_delete_webhook(&id, Some(reload_after.clone()));
+                }
+            })
+        };
+
+        html! {
+            <div class="bg-surface p-2 rounded space-y-1">
+                <div class="flex items-center justify-between">
+                    <span class="text-sm">
+                        <span class="font-medium">{ &hook.name }</span>
+                        { format!(" · {}{}",
+                            hook.branch.as_deref().map(|b| format!("branch {}", b)).unwrap_or_else(|| "checked-out branch".into()),
+                            if hook.enabled { "" } else { " · disabled" }) }
+                    </span>
+                    <div class="flex gap-2">
+                        <button class="btn btn-secondary text-sm" onclick={on_edit}>{"Edit"}</button>
+                        <button class="btn btn-secondary text-sm" onclick={on_regenerate}>{"New Secret"}</button>
+                        <button class="btn btn-danger text-sm" onclick={on_delete}>{"Delete"}</button>
+                    </div>
+                </div>
+                <div class="text-xs">{"URL "}<code>{ format!("{}{}", api_url.trim_end_matches('/'), hook.path) }</code></div>
+                <div class="text-xs">{"Secret "}<code>{ &hook.secret }</code></div>
+            </div>
+        }
+    };
+
+    let delivery_row = |delivery: &WebhookDelivery| {
+        let hook_name = hooks.hooks.iter().find(|h| h.id == delivery.hook_id)
+            .map(|h| h.name.clone())
+            .unwrap_or_else(|| "deleted webhook".into());
+        html! {
+            <tr class="text-xs">
+                <td>{ format_timestamp(Some(delivery.received_at)) }</td>
+                <td>{ hook_name }</td>
+                <td>{ format!("{} {}", delivery.provider.clone().unwrap_or_default(), delivery.event.clone().unwrap_or_default()) }</td>
+                <td>{ delivery.branch.clone().unwrap_or_default() }</td>
+                <td class={delivery_status_class(&delivery.status)}>{ &delivery.status }</td>
+                <td>{ &delivery.message }</td>
+            </tr>
+        }
+    };
+
+    html! {
+        <div class="space-y-4">
+            { status_banner(&status_message) }
+            { if let Some((ref hook_name, ref secret)) = *new_secret {
+                html! {
+                    <div class="p-2 rounded text-sm border">
+                        { format!("Secret of {}: ", hook_name) }<code>{ secret }</code>
+                        <div class="text-xs">{"Copy it to the git host now; it is not shown again."}</div>
+                    </div>
+                }
+            } else {
+                html! {}
+            }}
+            <p class="text-xs">{"Add the URL and secret as a push webhook on GitHub, Gitea, Forgejo or GitLab (content type application/json). A push to the watched branch queues a pull."}</p>
+
+            { if hooks.hooks.is_empty() {
+                html! { <p class="text-sm">{"No webhooks."}</p> }
+            } else {
+                html! { for hooks.hooks.iter().map(row) }
+            }}
+
+            <div class="space-y-2 p-3 border rounded">
+                <h3 class="font-medium">{ if editing.is_some() { "Edit webhook" } else { "New webhook" } }</h3>
+                <div class="flex items-center gap-3">
+                    <input type="text" class="input" placeholder="Name, e.g. Forgejo"
+                        value={(*name).clone()} onchange={text_field(&name)} />
+                    <input type="text" class="input" placeholder="Branch (empty for the checked-out one)"
+                        value={(*branch).clone()} onchange={text_field(&branch)} />
+                    <label class="flex items-center gap-1 text-sm">
+                        <input type="checkbox" checked={*enabled} onchange={on_enabled_change} />
+                        {"Enabled"}
+                    </label>
+                </div>
+                <div class="flex items-end gap-3">
+                    <div>
+                        <label class="block text-sm font-medium mb-1">{"Uncommitted changes"}</label>
+                        <select class="input" onchange={on_dirty_change}>
+                            { for DirtyTreePolicy::ALL.iter().map(|p| html! {
+                                <option value={p.key()} selected={*p == policy.dirty}>{ p.label() }</option>
+                            }) }
+                        </select>
+                    </div>
+                    <div>
+                        <label class="block text-sm font-medium mb-1">{"Diverged history"}</label>
+                        <select class="input" onchange={on_diverged_change}>
+                            { for DivergedPolicy::ALL.iter().map(|p| html! {
+                                <option value={p.key()} selected={*p == policy.diverged}>{ p.label() }</option>
+                            }) }
+                        </select>
+                    </div>
+                    <button class="btn btn-primary" onclick={on_save}>{"Save"}</button>
+                    { if editing.is_some() {
+                        html! { <button class="btn btn-secondary" onclick={on_cancel_edit}>{"Cancel"}</button> }
+                    } else {
+                        html! {}
+                    }}
+                </div>
+            </div>
+
+            <div class="space-y-2">
+                <div class="flex items-center justify-between">
+                    <h3 class="font-medium">{"Recent deliveries"}</h3>
+                    <button class="btn btn-secondary text-sm" onclick={on_refresh}>{"Refresh"}</button>
+                </div>
+                { if deliveries.is_empty() {
+                    html! { <p class="text-sm">{"No deliveries yet."}</p> }
+                } else {
+                    html! {
+                        <table class="w-full">
+                            <thead>
+                                <tr class="text-xs text-left">
+                                    <th>{"Received"}</th><th>{"Webhook"}</th><th>{"Event"}</th>
+                                    <th>{"Branch"}</th><th>{"Status"}</th><th>{"Message"}</th>
+                                </tr>
+                            </thead>
+                            <tbody>{ for deliveries.iter().map(delivery_row) }</tbody>
+                        </table>
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
@@ -6,4 +6,4 @@ mod use_input;
 
 pub use use_git_settings::use_git_settings;
 pub use use_async_action::use_async_action;
-pub use use_input::input_callback;
+pub use use_input::{input_callback, secret_input_callback};
@@ -1,6 +1,6 @@
 // frontend_simple_web/src/hooks/use_git_settings.rs
 //
-// Consolidates the 4 git-form state handles + their localStorage loading.
+// Consolidates the git-form state handles + their localStorage loading.
 
 use yew::prelude::*;
 use crate::api::git::GitRepoConfig;
@@ -10,6 +10,10 @@ pub struct GitSettings {
     pub branch: UseStateHandle<String>,
     pub username: UseStateHandle<String>,
     pub token: UseStateHandle<String>,
+    /// Clone depth; empty for the full history
+    pub depth: UseStateHandle<String>,
+    /// Comma-separated directories for a sparse checkout
+    pub sparse_paths: UseStateHandle<String>,
 }
 
 impl GitSettings {
@@ -20,36 +24,48 @@ impl GitSettings {
             branch: if self.branch.trim().is_empty() { None } else { Some(self.branch.trim().to_string()) },
             username: if self.username.trim().is_empty() { None } else { Some(self.username.trim().to_string()) },
             token: if self.token.trim().is_empty() { None } else { Some(self.token.trim().to_string()) },
+            depth: self.depth.trim().parse().ok().filter(|depth| *depth > 0),
+            sparse_paths: self.sparse_paths.split(',')
+                .map(|path| path.trim().to_string())
+                .filter(|path| !path.is_empty())
+                .collect(),
         }
     }
 }
 
-/// Hook that creates 4 state handles for git settings and loads their
-/// initial values from localStorage on first render.
+/// Hook that creates the state handles for git settings and loads their
+/// initial values from localStorage on first render. The token always starts
+/// empty.
 #[hook]
 pub fn use_git_settings() -> GitSettings {
     let repo_url = use_state(|| String::new());
     let branch = use_state(|| String::new());
     let username = use_state(|| String::new());
     let token = use_state(|| String::new());
+    let depth = use_state(String::new);
+    let sparse_paths = use_state(String::new);
 
     {
         let repo_url = repo_url.clone();
         let branch = branch.clone();
         let username = username.clone();
-        let token = token.clone();
+        let depth = depth.clone();
+        let sparse_paths = sparse_paths.clone();
         use_effect_with((), move |_| {
             if let Some(window) = web_sys::window() {
                 if let Ok(Some(storage)) = window.local_storage() {
                     if let Ok(Some(url)) = storage.get_item("git_repo_url") { repo_url.set(url); }
                     if let Ok(Some(br)) = storage.get_item("git_branch") { branch.set(br); }
                     if let Ok(Some(user)) = storage.get_item("git_username") { username.set(user); }
-                    if let Ok(Some(tok)) = storage.get_item("git_token") { token.set(tok); }
+                    if let Ok(Some(d)) = storage.get_item("git_depth") { depth.set(d); }
+                    if let Ok(Some(paths)) = storage.get_item("git_sparse_paths") { sparse_paths.set(paths); }
+                    // Tokens are stored encrypted server-side; drop copies older versions kept here
+                    let _ = storage.remove_item("git_token");
                 }
             }
             || ()
         });
     }
 
-    GitSettings { repo_url, branch, username, token }
+    GitSettings { repo_url, branch, username, token, depth, sparse_paths }
 }
@@ -24,3 +24,13 @@ pub fn input_callback(state: UseStateHandle<String>, storage_key: &'static str)
         }
     })
 }
+
+/// Like `input_callback`, but keeps the value in memory only. Used for
+/// secrets, which must not end up in localStorage.
+pub fn secret_input_callback(state: UseStateHandle<String>) -> Callback<Event> {
+    Callback::from(move |e: Event| {
+        if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
+            state.set(input.value());
+        }
+    })
+}
@@ -4,11 +4,26 @@ use web_sys::HtmlInputElement;
 use wasm_bindgen_futures::spawn_local;
 use gloo::timers::callback::Interval;
 
-use crate::api::git::{api_git_setup, api_git_pull, api_git_test, api_get_git_status, api_commit_changes, api_push_changes, api_force_pull, api_get_auto_pull_config, api_set_auto_pull_config, GitStatus, GitRepoStatus, AutoPullConfig};
+use crate::api::git::{api_git_test, api_get_git_status, api_commit_changes, api_stage_paths, api_unstage_paths, run_git_task, CommitRequest, DiffTarget, GitRepoStatus, GitStatus, PullMode, TaskRequest};
 use crate::api::auth;
 use crate::router::Route;
 use crate::components::theme_editor::ThemeEditor;
-use crate::hooks::{use_git_settings, use_async_action, input_callback};
+use crate::components::job_manager::{format_timestamp, JobManager};
+use crate::components::publications::PublicationList;
+use crate::components::backup_manager::BackupManager;
+use crate::components::branch_switcher::BranchSwitcher;
+use crate::components::git_tasks::GitTasks;
+use crate::components::history::HistoryView;
+use crate::components::diff_view::DiffView;
+use crate::components::identity_settings::{selected_author, IdentitySettings};
+use crate::components::conflict_resolver::ConflictResolver;
+use crate::components::credential_settings::CredentialSettings;
+use crate::components::remote_manager::RemoteManager;
+use crate::components::tag_manager::TagManager;
+use crate::components::webhook_settings::WebhookSettings;
+use crate::components::ssh_settings::SshSettings;
+use crate::components::stash_manager::StashManager;
+use crate::hooks::{use_git_settings, use_async_action, input_callback, secret_input_callback};
 
 type StatusMsg = UseStateHandle<Option<(bool, String)>>;
 
@@ -58,19 +73,19 @@ pub fn settings() -> Html {
     // Git form fields — loaded from localStorage by the hook
     let git = use_git_settings();
 
-    let auto_pull_enabled = use_state(|| false);
-    let pull_interval = use_state(|| 30u32);
     let status_message: StatusMsg = use_state(|| None);
     let git_repo_status = use_state(|| None::<GitRepoStatus>);
     let commit_message = use_state(|| "Updated files via simple_web".to_string());
+    let co_authors = use_state(String::new);
     let _status_poll_timer = use_state(|| None::<Interval>);
+    let diff_target = use_state(|| DiffTarget::WorkingTree);
 
     // --- Async actions using the hook (replaces 6 separate is_loading states) ---
 
     let setup_action = {
         let git_config = git.to_config();
         use_async_action(status_message.clone(), move |cb| {
-            api_git_setup(git_config.clone(), Some(cb));
+            run_git_task(TaskRequest::Clone(git_config.clone()), cb);
         })
     };
 
@@ -82,60 +97,52 @@ pub fn settings() -> Html {
     };
 
     let pull_action = use_async_action(status_message.clone(), |cb| {
-        api_git_pull(Some(cb));
+        run_git_task(TaskRequest::Pull { mode: PullMode::FastForward, stash: false, remote: None }, cb);
+    });
+
+    let merge_pull_action = use_async_action(status_message.clone(), |cb| {
+        run_git_task(TaskRequest::Pull { mode: PullMode::Merge, stash: false, remote: None }, cb);
+    });
+
+    let rebase_pull_action = use_async_action(status_message.clone(), |cb| {
+        run_git_task(TaskRequest::Pull { mode: PullMode::Rebase, stash: false, remote: None }, cb);
+    });
+
+    // Pull without committing first: park the changes and bring them back
+    let stash_pull_action = use_async_action(status_message.clone(), |cb| {
+        run_git_task(TaskRequest::Pull { mode: PullMode::FastForward, stash: true, remote: None }, cb);
     });
 
     let push_action = use_async_action(status_message.clone(), |cb| {
-        api_push_changes(Some(cb));
+        run_git_task(TaskRequest::Push { remote: None }, cb);
     });
 
-    let commit_action = {
+    // Commit as this browser's author profile, crediting the co-authors
+    let commit_request = {
         let msg = commit_message.clone();
+        let co_authors = co_authors.clone();
+        move |all: bool| CommitRequest {
+            message: msg.to_string(),
+            all,
+            author: selected_author(),
+            co_authors: co_authors.split(';').map(|c| c.trim().to_string()).filter(|c| !c.is_empty()).collect(),
+        }
+    };
+
+    let commit_action = {
+        let commit_request = commit_request.clone();
         use_async_action(status_message.clone(), move |cb| {
-            api_commit_changes(msg.to_string(), Some(cb));
+            api_commit_changes(commit_request(false), Some(cb));
         })
     };
 
-    let force_pull_action = use_async_action(status_message.clone(), |cb| {
-        api_force_pull(Some(cb));
+    let commit_all_action = use_async_action(status_message.clone(), move |cb| {
+        api_commit_changes(commit_request(true), Some(cb));
     });
 
-    // Load auto-pull config from backend
-    {
-        let auto_pull_enabled = auto_pull_enabled.clone();
-        let pull_interval = pull_interval.clone();
-
-        use_effect_with((), move |_| {
-            api_get_auto_pull_config(Some({
-                let auto_pull_enabled = auto_pull_enabled.clone();
-                let pull_interval = pull_interval.clone();
-                move |result: Result<AutoPullConfig, String>| {
-                    match result {
-                        Ok(config) => {
-                            auto_pull_enabled.set(config.enabled);
-                            pull_interval.set(config.interval_minutes);
-                        }
-                        Err(_) => {
-                            if let Some(window) = web_sys::window() {
-                                if let Ok(Some(storage)) = window.local_storage() {
-                                    if let Ok(Some(auto)) = storage.get_item("auto_pull_enabled") {
-                                        auto_pull_enabled.set(auto == "true");
-                                    }
-                                    if let Ok(Some(interval)) = storage.get_item("pull_interval") {
-                                        if let Ok(minutes) = interval.parse::<u32>() {
-                                            pull_interval.set(minutes);
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }));
-
-            || ()
-        });
-    }
+    let force_pull_action = use_async_action(status_message.clone(), |cb| {
+        run_git_task(TaskRequest::ForcePull, cb);
+    });
 
     // Poll git status regularly
     {
@@ -163,86 +170,57 @@ pub fn settings() -> Html {
     let on_url_change = input_callback(git.repo_url.clone(), "git_repo_url");
     let on_branch_change = input_callback(git.branch.clone(), "git_branch");
     let on_username_change = input_callback(git.username.clone(), "git_username");
-    let on_token_change = input_callback(git.token.clone(), "git_token");
-
-    let on_auto_pull_toggle = {
-        let auto_pull_enabled = auto_pull_enabled.clone();
-        let pull_interval = pull_interval.clone();
-        let status_message = status_message.clone();
+    let on_token_change = secret_input_callback(git.token.clone());
+    let on_depth_change = input_callback(git.depth.clone(), "git_depth");
+    let on_sparse_paths_change = input_callback(git.sparse_paths.clone(), "git_sparse_paths");
 
+    let on_commit_message_change = {
+        let commit_message = commit_message.clone();
         Callback::from(move |e: Event| {
             if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                let enabled = input.checked();
-
-                let auto_pull_enabled = auto_pull_enabled.clone();
-                let status_message = status_message.clone();
-                api_set_auto_pull_config(
-                    AutoPullConfig { enabled, interval_minutes: *pull_interval },
-                    Some(move |result: Result<GitStatus, String>| {
-                        match result {
-                            Ok(status) if status.success => {
-                                auto_pull_enabled.set(enabled);
-                                status_message.set(Some((true, format!("Auto-pull {} successfully",
-                                    if enabled { "enabled" } else { "disabled" }))));
-                            }
-                            Ok(status) => status_message.set(Some((false, format!("Failed to update auto-pull: {}", status.message)))),
-                            Err(e) => status_message.set(Some((false, format!("Failed to update auto-pull: {}", e)))),
-                        }
-                    }),
-                );
+                commit_message.set(input.value());
             }
         })
     };
 
-    let on_interval_change = {
-        let pull_interval = pull_interval.clone();
-        let auto_pull_enabled = auto_pull_enabled.clone();
-        let status_message = status_message.clone();
-
+    let on_co_authors_change = {
+        let co_authors = co_authors.clone();
         Callback::from(move |e: Event| {
             if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                if let Ok(minutes) = input.value().parse::<u32>() {
-                    if minutes > 0 && minutes <= 1440 {
-                        if let Some(window) = web_sys::window() {
-                            if let Ok(Some(storage)) = window.local_storage() {
-                                let _ = storage.set_item("pull_interval", &minutes.to_string());
-                            }
-                        }
-
-                        if *auto_pull_enabled {
-                            let pull_interval = pull_interval.clone();
-                            let status_message = status_message.clone();
-                            api_set_auto_pull_config(
-                                AutoPullConfig { enabled: true, interval_minutes: minutes },
-                                Some(move |result: Result<GitStatus, String>| {
-                                    match result {
-                                        Ok(status) if status.success => {
-                                            pull_interval.set(minutes);
-                                            status_message.set(Some((true, format!("Auto-pull interval updated to {} minutes", minutes))));
-                                        }
-                                        Ok(status) => status_message.set(Some((false, format!("Failed to update interval: {}", status.message)))),
-                                        Err(e) => status_message.set(Some((false, format!("Failed to update interval: {}", e)))),
-                                    }
-                                }),
-                            );
-                        } else {
-                            pull_interval.set(minutes);
-                        }
-                    }
-                }
+                co_authors.set(input.value());
             }
         })
     };
 
-    let on_commit_message_change = {
-        let commit_message = commit_message.clone();
-        Callback::from(move |e: Event| {
-            if let Some(input) = e.target_dyn_into::<HtmlInputElement>() {
-                commit_message.set(input.value());
+    // Checkbox that stages or unstages a single file
+    let stage_toggle = |path: &str, staged: bool| {
+        let path = path.to_string();
+        let git_repo_status = git_repo_status.clone();
+        let status_message = status_message.clone();
+        Callback::from(move |_: Event| {
+            let git_repo_status = git_repo_status.clone();
+            let status_message = status_message.clone();
+            let callback = Some(move |result: Result<GitStatus, String>| {
+                match result {
+                    Ok(status) if !status.success => status_message.set(Some((false, status.message))),
+                    Err(e) => status_message.set(Some((false, e))),
+                    Ok(_) => {}
+                }
+                refresh_git_status(&git_repo_status);
+            });
+            if staged {
+                api_unstage_paths(vec![path.clone()], callback);
+            } else {
+                api_stage_paths(vec![path.clone()], callback);
             }
         })
     };
 
+    let on_diff_staged = {
+        let git_repo_status = git_repo_status.clone();
+        Callback::from(move |_| refresh_git_status(&git_repo_status))
+    };
+
     // Force pull needs a confirmation dialog, so wrap the action trigger
     let force_pull_with_confirm = {
         let trigger = force_pull_action.trigger.clone();
@@ -288,12 +266,73 @@ pub fn settings() -> Html {
                             }}
                         </div>
 
+                        { if status.shallow || !status.sparse_paths.is_empty() {
+                            let mut notes = Vec::new();
+                            if status.shallow {
+                                notes.push("Shallow clone: older history is not available".to_string());
+                            }
+                            if !status.sparse_paths.is_empty() {
+                                notes.push(format!("Sparse checkout of {}", status.sparse_paths.join(", ")));
+                            }
+                            html! { <div class="text-xs text-gray-500">{ notes.join(" · ") }</div> }
+                        } else {
+                            html! {}
+                        }}
+
+                        { if !status.lock_holders.iter().any(|holder| holder.exclusive) {
+                            html! {}
+                        } else {
+                            html! {
+                                <div class="text-xs text-orange-600">
+                                    { format!("Repository busy: {}", status.lock_holders.iter()
+                                        .filter(|holder| holder.exclusive)
+                                        .map(|holder| format!("{} since {}", holder.operation, format_timestamp(Some(holder.since))))
+                                        .collect::<Vec<_>>()
+                                        .join(", ")) }
+                                </div>
+                            }
+                        }}
+
+                        { if status.submodules.is_empty() {
+                            html! {}
+                        } else {
+                            html! {
+                                <div class="space-y-1">
+                                    <div class="text-sm font-medium">{"Submodules:"}</div>
+                                    { for status.submodules.iter().map(|submodule| html! {
+                                        <div class="flex items-center gap-2 text-xs bg-surface p-2 rounded">
+                                            <code class="text-xs">{ &submodule.path }</code>
+                                            <span class={match submodule.state.as_str() {
+                                                "clean" => "text-green-600",
+                                                "uninitialized" => "text-gray-500",
+                                                _ => "text-orange-600",
+                                            }}>
+                                                { match submodule.state.as_str() {
+                                                    "clean" => "up to date",
+                                                    "uninitialized" => "not initialized; pull to fetch it",
+                                                    "new_commits" => "another commit checked out than recorded",
+                                                    "modified" => "uncommitted changes inside",
+                                                    other => other,
+                                                }}
+                                            </span>
+                                            { submodule.checked_out_commit.as_deref().map(|c| html! {
+                                                <span class="text-gray-500">{ &c[..7.min(c.len())] }</span>
+                                            }).unwrap_or_default() }
+                                        </div>
+                                    }) }
+                                </div>
+                            }
+                        }}
+
                         { if status.has_changes {
                             html! {
                                 <div class="space-y-3 mt-4">
                                     <div class="text-sm font-medium text-orange-600">{"Local Changes Detected:"}</div>
+                                    <div class="text-xs">{"Checked files are staged for the next commit."}</div>
                                     { for status.changed_files.iter().map(|file| html! {
                                         <div class="flex items-center gap-2 text-xs bg-surface p-2 rounded">
+                                            <input type="checkbox" checked={file.status.starts_with("staged_")}
+                                                onchange={stage_toggle(&file.path, file.status.starts_with("staged_"))} />
                                             <span class={match file.status.as_str() {
                                                 "modified" => "text-orange-500",
                                                 "deleted" => "text-red-500",
@@ -315,6 +354,7 @@ pub fn settings() -> Html {
                                     }) }
                                     { for status.untracked_files.iter().map(|file| html! {
                                         <div class="flex items-center gap-2 text-xs bg-surface p-2 rounded">
+                                            <input type="checkbox" checked=false onchange={stage_toggle(file, false)} />
                                             <span class="text-gray-500">{"??"}</span>
                                             <code class="text-xs">{ file }</code>
                                         </div>
@@ -341,10 +381,33 @@ pub fn settings() -> Html {
                                             onchange={on_commit_message_change}
                                         />
                                     </div>
+                                    <div>
+                                        <label class="block text-sm font-medium mb-1">{"Co-authors"}</label>
+                                        <input
+                                            type="text"
+                                            class="input w-full"
+                                            placeholder="Jane Doe <jane@example.com>; …"
+                                            value={(*co_authors).clone()}
+                                            onchange={on_co_authors_change}
+                                        />
+                                    </div>
                                     <div class="flex gap-2 flex-wrap">
-                                        <button class="btn btn-primary" onclick={commit_action.trigger.reform(|_| ())} disabled={*commit_action.is_loading}>
-                                            { if *commit_action.is_loading { "Committing..." } else { "Commit Changes" } }
+                                        <button class="btn btn-primary" onclick={commit_action.trigger.reform(|_| ())}
+                                            disabled={*commit_action.is_loading || !status.has_staged_changes}>
+                                            { if *commit_action.is_loading { "Committing..." } else { "Commit Staged" } }
                                         </button>
+                                        <button class="btn btn-secondary" onclick={commit_all_action.trigger.reform(|_| ())} disabled={*commit_all_action.is_loading}>
+                                            { if *commit_all_action.is_loading { "Committing..." } else { "Commit All Changes" } }
+                                        </button>
+                                        { if status.behind_count > 0 && status.ahead_count == 0 {
+                                            html! {
+                                                <button class="btn btn-secondary" onclick={stash_pull_action.trigger.reform(|_| ())} disabled={*stash_pull_action.is_loading}>
+                                                    { if *stash_pull_action.is_loading { "Pulling..." } else { "Stash & Pull" } }
+                                                </button>
+                                            }
+                                        } else {
+                                            html! {}
+                                        }}
                                         <button class="btn btn-danger" onclick={force_pull_with_confirm.clone()} disabled={*force_pull_action.is_loading}>
                                             { if *force_pull_action.is_loading { "Force Pulling..." } else { "Force Pull (Discard Changes)" } }
                                         </button>
@@ -365,8 +428,18 @@ pub fn settings() -> Html {
                                     </div>
                                     { if status.behind_count > 0 {
                                         html! {
-                                            <div class="text-sm text-orange-600 px-2 py-1 bg-orange-50 rounded">
-                                                {"Cannot pull: you are both ahead and behind. Push first or use Force Pull."}
+                                            <div class="space-y-2">
+                                                <div class="text-sm text-orange-600 px-2 py-1 bg-orange-50 rounded">
+                                                    {"You are both ahead and behind. Merge or rebase onto origin before pushing."}
+                                                </div>
+                                                <div class="flex gap-2 flex-wrap">
+                                                    <button class="btn btn-secondary" onclick={merge_pull_action.trigger.reform(|_| ())} disabled={*merge_pull_action.is_loading}>
+                                                        { if *merge_pull_action.is_loading { "Merging..." } else { "Merge Origin" } }
+                                                    </button>
+                                                    <button class="btn btn-secondary" onclick={rebase_pull_action.trigger.reform(|_| ())} disabled={*rebase_pull_action.is_loading}>
+                                                        { if *rebase_pull_action.is_loading { "Rebasing..." } else { "Rebase onto Origin" } }
+                                                    </button>
+                                                </div>
                                             </div>
                                         }
                                     } else {
@@ -426,12 +499,100 @@ pub fn settings() -> Html {
             <div class="space-y-6">
                 { repo_status_section }
 
+                { if git_repo_status.as_ref().is_some_and(|status| status.merge_in_progress) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Merge"}</h2>
+                            <ConflictResolver on_resolved={on_diff_staged.clone()} />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Branches"}</h2>
+                            <BranchSwitcher />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Remotes"}</h2>
+                            <RemoteManager on_changed={on_diff_staged.clone()} />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    let diff_tab = |target: DiffTarget, label: &'static str| {
+                        let class = if *diff_target == target { "btn btn-primary text-sm" } else { "btn btn-secondary text-sm" };
+                        let diff_target = diff_target.clone();
+                        html! {
+                            <button class={class} onclick={Callback::from(move |_| diff_target.set(target.clone()))}>{ label }</button>
+                        }
+                    };
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Review Changes"}</h2>
+                            <div class="flex gap-2 mb-4">
+                                { diff_tab(DiffTarget::WorkingTree, "Unstaged") }
+                                { diff_tab(DiffTarget::Staged, "Staged") }
+                            </div>
+                            <DiffView target={(*diff_target).clone()} staging=true on_staged={on_diff_staged.clone()} />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Stash"}</h2>
+                            <StashManager on_changed={on_diff_staged.clone()} />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"History"}</h2>
+                            <HistoryView on_changed={on_diff_staged.clone()} />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                { if git_repo_status.as_ref().is_some_and(|status| status.success) {
+                    html! {
+                        <section class="bg-card p-4 rounded border">
+                            <h2 class="font-bold mb-4">{"Tags"}</h2>
+                            <TagManager />
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }}
+
                 <section class="bg-card p-4 rounded border">
                     <h2 class="font-bold mb-4">{"Git Repository Configuration"}</h2>
                     <div>
                         <div>
                             <label class="block text-sm font-medium mb-1">{"Repository URL *"}</label>
-                            <input type="url" class="input w-full" placeholder="https://github.com/username/repo.git"
+                            <input type="text" class="input w-full" placeholder="https://github.com/username/repo.git or git@github.com:username/repo.git"
                                 value={(*git.repo_url).clone()} onchange={on_url_change} />
                         </div>
                         <div>
@@ -446,9 +607,21 @@ pub fn settings() -> Html {
                         </div>
                         <div>
                             <label class="block text-sm font-medium mb-1">{"Personal Access Token (for private repos)"}</label>
-                            <input type="password" class="input w-full" placeholder="ghp_..."
+                            <input type="password" class="input w-full" placeholder="ghp_... (leave empty to use the saved credentials)"
                                 value={(*git.token).clone()} onchange={on_token_change} />
                         </div>
+                        <div>
+                            <label class="block text-sm font-medium mb-1">{"Clone depth (optional)"}</label>
+                            <input type="number" min="1" class="input w-full" placeholder="Full history"
+                                value={(*git.depth).clone()} onchange={on_depth_change} />
+                            <p class="text-xs">{"Only fetch this many recent commits, for repositories with a long or large history."}</p>
+                        </div>
+                        <div>
+                            <label class="block text-sm font-medium mb-1">{"Sparse checkout (optional)"}</label>
+                            <input type="text" class="input w-full" placeholder="public, assets"
+                                value={(*git.sparse_paths).clone()} onchange={on_sparse_paths_change} />
+                            <p class="text-xs">{"Comma-separated directories to check out; everything when empty. Both apply when cloning."}</p>
+                        </div>
                         <div class="flex gap-2">
                             <button class="btn btn-secondary" onclick={test_action.trigger.reform(|_| ())} disabled={*test_action.is_loading}>
                                 { if *test_action.is_loading { "Testing..." } else { "Test Connection" } }
@@ -460,29 +633,43 @@ pub fn settings() -> Html {
                                 { if *pull_action.is_loading { "Pulling..." } else { "Pull Updates" } }
                             </button>
                         </div>
+                        <GitTasks />
                     </div>
                 </section>
 
                 <section class="bg-card p-4 rounded border">
-                    <h2 class="font-bold mb-4">{"Automatic Synchronization"}</h2>
-                    <div>
-                        <div class="flex items-center gap-3">
-                            <input type="checkbox" id="auto-pull" checked={*auto_pull_enabled} onchange={on_auto_pull_toggle} />
-                            <label for="auto-pull" class="text-sm font-medium">{"Enable automatic pulling"}</label>
-                        </div>
-                        { if *auto_pull_enabled {
-                            html! {
-                                <div>
-                                    <label class="block text-sm font-medium mb-1">{"Pull interval (minutes)"}</label>
-                                    <input type="number" min="1" max="1440" class="input w-32"
-                                        value={(*pull_interval).to_string()} onchange={on_interval_change} />
-                                    <p class="text-sm mt-1">{format!("Automatically check for changes and pull every {} minutes. Repository must be set up first.", *pull_interval)}</p>
-                                </div>
-                            }
-                        } else {
-                            html! {}
-                        }}
-                    </div>
+                    <h2 class="font-bold mb-4">{"Git Credentials"}</h2>
+                    <CredentialSettings />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"SSH Deploy Key"}</h2>
+                    <SshSettings />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"Commit Identity"}</h2>
+                    <IdentitySettings />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"Scheduled Jobs"}</h2>
+                    <JobManager />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"Webhooks"}</h2>
+                    <WebhookSettings />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"Scheduled Publications"}</h2>
+                    <PublicationList />
+                </section>
+
+                <section class="bg-card p-4 rounded border">
+                    <h2 class="font-bold mb-4">{"Backups"}</h2>
+                    <BackupManager />
                 </section>
 
                 <section class="bg-card p-4 rounded border">
@@ -498,7 +685,7 @@ pub fn settings() -> Html {
                             onclick={Callback::from(|_| {
                                 if let Some(window) = web_sys::window() {
                                     if let Ok(Some(storage)) = window.local_storage() {
-                                        for key in ["git_repo_url", "git_branch", "git_username", "git_token", "auto_pull_enabled", "pull_interval"] {
+                                        for key in ["git_repo_url", "git_branch", "git_username", "git_token", "git_depth", "git_sparse_paths", "auto_pull_enabled", "pull_interval"] {
                                             let _ = storage.remove_item(key);
                                         }
                                         web_sys::window().unwrap().location().reload().unwrap();
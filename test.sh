@@ -17,6 +17,9 @@ show_help() {
     echo "  test-files  Run file management tests only"
     echo "  test-git    Run git operations tests only"
     echo "  test-themes Run theme management tests only"
+    echo "  test-jobs   Run scheduled job tests only"
+    echo "  test-publications Run scheduled publication tests only"
+    echo "  test-backups Run backup tests only"
     echo "  stop        Stop the application"
     echo "  logs        Show application logs"
     echo "  help        Show this help message"
@@ -68,7 +71,7 @@ run_all_tests() {
     echo "Running all API tests..."
 
     # Run each test file individually
-    for test_file in auth.hurl files.hurl git.hurl themes.hurl; do
+    for test_file in auth.hurl files.hurl git.hurl themes.hurl jobs.hurl publications.hurl backups.hurl; do
         echo "Running $test_file..."
         if docker compose --profile test -f docker-compose.dev.yml run --rm hurl_tests --test "$test_file"; then
             echo "✅ $test_file passed"
@@ -99,6 +102,15 @@ case "${1:-help}" in
     test-themes)
         run_hurl_test "themes.hurl" "Theme Management"
         ;;
+    test-jobs)
+        run_hurl_test "jobs.hurl" "Scheduled Jobs"
+        ;;
+    test-publications)
+        run_hurl_test "publications.hurl" "Scheduled Publications"
+        ;;
+    test-backups)
+        run_hurl_test "backups.hurl" "Backups"
+        ;;
     stop)
         echo "Stopping Simple Web application..."
         docker compose -f docker-compose.dev.yml down